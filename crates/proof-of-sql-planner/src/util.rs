@@ -33,6 +33,8 @@ pub(crate) fn scalar_value_to_literal_value(value: ScalarValue) -> PlannerResult
         ScalarValue::Int32(Some(v)) => Ok(LiteralValue::Int(v)),
         ScalarValue::Int64(Some(v)) => Ok(LiteralValue::BigInt(v)),
         ScalarValue::UInt8(Some(v)) => Ok(LiteralValue::Uint8(v)),
+        ScalarValue::UInt64(Some(v)) => Ok(LiteralValue::UInt64(v)),
+        ScalarValue::Date32(Some(v)) => Ok(LiteralValue::Date(v)),
         ScalarValue::Utf8(Some(v)) => Ok(LiteralValue::VarChar(v)),
         ScalarValue::Binary(Some(v)) => Ok(LiteralValue::VarBinary(v)),
         ScalarValue::TimestampSecond(Some(v), None) => Ok(LiteralValue::TimeStampTZ(
@@ -0,0 +1,46 @@
+//! # Running the Benchmark
+//!
+//! To run the benchmark, use the following command:
+//!
+//! ```bash
+//! cargo bench --bench bench_batch_scalar_mul
+//! ```
+#![allow(missing_docs, clippy::missing_docs_in_private_items)]
+use ark_bls12_381::G1Projective;
+use ark_ec::PrimeGroup;
+use ark_std::{test_rng, UniformRand};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use proof_of_sql::proof_primitive::{batch_scalar_mul, dory::DoryScalar};
+
+/// Compare multiplying a fixed base by `n` scalars using a precomputed window table
+/// against multiplying the base by each scalar in a loop.
+fn bench_batch_scalar_mul(c: &mut Criterion, n: usize) {
+    let mut rng = test_rng();
+    let base = G1Projective::rand(&mut rng);
+    let scalars: Vec<DoryScalar> = (0..n).map(|_| DoryScalar::rand(&mut rng)).collect();
+    c.bench_function(&format!("batch_scalar_mul_{n}"), |b| {
+        b.iter(|| batch_scalar_mul(black_box(&scalars), black_box(&base)));
+    });
+    c.bench_function(&format!("naive_scalar_mul_{n}"), |b| {
+        b.iter(|| {
+            black_box(&scalars)
+                .iter()
+                .map(|scalar| black_box(base).mul_bigint(<[u64; 4]>::from(scalar)))
+                .collect::<Vec<_>>()
+        });
+    });
+}
+
+fn bench_batch_scalar_mul_256(c: &mut Criterion) {
+    bench_batch_scalar_mul(c, 256);
+}
+
+fn bench_batch_scalar_mul_4096(c: &mut Criterion) {
+    bench_batch_scalar_mul(c, 4096);
+}
+criterion_group!(
+    benches,
+    bench_batch_scalar_mul_256,
+    bench_batch_scalar_mul_4096
+);
+criterion_main!(benches);
@@ -0,0 +1,40 @@
+//! # Running the Benchmark
+//!
+//! To run the benchmark, use the following command:
+//!
+//! ```bash
+//! cargo bench --bench bench_precompute_multiples
+//! ```
+#![allow(missing_docs, clippy::missing_docs_in_private_items)]
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use proof_of_sql::{base::scalar::ScalarExt, proof_primitive::dory::DoryScalar};
+
+/// Compare computing the multiples `[0, s, 2s, ..., ns]` of a scalar using repeated additions
+/// against multiplying `s` by each of `0..=n`.
+fn bench_precompute_multiples(c: &mut Criterion, n: usize) {
+    let s = DoryScalar::from(0x1234_5678_9abc_def0_u64);
+    c.bench_function(&format!("precompute_multiples_{n}"), |b| {
+        b.iter(|| black_box(s).precompute_multiples(black_box(n)));
+    });
+    c.bench_function(&format!("naive_multiples_{n}"), |b| {
+        b.iter(|| {
+            (0..=black_box(n) as u64)
+                .map(|i| black_box(s) * DoryScalar::from(i))
+                .collect::<Vec<_>>()
+        });
+    });
+}
+
+fn bench_precompute_multiples_256(c: &mut Criterion) {
+    bench_precompute_multiples(c, 256);
+}
+
+fn bench_precompute_multiples_65536(c: &mut Criterion) {
+    bench_precompute_multiples(c, 65536);
+}
+criterion_group!(
+    benches,
+    bench_precompute_multiples_256,
+    bench_precompute_multiples_65536
+);
+criterion_main!(benches);
@@ -0,0 +1,37 @@
+//! # Running the Benchmark
+//!
+//! To run the benchmark, use the following command:
+//!
+//! ```bash
+//! cargo bench --bench bench_prefix_sum
+//! ```
+#![allow(missing_docs, clippy::missing_docs_in_private_items)]
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use proof_of_sql::{base::scalar::ScalarExt, proof_primitive::dory::DoryScalar};
+
+/// Compare computing the prefix sums of `n` scalars using [`ScalarExt::prefix_sum`]
+/// against a naive sequential loop.
+fn bench_prefix_sum(c: &mut Criterion, n: u64) {
+    let values: Vec<DoryScalar> = (0..n).map(DoryScalar::from).collect();
+    c.bench_function(&format!("prefix_sum_{n}"), |b| {
+        b.iter(|| DoryScalar::prefix_sum(black_box(&values)));
+    });
+    c.bench_function(&format!("naive_prefix_sum_{n}"), |b| {
+        b.iter(|| {
+            let mut sum = DoryScalar::default();
+            black_box(&values)
+                .iter()
+                .map(|value| {
+                    sum += *value;
+                    sum
+                })
+                .collect::<Vec<_>>()
+        });
+    });
+}
+
+fn bench_prefix_sum_1m(c: &mut Criterion) {
+    bench_prefix_sum(c, 1 << 20);
+}
+criterion_group!(benches, bench_prefix_sum_1m);
+criterion_main!(benches);
@@ -1,6 +1,6 @@
 use crate::base::{
     database::{ColumnField, ColumnType},
-    math::decimal::Precision,
+    math::decimal::{Precision, MAX_SUPPORTED_PRECISION},
     posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
 };
 use alloc::sync::Arc;
@@ -16,13 +16,15 @@ impl From<&ColumnType> for DataType {
             ColumnType::SmallInt => DataType::Int16,
             ColumnType::Int => DataType::Int32,
             ColumnType::BigInt => DataType::Int64,
+            ColumnType::UInt64 => DataType::UInt64,
+            ColumnType::Date => DataType::Date32,
             ColumnType::Int128 => DataType::Decimal128(38, 0),
             ColumnType::Decimal75(precision, scale) => {
                 DataType::Decimal256(precision.value(), *scale)
             }
             ColumnType::VarChar => DataType::Utf8,
             ColumnType::VarBinary => DataType::Binary,
-            ColumnType::Scalar => unimplemented!("Cannot convert Scalar type to arrow type"),
+            ColumnType::Scalar => DataType::Decimal256(MAX_SUPPORTED_PRECISION, 0),
             ColumnType::TimestampTZ(timeunit, timezone) => {
                 let arrow_timezone = Some(Arc::from(timezone.to_string()));
                 let arrow_timeunit = match timeunit {
@@ -49,6 +51,8 @@ impl TryFrom<DataType> for ColumnType {
             DataType::Int16 => Ok(ColumnType::SmallInt),
             DataType::Int32 => Ok(ColumnType::Int),
             DataType::Int64 => Ok(ColumnType::BigInt),
+            DataType::UInt64 => Ok(ColumnType::UInt64),
+            DataType::Date32 => Ok(ColumnType::Date),
             DataType::Decimal128(38, 0) => Ok(ColumnType::Int128),
             DataType::Decimal256(precision, scale) if precision <= 75 => {
                 Ok(ColumnType::Decimal75(Precision::new(precision)?, scale))
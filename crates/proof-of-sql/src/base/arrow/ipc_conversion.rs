@@ -0,0 +1,135 @@
+use super::owned_and_arrow_conversions::OwnedArrowConversionError;
+use crate::base::{
+    database::{OwnedTable, OwnedTableError},
+    scalar::Scalar,
+};
+use arrow::{
+    error::ArrowError,
+    ipc::{reader::StreamReader, writer::StreamWriter},
+    record_batch::RecordBatch,
+};
+use snafu::Snafu;
+use std::io::{Read, Write};
+
+/// Errors that can occur when streaming an [`OwnedTable`] in the Arrow IPC stream format.
+#[derive(Snafu, Debug)]
+pub enum ArrowIpcError {
+    /// The stream could not be written, read or decoded.
+    #[snafu(transparent)]
+    Arrow {
+        /// The underlying source error
+        source: ArrowError,
+    },
+    /// A record batch could not be converted into an [`OwnedTable`].
+    #[snafu(transparent)]
+    Conversion {
+        /// The underlying source error
+        source: OwnedArrowConversionError,
+    },
+    /// A record batch could not be appended to the rows read so far.
+    #[snafu(display("record batch {batch} has an incompatible schema: {source}"))]
+    BatchSchemaMismatch {
+        /// The index of the offending record batch
+        batch: usize,
+        /// The underlying source error
+        source: OwnedTableError,
+    },
+}
+
+impl<S: Scalar> OwnedTable<S> {
+    /// Writes the table to `writer` in the Arrow IPC stream format, as a single record batch.
+    ///
+    /// # Errors
+    /// Returns [`ArrowIpcError::Arrow`] if the table cannot be converted to a record batch or the stream cannot be written.
+    pub fn write_arrow_ipc_stream<W: Write>(&self, writer: W) -> Result<(), ArrowIpcError> {
+        let batch = RecordBatch::try_from(self.clone())?;
+        let mut stream_writer = StreamWriter::try_new(writer, &batch.schema())?;
+        stream_writer.write(&batch)?;
+        stream_writer.finish()?;
+        Ok(())
+    }
+
+    /// Reads a table in the Arrow IPC stream format from `reader`.
+    ///
+    /// The stream may contain any number of record batches, which are appended to the table in order
+    /// with [`OwnedTable::concat`].
+    ///
+    /// # Errors
+    /// Returns [`ArrowIpcError::BatchSchemaMismatch`] if a record batch cannot be appended to the rows
+    /// read so far, and other variants of [`ArrowIpcError`] if the stream cannot be read or converted.
+    pub fn read_arrow_ipc_stream<R: Read>(reader: R) -> Result<Self, ArrowIpcError> {
+        let stream_reader = StreamReader::try_new(reader, None)?;
+        let mut table = Self::try_from(RecordBatch::new_empty(stream_reader.schema()))?;
+        for (batch, record_batch) in stream_reader.enumerate() {
+            table = table
+                .concat(Self::try_from(record_batch?)?)
+                .map_err(|source| ArrowIpcError::BatchSchemaMismatch { batch, source })?;
+        }
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{database::owned_table_utility::*, scalar::test_scalar::TestScalar};
+
+    #[test]
+    fn we_can_write_and_read_a_table_as_an_arrow_ipc_stream() {
+        let table = owned_table::<TestScalar>([
+            bigint("a", [1, 2, 3]),
+            varchar("b", ["x", "y", "z"]),
+            boolean("c", [true, false, true]),
+            decimal75("d", 10, 2, [100, -200, 300]),
+        ]);
+        let mut stream = Vec::new();
+        table.write_arrow_ipc_stream(&mut stream).unwrap();
+        assert_eq!(
+            OwnedTable::<TestScalar>::read_arrow_ipc_stream(stream.as_slice()).unwrap(),
+            table
+        );
+    }
+
+    #[test]
+    fn we_can_read_an_arrow_ipc_stream_with_many_record_batches() {
+        let batches = [
+            owned_table::<TestScalar>([bigint("a", [1, 2]), varchar("b", ["v", "w"])]),
+            owned_table::<TestScalar>([bigint("a", [0; 0]), varchar("b", [""; 0])]),
+            owned_table::<TestScalar>([bigint("a", [3, 4, 5]), varchar("b", ["x", "y", "z"])]),
+        ]
+        .map(|table| RecordBatch::try_from(table).unwrap());
+        let mut stream = Vec::new();
+        let mut writer = StreamWriter::try_new(&mut stream, &batches[0].schema()).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+        assert_eq!(
+            OwnedTable::<TestScalar>::read_arrow_ipc_stream(stream.as_slice()).unwrap(),
+            owned_table([
+                bigint("a", [1, 2, 3, 4, 5]),
+                varchar("b", ["v", "w", "x", "y", "z"]),
+            ])
+        );
+    }
+
+    #[test]
+    fn we_can_write_and_read_a_table_without_rows_as_an_arrow_ipc_stream() {
+        let table = owned_table::<TestScalar>([bigint("a", [0; 0]), int("b", [0; 0])]);
+        let mut stream = Vec::new();
+        table.write_arrow_ipc_stream(&mut stream).unwrap();
+        assert_eq!(
+            OwnedTable::<TestScalar>::read_arrow_ipc_stream(stream.as_slice()).unwrap(),
+            table
+        );
+    }
+
+    #[test]
+    fn we_cannot_read_data_that_is_not_an_arrow_ipc_stream() {
+        assert!(matches!(
+            OwnedTable::<TestScalar>::read_arrow_ipc_stream(&b"not an arrow stream"[..]),
+            Err(ArrowIpcError::Arrow { .. })
+        ));
+    }
+}
@@ -21,3 +21,10 @@ pub mod scalar_and_i256_conversions;
 
 /// Module for handling conversions between columns and Arrow arrays.
 pub mod column_arrow_conversions;
+
+/// Module for reading owned tables from Parquet files.
+#[cfg(feature = "parquet")]
+pub mod parquet_conversion;
+
+/// Module for streaming owned tables in the Arrow IPC stream format.
+pub mod ipc_conversion;
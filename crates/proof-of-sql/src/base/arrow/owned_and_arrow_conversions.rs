@@ -4,30 +4,39 @@
 //! `OwnedTable` <-> `RecordBatch`
 //! `Boolean` <-> `Boolean`
 //! `BigInt` <-> `Int64`
+//! `UInt64` <-> `UInt64`
+//! `Date` <-> `Date32`
 //! `VarChar` <-> `Utf8/String`
 //! `Int128` <-> `Decimal128(38,0)`
 //! `Decimal75` <-> `S`
+//! `Scalar` -> `Decimal256(75,0)`
 //!
 //! Note: this converts `Int128` values to `Decimal128(38,0)`, which are backed by `i128`.
 //! This is because there is no `Int128` type in Arrow.
 //! This does not check that the values are less than 39 digits.
 //! However, the actual arrow backing `i128` is the correct value.
-use super::scalar_and_i256_conversions::{convert_i256_to_scalar, convert_scalar_to_i256};
+//!
+//! Similarly, `Scalar` values are converted to `Decimal256(75,0)` using their signed representation.
+//! Converting back yields a `Decimal75` column with the same values.
+//! Since scalars can have more than 75 digits, converting an `OwnedTable` to a `RecordBatch` checks the precision
+//! of decimal columns and fails if a value does not fit.
+use super::scalar_and_i256_conversions::{convert_i256_to_scalar, convert_scalars_to_i256};
 use crate::base::{
     database::{OwnedColumn, OwnedTable, OwnedTableError},
     map::IndexMap,
-    math::decimal::Precision,
+    math::decimal::{Precision, MAX_SUPPORTED_PRECISION},
     posql_time::{PoSQLTimeUnit, PoSQLTimeZone, PoSQLTimestampError},
     scalar::Scalar,
 };
 use alloc::sync::Arc;
 use arrow::{
     array::{
-        ArrayRef, BinaryArray, BooleanArray, Decimal128Array, Decimal256Array, Int16Array,
-        Int32Array, Int64Array, Int8Array, StringArray, TimestampMicrosecondArray,
-        TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray, UInt8Array,
+        ArrayRef, BinaryArray, BooleanArray, Date32Array, Decimal128Array, Decimal256Array,
+        Int16Array, Int32Array, Int64Array, Int8Array, StringArray, TimestampMicrosecondArray,
+        TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray, UInt64Array,
+        UInt8Array,
     },
-    datatypes::{i256, DataType, Schema, SchemaRef, TimeUnit as ArrowTimeUnit},
+    datatypes::{DataType, Schema, SchemaRef, TimeUnit as ArrowTimeUnit},
     error::ArrowError,
     record_batch::RecordBatch,
 };
@@ -70,7 +79,6 @@ pub enum OwnedArrowConversionError {
 ///
 /// Will panic if setting precision and scale fails when converting `OwnedColumn::Int128`.
 /// Will panic if setting precision and scale fails when converting `OwnedColumn::Decimal75`.
-/// Will panic if trying to convert `OwnedColumn::Scalar`, as this conversion is not implemented
 impl<S: Scalar> From<OwnedColumn<S>> for ArrayRef {
     fn from(value: OwnedColumn<S>) -> Self {
         match value {
@@ -80,13 +88,15 @@ impl<S: Scalar> From<OwnedColumn<S>> for ArrayRef {
             OwnedColumn::SmallInt(col) => Arc::new(Int16Array::from(col)),
             OwnedColumn::Int(col) => Arc::new(Int32Array::from(col)),
             OwnedColumn::BigInt(col) => Arc::new(Int64Array::from(col)),
+            OwnedColumn::UInt64(col) => Arc::new(UInt64Array::from(col)),
+            OwnedColumn::Date(col) => Arc::new(Date32Array::from(col)),
             OwnedColumn::Int128(col) => Arc::new(
                 Decimal128Array::from(col)
                     .with_precision_and_scale(38, 0)
                     .unwrap(),
             ),
             OwnedColumn::Decimal75(precision, scale, col) => {
-                let converted_col: Vec<i256> = col.iter().map(convert_scalar_to_i256).collect();
+                let converted_col = convert_scalars_to_i256(&col);
 
                 Arc::new(
                     Decimal256Array::from(converted_col)
@@ -94,7 +104,15 @@ impl<S: Scalar> From<OwnedColumn<S>> for ArrayRef {
                         .unwrap(),
                 )
             }
-            OwnedColumn::Scalar(_) => unimplemented!("Cannot convert Scalar type to arrow type"),
+            OwnedColumn::Scalar(col) => {
+                let converted_col = convert_scalars_to_i256(&col);
+
+                Arc::new(
+                    Decimal256Array::from(converted_col)
+                        .with_precision_and_scale(MAX_SUPPORTED_PRECISION, 0)
+                        .unwrap(),
+                )
+            }
             OwnedColumn::VarChar(col) => Arc::new(StringArray::from(col)),
             OwnedColumn::VarBinary(col) => {
                 Arc::new(BinaryArray::from_iter_values(col.iter().map(Vec::as_slice)))
@@ -115,14 +133,19 @@ impl<S: Scalar> TryFrom<OwnedTable<S>> for RecordBatch {
         if value.is_empty() {
             Ok(RecordBatch::new_empty(SchemaRef::new(Schema::empty())))
         } else {
-            RecordBatch::try_from_iter(
-                value
-                    .into_inner()
-                    .into_iter()
-                    .map(|(identifier, owned_column)| {
-                        (identifier.value, ArrayRef::from(owned_column))
-                    }),
-            )
+            let columns = value
+                .into_inner()
+                .into_iter()
+                .map(|(identifier, owned_column)| {
+                    let array = ArrayRef::from(owned_column);
+                    // `Scalar` values can have more digits than a `Decimal256(75, 0)` allows.
+                    if let Some(decimals) = array.as_any().downcast_ref::<Decimal256Array>() {
+                        decimals.validate_decimal_precision(decimals.precision())?;
+                    }
+                    Ok((identifier.value, array))
+                })
+                .collect::<Result<Vec<_>, ArrowError>>()?;
+            RecordBatch::try_from_iter(columns)
         }
     }
 }
@@ -200,6 +223,22 @@ impl<S: Scalar> TryFrom<&ArrayRef> for OwnedColumn<S> {
                     .values()
                     .to_vec(),
             )),
+            DataType::UInt64 => Ok(Self::UInt64(
+                value
+                    .as_any()
+                    .downcast_ref::<UInt64Array>()
+                    .unwrap()
+                    .values()
+                    .to_vec(),
+            )),
+            DataType::Date32 => Ok(Self::Date(
+                value
+                    .as_any()
+                    .downcast_ref::<Date32Array>()
+                    .unwrap()
+                    .values()
+                    .to_vec(),
+            )),
             DataType::Decimal128(38, 0) => Ok(Self::Int128(
                 value
                     .as_any()
@@ -2,14 +2,16 @@ use super::owned_and_arrow_conversions::OwnedArrowConversionError;
 use crate::base::{
     database::{owned_table_utility::*, OwnedColumn, OwnedTable},
     map::IndexMap,
-    scalar::test_scalar::TestScalar,
+    scalar::{test_scalar::TestScalar, Scalar, ScalarExt},
 };
 use alloc::sync::Arc;
 use arrow::{
     array::{
-        ArrayRef, BinaryArray, BooleanArray, Decimal128Array, Float32Array, Int64Array, StringArray,
+        ArrayRef, BinaryArray, BooleanArray, Date32Array, Decimal128Array, Decimal256Array,
+        Float32Array, Int16Array, Int32Array, Int64Array, Int8Array, StringArray, UInt64Array,
     },
-    datatypes::{DataType, Field, Schema},
+    datatypes::{i256, DataType, Field, Schema},
+    error::ArrowError,
     record_batch::RecordBatch,
 };
 use proptest::prelude::*;
@@ -47,6 +49,12 @@ fn we_can_convert_between_bigint_owned_column_and_array_ref_impl(data: Vec<i64>)
         Arc::new(Int64Array::from(data)),
     );
 }
+fn we_can_convert_between_int_owned_column_and_array_ref_impl(data: Vec<i32>) {
+    we_can_convert_between_owned_column_and_array_ref_impl(
+        &OwnedColumn::<TestScalar>::Int(data.clone()),
+        Arc::new(Int32Array::from(data)),
+    );
+}
 fn we_can_convert_between_int128_owned_column_and_array_ref_impl(data: Vec<i128>) {
     we_can_convert_between_owned_column_and_array_ref_impl(
         &OwnedColumn::<TestScalar>::Int128(data.clone()),
@@ -67,12 +75,15 @@ fn we_can_convert_between_varchar_owned_column_and_array_ref_impl(data: Vec<Stri
 fn we_can_convert_between_owned_column_and_array_ref() {
     we_can_convert_between_boolean_owned_column_and_array_ref_impl(vec![]);
     we_can_convert_between_bigint_owned_column_and_array_ref_impl(vec![]);
+    we_can_convert_between_int_owned_column_and_array_ref_impl(vec![]);
     we_can_convert_between_int128_owned_column_and_array_ref_impl(vec![]);
     we_can_convert_between_varchar_owned_column_and_array_ref_impl(vec![]);
     let data = vec![true, false, true, false, true, false, true, false, true];
     we_can_convert_between_boolean_owned_column_and_array_ref_impl(data);
     let data = vec![0, 1, 2, 3, 4, 5, 6, i64::MIN, i64::MAX];
     we_can_convert_between_bigint_owned_column_and_array_ref_impl(data);
+    let data = vec![0, 1, -1, i32::MIN, i32::MAX];
+    we_can_convert_between_int_owned_column_and_array_ref_impl(data);
     let data = vec![0, 1, 2, 3, 4, 5, 6, i128::MIN, i128::MAX];
     we_can_convert_between_int128_owned_column_and_array_ref_impl(data);
     let data = vec!["0", "1", "2", "3", "4", "5", "6"];
@@ -193,10 +204,92 @@ fn we_can_convert_between_owned_table_and_record_batch() {
 }
 
 #[test]
-#[should_panic(expected = "not implemented: Cannot convert Scalar type to arrow type")]
-fn we_panic_when_converting_an_owned_table_with_a_scalar_column() {
-    let owned_table = owned_table::<TestScalar>([scalar("a", [0; 0])]);
-    let _ = RecordBatch::try_from(owned_table);
+fn we_can_convert_an_owned_table_with_a_scalar_column_to_a_decimal256_record_batch() {
+    let owned_table = owned_table::<TestScalar>([scalar("a", [0, 1, -1, i64::MAX])]);
+    let batch = RecordBatch::try_from(owned_table).unwrap();
+    assert_eq!(
+        batch.schema().field(0).data_type(),
+        &DataType::Decimal256(75, 0)
+    );
+    assert_eq!(
+        batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Decimal256Array>()
+            .unwrap()
+            .values()
+            .to_vec(),
+        vec![
+            i256::ZERO,
+            i256::ONE,
+            i256::MINUS_ONE,
+            i256::from_i128(i64::MAX.into())
+        ]
+    );
+    assert_eq!(
+        OwnedTable::<TestScalar>::try_from(batch).unwrap(),
+        owned_table([decimal75("a", 75, 0, [0, 1, -1, i64::MAX])])
+    );
+}
+
+#[test]
+fn we_cannot_convert_an_owned_table_with_a_scalar_of_more_than_75_digits_to_a_record_batch() {
+    let max_75_digits = TestScalar::pow10(75) - TestScalar::ONE;
+    let table = owned_table::<TestScalar>([scalar("a", [max_75_digits, -max_75_digits])]);
+    assert!(RecordBatch::try_from(table).is_ok());
+
+    let table = owned_table::<TestScalar>([scalar("a", [TestScalar::ZERO, TestScalar::pow10(75)])]);
+    assert!(matches!(
+        RecordBatch::try_from(table),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+    let table = owned_table::<TestScalar>([scalar("a", [-TestScalar::pow10(75)])]);
+    assert!(RecordBatch::try_from(table).is_err());
+}
+
+#[test]
+fn we_can_roundtrip_a_record_batch_through_an_owned_table() {
+    let batch = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![
+            Field::new("flag", DataType::Boolean, false),
+            Field::new("tiny", DataType::Int8, false),
+            Field::new("small", DataType::Int16, false),
+            Field::new("medium", DataType::Int32, false),
+            Field::new("big", DataType::Int64, false),
+            Field::new("unsigned", DataType::UInt64, false),
+            Field::new("day", DataType::Date32, false),
+            Field::new("huge", DataType::Decimal128(38, 0), false),
+            Field::new("money", DataType::Decimal256(10, 2), false),
+            Field::new("name", DataType::Utf8, false),
+            Field::new("bytes", DataType::Binary, false),
+        ])),
+        vec![
+            Arc::new(BooleanArray::from(vec![true, false])),
+            Arc::new(Int8Array::from(vec![i8::MIN, 1])),
+            Arc::new(Int16Array::from(vec![2, i16::MAX])),
+            Arc::new(Int32Array::from(vec![-3, 3])),
+            Arc::new(Int64Array::from(vec![i64::MAX, 0])),
+            Arc::new(UInt64Array::from(vec![u64::MAX, 4])),
+            Arc::new(Date32Array::from(vec![0, 19_000])),
+            Arc::new(
+                Decimal128Array::from(vec![i128::MIN, 5])
+                    .with_precision_and_scale(38, 0)
+                    .unwrap(),
+            ),
+            Arc::new(
+                Decimal256Array::from(vec![i256::from_i128(-12_345), i256::from_i128(600)])
+                    .with_precision_and_scale(10, 2)
+                    .unwrap(),
+            ),
+            Arc::new(StringArray::from(vec!["x", "yz"])),
+            Arc::new(BinaryArray::from(vec![&[1_u8, 2][..], &[]])),
+        ],
+    )
+    .unwrap();
+    let owned_table = OwnedTable::<TestScalar>::try_from(batch.clone()).unwrap();
+    let roundtripped = RecordBatch::try_from(owned_table).unwrap();
+    assert_eq!(roundtripped.schema(), batch.schema());
+    assert_eq!(roundtripped, batch);
 }
 
 proptest! {
@@ -208,3 +301,230 @@ proptest! {
         prop_assert_eq!(actual, owned_column);
     }
 }
+
+#[test]
+fn we_can_convert_between_owned_table_and_record_batch_with_int_columns() {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("int32", DataType::Int32, false),
+        Field::new("int64", DataType::Int64, false),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from(vec![i32::MIN, 0, i32::MAX])),
+            Arc::new(Int64Array::from(vec![1, 2, 3])),
+        ],
+    )
+    .unwrap();
+    we_can_convert_between_owned_table_and_record_batch_impl(
+        &owned_table([
+            int("int32", [i32::MIN, 0, i32::MAX]),
+            bigint("int64", [1, 2, 3]),
+        ]),
+        &batch,
+    );
+
+    let empty_batch = RecordBatch::new_empty(schema);
+    we_can_convert_between_owned_table_and_record_batch_impl(
+        &owned_table([int("int32", [0; 0]), bigint("int64", [0; 0])]),
+        &empty_batch,
+    );
+}
+
+#[test]
+fn we_can_convert_small_integer_columns_to_and_from_arrow_at_their_bounds() {
+    we_can_convert_between_owned_column_and_array_ref_impl(
+        &OwnedColumn::<TestScalar>::TinyInt(vec![i8::MIN, -1, 0, 1, i8::MAX]),
+        Arc::new(Int8Array::from(vec![i8::MIN, -1, 0, 1, i8::MAX])),
+    );
+    we_can_convert_between_owned_column_and_array_ref_impl(
+        &OwnedColumn::<TestScalar>::SmallInt(vec![i16::MIN, -1, 0, 1, i16::MAX]),
+        Arc::new(Int16Array::from(vec![i16::MIN, -1, 0, 1, i16::MAX])),
+    );
+    we_can_convert_between_owned_column_and_array_ref_impl(
+        &OwnedColumn::<TestScalar>::TinyInt(vec![]),
+        Arc::new(Int8Array::from(Vec::<i8>::new())),
+    );
+}
+
+#[test]
+fn we_can_convert_tables_mixing_small_integer_and_bigint_columns_to_and_from_record_batches() {
+    let batch = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![
+            Field::new("int8", DataType::Int8, false),
+            Field::new("int16", DataType::Int16, false),
+            Field::new("int64", DataType::Int64, false),
+        ])),
+        vec![
+            Arc::new(Int8Array::from(vec![i8::MIN, 0, i8::MAX])),
+            Arc::new(Int16Array::from(vec![i16::MIN, 0, i16::MAX])),
+            Arc::new(Int64Array::from(vec![i64::MIN, 0, i64::MAX])),
+        ],
+    )
+    .unwrap();
+    we_can_convert_between_owned_table_and_record_batch_impl(
+        &owned_table([
+            tinyint("int8", [i8::MIN, 0, i8::MAX]),
+            smallint("int16", [i16::MIN, 0, i16::MAX]),
+            bigint("int64", [i64::MIN, 0, i64::MAX]),
+        ]),
+        &batch,
+    );
+}
+
+#[test]
+fn we_can_convert_uint64_columns_to_and_from_arrow_without_wrapping() {
+    we_can_convert_between_owned_column_and_array_ref_impl(
+        &OwnedColumn::<TestScalar>::UInt64(vec![0, 1, 1 << 63, u64::MAX]),
+        Arc::new(UInt64Array::from(vec![0, 1, 1 << 63, u64::MAX])),
+    );
+    let batch = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![
+            Field::new("block", DataType::UInt64, false),
+            Field::new("int64", DataType::Int64, false),
+        ])),
+        vec![
+            Arc::new(UInt64Array::from(vec![u64::MAX, 0])),
+            Arc::new(Int64Array::from(vec![-1, 0])),
+        ],
+    )
+    .unwrap();
+    we_can_convert_between_owned_table_and_record_batch_impl(
+        &owned_table([uint64("block", [u64::MAX, 0]), bigint("int64", [-1, 0])]),
+        &batch,
+    );
+}
+
+#[test]
+fn we_can_convert_binary_columns_with_empty_and_ragged_values_to_and_from_arrow() {
+    let digest = [0xAB_u8; 32];
+    let values: [&[u8]; 4] = [&digest, &[], &[1, 2, 3], &[]];
+    let batch = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![
+            Field::new("hash", DataType::Binary, false),
+            Field::new("id", DataType::Int64, false),
+        ])),
+        vec![
+            Arc::new(BinaryArray::from(values.to_vec())),
+            Arc::new(Int64Array::from(vec![1, 2, 3, 4])),
+        ],
+    )
+    .unwrap();
+    we_can_convert_between_owned_table_and_record_batch_impl(
+        &owned_table([binary("hash", values), bigint("id", [1, 2, 3, 4])]),
+        &batch,
+    );
+}
+
+#[test]
+fn we_can_convert_date_columns_to_and_from_arrow_at_their_bounds() {
+    let days = vec![0, -1, -719_162, 2_932_896, i32::MIN, i32::MAX];
+    we_can_convert_between_owned_column_and_array_ref_impl(
+        &OwnedColumn::<TestScalar>::Date(days.clone()),
+        Arc::new(Date32Array::from(days.clone())),
+    );
+    let batch = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![
+            Field::new("day", DataType::Date32, false),
+            Field::new("int", DataType::Int32, false),
+        ])),
+        vec![
+            Arc::new(Date32Array::from(days.clone())),
+            Arc::new(Int32Array::from(days.clone())),
+        ],
+    )
+    .unwrap();
+    we_can_convert_between_owned_table_and_record_batch_impl(
+        &owned_table([date("day", days.clone()), int("int", days)]),
+        &batch,
+    );
+}
+
+#[test]
+fn we_can_convert_decimal_columns_with_different_scales_to_and_from_arrow() {
+    // The largest value with 38 digits
+    const MAX_DECIMAL_38: i128 = 99_999_999_999_999_999_999_999_999_999_999_999_999;
+    let batch = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![
+            Field::new("price", DataType::Decimal256(10, 2), false),
+            Field::new("rate", DataType::Decimal256(38, -3), false),
+        ])),
+        vec![
+            Arc::new(
+                Decimal256Array::from(vec![i256::from_i128(1_250), i256::from_i128(-1)])
+                    .with_precision_and_scale(10, 2)
+                    .unwrap(),
+            ),
+            Arc::new(
+                Decimal256Array::from(vec![i256::from_i128(7), i256::from_i128(MAX_DECIMAL_38)])
+                    .with_precision_and_scale(38, -3)
+                    .unwrap(),
+            ),
+        ],
+    )
+    .unwrap();
+    we_can_convert_between_owned_table_and_record_batch_impl(
+        &owned_table([
+            decimal("price", 10, 2, [1_250, -1]),
+            decimal("rate", 38, -3, [7, MAX_DECIMAL_38]),
+        ]),
+        &batch,
+    );
+}
+
+#[test]
+fn we_get_an_unsupported_type_error_when_converting_a_record_batch_with_an_unsupported_column() {
+    let batch = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Float32, false),
+        ])),
+        vec![
+            Arc::new(Int64Array::from(vec![1])),
+            Arc::new(Float32Array::from(vec![0.5])),
+        ],
+    )
+    .unwrap();
+    assert!(matches!(
+        OwnedTable::<TestScalar>::try_from(batch),
+        Err(OwnedArrowConversionError::UnsupportedType {
+            datatype: DataType::Float32
+        })
+    ));
+}
+
+#[test]
+fn we_preserve_column_names_and_values_when_converting_a_record_batch_to_an_owned_table() {
+    let batch = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![
+            Field::new("BlockNumber", DataType::UInt64, false),
+            Field::new("tx_hash", DataType::Binary, false),
+            Field::new("Amount", DataType::Int16, false),
+            Field::new("memo", DataType::Utf8, false),
+        ])),
+        vec![
+            Arc::new(UInt64Array::from(vec![7, u64::MAX])),
+            Arc::new(BinaryArray::from(vec![&[0xAB_u8][..], &[]])),
+            Arc::new(Int16Array::from(vec![i16::MIN, 3])),
+            Arc::new(StringArray::from(vec!["a", ""])),
+        ],
+    )
+    .unwrap();
+    let table = OwnedTable::<TestScalar>::try_from(batch).unwrap();
+    assert_eq!(
+        table
+            .column_names()
+            .map(|ident| ident.value.as_str())
+            .collect::<Vec<_>>(),
+        vec!["BlockNumber", "tx_hash", "Amount", "memo"]
+    );
+    assert_eq!(
+        table,
+        owned_table([
+            uint64("BlockNumber", [7, u64::MAX]),
+            binary("tx_hash", [&[0xAB_u8][..], &[]]),
+            smallint("Amount", [i16::MIN, 3]),
+            varchar("memo", ["a", ""]),
+        ])
+    );
+}
@@ -0,0 +1,166 @@
+use super::owned_and_arrow_conversions::OwnedArrowConversionError;
+use crate::base::{
+    database::{OwnedTable, OwnedTableError},
+    scalar::Scalar,
+};
+use arrow::record_batch::RecordBatch;
+use parquet::arrow::arrow_reader::{
+    ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReaderBuilder,
+};
+use snafu::Snafu;
+use std::{fs::File, path::Path};
+
+/// Errors that can occur when reading an [`OwnedTable`] from a Parquet file.
+#[derive(Snafu, Debug)]
+pub enum ParquetError {
+    /// The file could not be opened.
+    #[snafu(transparent)]
+    Io {
+        /// The underlying source error
+        source: std::io::Error,
+    },
+    /// The file is not a valid Parquet file.
+    #[snafu(transparent)]
+    Parquet {
+        /// The underlying source error
+        source: parquet::errors::ParquetError,
+    },
+    /// The data in the file could not be decoded into Arrow record batches.
+    #[snafu(transparent)]
+    Arrow {
+        /// The underlying source error
+        source: arrow::error::ArrowError,
+    },
+    /// The Arrow data could not be converted into an [`OwnedTable`].
+    #[snafu(transparent)]
+    Conversion {
+        /// The underlying source error
+        source: OwnedArrowConversionError,
+    },
+    /// A row group does not have the same schema as the row groups before it.
+    #[snafu(display("row group {row_group} has an incompatible schema: {source}"))]
+    RowGroupSchemaMismatch {
+        /// The index of the offending row group
+        row_group: usize,
+        /// The underlying source error
+        source: OwnedTableError,
+    },
+}
+
+impl<S: Scalar> OwnedTable<S> {
+    /// Reads the Parquet file at `path` into an [`OwnedTable`].
+    ///
+    /// Row groups are decoded one at a time and their columns are appended to the columns of the table,
+    /// so that only the output table and a single decoded row group are held in memory at once.
+    /// Uncompressed, Snappy and Zstandard compressed files are supported.
+    ///
+    /// # Errors
+    /// Returns [`ParquetError::RowGroupSchemaMismatch`] if a row group cannot be appended to the rows
+    /// read so far, and other variants of [`ParquetError`] if the file cannot be read or converted.
+    pub fn from_parquet_file(path: impl AsRef<Path>) -> Result<Self, ParquetError> {
+        let file = File::open(path)?;
+        let metadata = ArrowReaderMetadata::load(&file, ArrowReaderOptions::default())?;
+        let mut table = Self::try_from(RecordBatch::new_empty(metadata.schema().clone()))?;
+        for row_group in 0..metadata.metadata().num_row_groups() {
+            let reader = ParquetRecordBatchReaderBuilder::new_with_metadata(
+                file.try_clone()?,
+                metadata.clone(),
+            )
+            .with_row_groups(vec![row_group])
+            .build()?;
+            for batch in reader {
+                table
+                    .try_append(Self::try_from(batch?)?)
+                    .map_err(|source| ParquetError::RowGroupSchemaMismatch { row_group, source })?;
+            }
+        }
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{database::owned_table_utility::*, scalar::test_scalar::TestScalar};
+    use parquet::{
+        arrow::ArrowWriter,
+        basic::{Compression, ZstdLevel},
+        file::properties::WriterProperties,
+    };
+
+    fn write_parquet_file(
+        table: OwnedTable<TestScalar>,
+        max_row_group_size: usize,
+        compression: Compression,
+    ) -> tempfile::NamedTempFile {
+        let batch = RecordBatch::try_from(table).unwrap();
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let properties = WriterProperties::builder()
+            .set_max_row_group_size(max_row_group_size)
+            .set_compression(compression)
+            .build();
+        let mut writer =
+            ArrowWriter::try_new(file.reopen().unwrap(), batch.schema(), Some(properties)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        file
+    }
+
+    #[test]
+    fn we_can_read_a_parquet_file_with_many_row_groups() {
+        let table = owned_table::<TestScalar>([
+            bigint("a", [1, 2, 3, 4, 5]),
+            varchar("b", ["v", "w", "x", "y", "z"]),
+            boolean("c", [true, false, true, false, true]),
+        ]);
+        let file = write_parquet_file(table.clone(), 2, Compression::UNCOMPRESSED);
+        assert_eq!(
+            OwnedTable::<TestScalar>::from_parquet_file(file.path()).unwrap(),
+            table
+        );
+    }
+
+    #[test]
+    fn we_can_read_compressed_parquet_files() {
+        let table = owned_table::<TestScalar>([
+            bigint("a", [1, 2, 3, 4, 5]),
+            varchar("b", ["v", "w", "x", "y", "z"]),
+        ]);
+        for compression in [Compression::SNAPPY, Compression::ZSTD(ZstdLevel::default())] {
+            let file = write_parquet_file(table.clone(), 2, compression);
+            assert_eq!(
+                OwnedTable::<TestScalar>::from_parquet_file(file.path()).unwrap(),
+                table
+            );
+        }
+    }
+
+    #[test]
+    fn we_can_read_a_parquet_file_without_rows() {
+        let table = owned_table::<TestScalar>([bigint("a", [0; 0]), int("b", [0; 0])]);
+        let file = write_parquet_file(table.clone(), 2, Compression::UNCOMPRESSED);
+        assert_eq!(
+            OwnedTable::<TestScalar>::from_parquet_file(file.path()).unwrap(),
+            table
+        );
+    }
+
+    #[test]
+    fn we_cannot_read_a_missing_parquet_file() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(matches!(
+            OwnedTable::<TestScalar>::from_parquet_file(dir.path().join("missing.parquet")),
+            Err(ParquetError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn we_cannot_read_a_file_that_is_not_parquet() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), b"not parquet").unwrap();
+        assert!(matches!(
+            OwnedTable::<TestScalar>::from_parquet_file(file.path()),
+            Err(ParquetError::Parquet { .. })
+        ));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::base::{math, scalar::Scalar};
+use alloc::vec::Vec;
 use arrow::datatypes::i256;
 
 const MIN_SUPPORTED_I256: i256 = i256::from_parts(
@@ -27,6 +28,16 @@ pub fn convert_scalar_to_i256<S: Scalar>(val: &S) -> i256 {
     }
 }
 
+/// Converts a slice of types implementing [Scalar] into arrow i256s.
+///
+/// This is equivalent to calling [`convert_scalar_to_i256`] on each element, but allocates the output once.
+#[must_use]
+pub fn convert_scalars_to_i256<S: Scalar>(vals: &[S]) -> Vec<i256> {
+    let mut result = Vec::with_capacity(vals.len());
+    result.extend(vals.iter().map(convert_scalar_to_i256));
+    result
+}
+
 #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
 /// Converts an arrow i256 into limbed representation and then
 /// into a type implementing [Scalar]
@@ -65,12 +76,23 @@ impl From<i256> for math::i256::I256 {
     }
 }
 
+impl From<math::i256::I256> for i256 {
+    fn from(value: math::i256::I256) -> Self {
+        let limbs = value.limbs();
+        let low = u128::from(limbs[0]) | (u128::from(limbs[1]) << 64);
+        let high = i128::from(limbs[2]) | (i128::from(limbs[3]) << 64);
+        i256::from_parts(low, high)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
-    use crate::base::scalar::{test_scalar::TestScalar, Scalar};
-    use num_traits::Zero;
+    use crate::{
+        base::scalar::{test_scalar::TestScalar, Scalar},
+        proof_primitive::{dory::DoryScalar, hyperkzg::BNScalar},
+    };
     use rand::RngCore;
 
     /// Generate a random i256 within a supported range. Values generated by this function will
@@ -127,6 +149,15 @@ mod tests {
         assert_eq!(i256::from(min_scalar), expected_min);
     }
 
+    #[test]
+    fn test_u64_max_testscalar_i256_round_trip() {
+        let scalar = TestScalar::from(u64::MAX);
+        let value = i256::from(scalar);
+        assert_eq!(value, i256::from_i128(i128::from(u64::MAX)));
+        assert!(!value.is_negative());
+        assert_eq!(TestScalar::try_from(value).unwrap(), scalar);
+    }
+
     #[test]
     fn test_testscalar_i256_overflow_and_underflow() {
         // 2^256 overflows
@@ -157,7 +188,7 @@ mod tests {
         // Test conversion from i256(0) to TestScalar
         let zero_i256_testscalar = TestScalar::try_from(i256::from(0));
         assert!(zero_i256_testscalar.is_ok());
-        let zero_testscalar = TestScalar::zero();
+        let zero_testscalar = TestScalar::ZERO;
         assert_eq!(zero_i256_testscalar.unwrap(), zero_testscalar);
     }
 
@@ -252,4 +283,127 @@ mod tests {
             math::i256::I256::new([20, 0, u64::MAX - 19, u64::MAX])
         );
     }
+
+    #[test]
+    #[expect(clippy::cast_sign_loss)]
+    fn test_posql_i256_to_arrow_i256_conversion() {
+        for value in [
+            i256::ZERO,
+            i256::from(1),
+            i256::from(2),
+            i256::from(-1),
+            i256::from(-2),
+            i256::MAX,
+            i256::MIN,
+            i256::from_parts(40, 20),
+            i256::from_parts(20, -20),
+        ] {
+            let posql_value = math::i256::I256::from(value);
+            assert_eq!(i256::from(posql_value), value);
+        }
+        assert_eq!(
+            i256::from(math::i256::I256::new([
+                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+                i64::MAX as u64
+            ])),
+            i256::MAX
+        );
+        assert_eq!(
+            i256::from(math::i256::I256::new([0, 0, 0, i64::MIN as u64])),
+            i256::MIN
+        );
+    }
+
+    #[test]
+    fn batch_conversion_of_scalars_to_i256_matches_single_conversion() {
+        let mut rng = rand::thread_rng();
+        let scalars: Vec<TestScalar> = (0..1000)
+            .map(|_| TestScalar::try_from(random_i256(&mut rng)).unwrap())
+            .chain([
+                TestScalar::ZERO,
+                TestScalar::MAX_SIGNED,
+                -TestScalar::MAX_SIGNED,
+            ])
+            .collect();
+        let expected: Vec<i256> = scalars.iter().map(convert_scalar_to_i256).collect();
+        assert_eq!(convert_scalars_to_i256(&scalars), expected);
+        assert!(convert_scalars_to_i256::<TestScalar>(&[]).is_empty());
+    }
+
+    #[test]
+    fn dory_scalar_max_signed_converts_to_the_expected_i256() {
+        // (r - 1) / 2 for the BLS12-381 scalar field modulus r
+        let expected = i256::from_parts(
+            225_796_480_615_103_533_170_277_924_021_053_947_904,
+            77_047_593_810_979_328_214_411_077_263_450_762_242,
+        );
+        assert_eq!(convert_scalar_to_i256(&DoryScalar::MAX_SIGNED), expected);
+        assert_eq!(
+            convert_scalar_to_i256(&-DoryScalar::MAX_SIGNED),
+            expected.wrapping_neg()
+        );
+        // The BLS12-381 field is larger than the supported range, so its MAX_SIGNED does not convert back
+        assert!(expected > MAX_SUPPORTED_I256);
+        assert_eq!(convert_i256_to_scalar::<DoryScalar>(&expected), None);
+    }
+
+    /// Checks that every value in the supported range round-trips through `S` exactly as it does through [`TestScalar`].
+    fn assert_supported_i256_values_round_trip<S: Scalar>() {
+        let mut rng = rand::thread_rng();
+        let values = (0..1000).map(|_| random_i256(&mut rng)).chain([
+            i256::ZERO,
+            i256::ONE,
+            i256::MINUS_ONE,
+            i256::from_i128(i128::from(u64::MAX)),
+            MAX_SUPPORTED_I256,
+            MIN_SUPPORTED_I256,
+        ]);
+        for value in values {
+            let scalar = convert_i256_to_scalar::<S>(&value).unwrap();
+            assert_eq!(convert_scalar_to_i256(&scalar), value);
+            let test_scalar = convert_i256_to_scalar::<TestScalar>(&value).unwrap();
+            assert_eq!(convert_scalar_to_i256(&test_scalar), value);
+        }
+        assert!(convert_i256_to_scalar::<S>(&(MAX_SUPPORTED_I256 + i256::ONE)).is_none());
+        assert!(convert_i256_to_scalar::<S>(&(MIN_SUPPORTED_I256 - i256::ONE)).is_none());
+    }
+
+    #[test]
+    fn dory_scalar_i256_round_trips_match_testscalar() {
+        assert_supported_i256_values_round_trip::<DoryScalar>();
+    }
+
+    #[test]
+    fn bn_scalar_max_signed_converts_to_the_expected_i256() {
+        // (r - 1) / 2 for the BN254 scalar field modulus r
+        let expected = i256::from_parts(
+            196_860_502_576_623_995_926_439_750_070_219_833_344,
+            32_161_882_306_591_588_520_931_028_742_613_019_694,
+        );
+        assert_eq!(convert_scalar_to_i256(&BNScalar::MAX_SIGNED), expected);
+        assert_eq!(
+            convert_scalar_to_i256(&-BNScalar::MAX_SIGNED),
+            expected.wrapping_neg()
+        );
+        // Values on either side of the field boundary keep their sign
+        assert_eq!(
+            convert_scalar_to_i256(&(BNScalar::MAX_SIGNED + BNScalar::ONE)),
+            expected.wrapping_neg()
+        );
+        assert_eq!(
+            convert_scalar_to_i256(&(BNScalar::MAX_SIGNED - BNScalar::ONE)),
+            expected - i256::ONE
+        );
+        assert_eq!(convert_scalar_to_i256(&-BNScalar::ONE), i256::MINUS_ONE);
+        // The BN254 field is larger than the supported range, so its MAX_SIGNED does not convert back
+        assert!(expected > MAX_SUPPORTED_I256);
+        assert_eq!(convert_i256_to_scalar::<BNScalar>(&expected), None);
+    }
+
+    #[test]
+    fn bn_scalar_i256_round_trips_match_testscalar() {
+        assert_supported_i256_values_round_trip::<BNScalar>();
+    }
 }
@@ -9,6 +9,7 @@ use core::{
 };
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
+use snafu::Snafu;
 
 /// Describe the distribution of bit values in a table column
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
@@ -20,11 +21,13 @@ pub struct BitDistribution {
 }
 
 /// Errors associated with `BitDistribution`
-#[derive(Debug)]
+#[derive(Snafu, Debug)]
 pub enum BitDistributionError {
     /// No lead bit was provided when the lead bit is variable
+    #[snafu(display("no lead bit was provided when the lead bit is variable"))]
     NoLeadBit,
     /// Failed to verify bit decomposition
+    #[snafu(display("failed to verify bit decomposition"))]
     Verification,
 }
 
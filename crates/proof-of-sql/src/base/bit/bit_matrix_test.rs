@@ -1,7 +1,9 @@
 use super::*;
-use crate::base::{bit::BitDistribution, scalar::test_scalar::TestScalar};
+use crate::base::{
+    bit::BitDistribution,
+    scalar::{test_scalar::TestScalar, Scalar},
+};
 use bumpalo::Bump;
-use num_traits::{One, Zero};
 
 #[test]
 fn we_can_compute_the_bit_matrix_of_empty_data() {
@@ -14,7 +16,7 @@ fn we_can_compute_the_bit_matrix_of_empty_data() {
 
 #[test]
 fn we_can_compute_the_bit_matrix_for_a_single_element() {
-    let data: Vec<TestScalar> = vec![TestScalar::one()];
+    let data: Vec<TestScalar> = vec![TestScalar::ONE];
     let dist = BitDistribution::new::<TestScalar, _>(&data);
     let alloc = Bump::new();
     let matrix = compute_varying_bit_matrix(&alloc, &data, &dist);
@@ -23,7 +25,7 @@ fn we_can_compute_the_bit_matrix_for_a_single_element() {
 
 #[test]
 fn we_can_compute_the_bit_matrix_for_data_with_a_single_varying_bit() {
-    let data: Vec<TestScalar> = vec![TestScalar::one(), TestScalar::zero()];
+    let data: Vec<TestScalar> = vec![TestScalar::ONE, TestScalar::ZERO];
     let dist = BitDistribution::new::<TestScalar, _>(&data);
     let alloc = Bump::new();
     let matrix = compute_varying_bit_matrix(&alloc, &data, &dist);
@@ -34,7 +36,7 @@ fn we_can_compute_the_bit_matrix_for_data_with_a_single_varying_bit() {
 
 #[test]
 fn we_can_compute_the_bit_matrix_for_data_with_a_varying_sign_bit() {
-    let data: Vec<TestScalar> = vec![TestScalar::one(), -TestScalar::one()];
+    let data: Vec<TestScalar> = vec![TestScalar::ONE, -TestScalar::ONE];
     let dist = BitDistribution::new::<TestScalar, _>(&data);
     let alloc = Bump::new();
     let matrix = compute_varying_bit_matrix(&alloc, &data, &dist);
@@ -47,7 +49,7 @@ fn we_can_compute_the_bit_matrix_for_data_with_a_varying_sign_bit() {
 
 #[test]
 fn we_can_compute_the_bit_matrix_for_data_with_varying_bits_in_different_positions() {
-    let data: Vec<TestScalar> = vec![TestScalar::from(2), TestScalar::one()];
+    let data: Vec<TestScalar> = vec![TestScalar::from(2), TestScalar::ONE];
     let dist = BitDistribution::new::<TestScalar, _>(&data);
     let alloc = Bump::new();
     let matrix = compute_varying_bit_matrix(&alloc, &data, &dist);
@@ -77,7 +79,7 @@ fn we_can_compute_the_bit_matrix_for_data_with_varying_bits_and_constant_bits()
 fn we_can_compute_the_bit_matrix_for_data_entries_bigger_than_64_bit_integers() {
     let mut val = [0; 4];
     val[3] = 1 << 2;
-    let data: Vec<TestScalar> = vec![TestScalar::from_bigint(val), TestScalar::one()];
+    let data: Vec<TestScalar> = vec![TestScalar::from_bigint(val), TestScalar::ONE];
     let dist = BitDistribution::new::<TestScalar, _>(&data);
     let alloc = Bump::new();
     let matrix = compute_varying_bit_matrix(&alloc, &data, &dist);
@@ -217,6 +217,10 @@ pub enum ColumnBounds {
     Int128(Bounds<i128>),
     /// The bounds of a Timestamp column.
     TimestampTZ(Bounds<i64>),
+    /// The bounds of a `UInt64` column.
+    UInt64(Bounds<u64>),
+    /// The bounds of a `Date` column.
+    Date(Bounds<i32>),
 }
 
 impl ColumnBounds {
@@ -231,6 +235,8 @@ impl ColumnBounds {
             CommittableColumn::SmallInt(ints) => ColumnBounds::SmallInt(Bounds::from_iter(*ints)),
             CommittableColumn::Int(ints) => ColumnBounds::Int(Bounds::from_iter(*ints)),
             CommittableColumn::BigInt(ints) => ColumnBounds::BigInt(Bounds::from_iter(*ints)),
+            CommittableColumn::UInt64(ints) => ColumnBounds::UInt64(Bounds::from_iter(*ints)),
+            CommittableColumn::Date(days) => ColumnBounds::Date(Bounds::from_iter(*days)),
             CommittableColumn::Int128(ints) => ColumnBounds::Int128(Bounds::from_iter(*ints)),
             CommittableColumn::TimestampTZ(_, _, times) => {
                 ColumnBounds::TimestampTZ(Bounds::from_iter(*times))
@@ -264,6 +270,12 @@ impl ColumnBounds {
             (ColumnBounds::BigInt(bounds_a), ColumnBounds::BigInt(bounds_b)) => {
                 Ok(ColumnBounds::BigInt(bounds_a.union(bounds_b)))
             }
+            (ColumnBounds::UInt64(bounds_a), ColumnBounds::UInt64(bounds_b)) => {
+                Ok(ColumnBounds::UInt64(bounds_a.union(bounds_b)))
+            }
+            (ColumnBounds::Date(bounds_a), ColumnBounds::Date(bounds_b)) => {
+                Ok(ColumnBounds::Date(bounds_a.union(bounds_b)))
+            }
             (ColumnBounds::TimestampTZ(bounds_a), ColumnBounds::TimestampTZ(bounds_b)) => {
                 Ok(ColumnBounds::TimestampTZ(bounds_a.union(bounds_b)))
             }
@@ -299,6 +311,12 @@ impl ColumnBounds {
             (ColumnBounds::BigInt(bounds_a), ColumnBounds::BigInt(bounds_b)) => {
                 Ok(ColumnBounds::BigInt(bounds_a.difference(bounds_b)))
             }
+            (ColumnBounds::UInt64(bounds_a), ColumnBounds::UInt64(bounds_b)) => {
+                Ok(ColumnBounds::UInt64(bounds_a.difference(bounds_b)))
+            }
+            (ColumnBounds::Date(bounds_a), ColumnBounds::Date(bounds_b)) => {
+                Ok(ColumnBounds::Date(bounds_a.difference(bounds_b)))
+            }
             (ColumnBounds::Int128(bounds_a), ColumnBounds::Int128(bounds_b)) => {
                 Ok(ColumnBounds::Int128(bounds_a.difference(bounds_b)))
             }
@@ -49,6 +49,8 @@ impl ColumnCommitmentMetadata {
             | (ColumnType::SmallInt, ColumnBounds::SmallInt(_))
             | (ColumnType::Int, ColumnBounds::Int(_))
             | (ColumnType::BigInt, ColumnBounds::BigInt(_))
+            | (ColumnType::UInt64, ColumnBounds::UInt64(_))
+            | (ColumnType::Date, ColumnBounds::Date(_))
             | (ColumnType::Int128, ColumnBounds::Int128(_))
             | (ColumnType::TimestampTZ(_, _), ColumnBounds::TimestampTZ(_))
             | (
@@ -86,6 +88,14 @@ impl ColumnCommitmentMetadata {
                 BoundsInner::try_new(i64::MIN, i64::MAX)
                     .expect("i64::MIN and i64::MAX are valid bounds for BigInt"),
             )),
+            ColumnType::UInt64 => ColumnBounds::UInt64(super::Bounds::Bounded(
+                BoundsInner::try_new(u64::MIN, u64::MAX)
+                    .expect("u64::MIN and u64::MAX are valid bounds for UInt64"),
+            )),
+            ColumnType::Date => ColumnBounds::Date(super::Bounds::Bounded(
+                BoundsInner::try_new(i32::MIN, i32::MAX)
+                    .expect("i32::MIN and i32::MAX are valid bounds for Date"),
+            )),
             ColumnType::TimestampTZ(_, _) => ColumnBounds::TimestampTZ(super::Bounds::Bounded(
                 BoundsInner::try_new(i64::MIN, i64::MAX)
                     .expect("i64::MIN and i64::MAX are valid bounds for TimeStamp"),
@@ -18,6 +18,13 @@ pub trait CommitmentEvaluationProof {
     /// The public setup parameters required by the verifier.
     /// This is simply precomputed data that is required by the verifier to verify a proof.
     type VerifierPublicSetup<'a>: Copy;
+    /// The largest number of rows that a proof created with `setup` can cover, or `None` if `setup` imposes no limit.
+    ///
+    /// The default implementation imposes no limit.
+    #[must_use]
+    fn max_table_size(_setup: &Self::ProverPublicSetup<'_>) -> Option<usize> {
+        None
+    }
     /// Create a new proof.
     ///
     /// Note: `b_point` must have length `nu`, where `2^nu` is at least the length of `a`.
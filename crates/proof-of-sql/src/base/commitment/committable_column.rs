@@ -35,6 +35,8 @@ pub enum CommittableColumn<'a> {
     Int(&'a [i32]),
     /// Borrowed `BigInt` column, mapped to `i64`.
     BigInt(&'a [i64]),
+    /// Borrowed `UInt64` column, mapped to `u64`.
+    UInt64(&'a [u64]),
     /// Borrowed Int128 column, mapped to `i128`.
     Int128(&'a [i128]),
     /// Borrowed Decimal75(precision, scale, column), mapped to 'i256'
@@ -47,6 +49,8 @@ pub enum CommittableColumn<'a> {
     VarBinary(Vec<[u64; 4]>),
     /// Borrowed Timestamp column with Timezone, mapped to `i64`.
     TimestampTZ(PoSQLTimeUnit, PoSQLTimeZone, &'a [i64]),
+    /// Borrowed `Date` column, mapped to `i32` days since the unix epoch.
+    Date(&'a [i32]),
 }
 
 impl CommittableColumn<'_> {
@@ -57,8 +61,9 @@ impl CommittableColumn<'_> {
             CommittableColumn::Uint8(col) => col.len(),
             CommittableColumn::TinyInt(col) => col.len(),
             CommittableColumn::SmallInt(col) => col.len(),
-            CommittableColumn::Int(col) => col.len(),
+            CommittableColumn::Int(col) | CommittableColumn::Date(col) => col.len(),
             CommittableColumn::BigInt(col) | CommittableColumn::TimestampTZ(_, _, col) => col.len(),
+            CommittableColumn::UInt64(col) => col.len(),
             CommittableColumn::Int128(col) => col.len(),
             CommittableColumn::Decimal75(_, _, col)
             | CommittableColumn::Scalar(col)
@@ -89,6 +94,7 @@ impl<'a> From<&CommittableColumn<'a>> for ColumnType {
             CommittableColumn::SmallInt(_) => ColumnType::SmallInt,
             CommittableColumn::Int(_) => ColumnType::Int,
             CommittableColumn::BigInt(_) => ColumnType::BigInt,
+            CommittableColumn::UInt64(_) => ColumnType::UInt64,
             CommittableColumn::Int128(_) => ColumnType::Int128,
             CommittableColumn::Decimal75(precision, scale, _) => {
                 ColumnType::Decimal75(*precision, *scale)
@@ -98,6 +104,7 @@ impl<'a> From<&CommittableColumn<'a>> for ColumnType {
             CommittableColumn::VarBinary(_) => ColumnType::VarBinary,
             CommittableColumn::Boolean(_) => ColumnType::Boolean,
             CommittableColumn::TimestampTZ(tu, tz, _) => ColumnType::TimestampTZ(*tu, *tz),
+            CommittableColumn::Date(_) => ColumnType::Date,
         }
     }
 }
@@ -111,6 +118,7 @@ impl<'a, S: Scalar> From<&Column<'a, S>> for CommittableColumn<'a> {
             Column::SmallInt(ints) => CommittableColumn::SmallInt(ints),
             Column::Int(ints) => CommittableColumn::Int(ints),
             Column::BigInt(ints) => CommittableColumn::BigInt(ints),
+            Column::UInt64(ints) => CommittableColumn::UInt64(ints),
             Column::Int128(ints) => CommittableColumn::Int128(ints),
             Column::Decimal75(precision, scale, decimals) => {
                 let as_limbs: Vec<_> = decimals.iter().map(RefInto::<[u64; 4]>::ref_into).collect();
@@ -126,6 +134,7 @@ impl<'a, S: Scalar> From<&Column<'a, S>> for CommittableColumn<'a> {
                 CommittableColumn::VarBinary(as_limbs)
             }
             Column::TimestampTZ(tu, tz, times) => CommittableColumn::TimestampTZ(*tu, *tz, times),
+            Column::Date(days) => CommittableColumn::Date(days),
         }
     }
 }
@@ -145,6 +154,8 @@ impl<'a, S: Scalar> From<&'a OwnedColumn<S>> for CommittableColumn<'a> {
             OwnedColumn::SmallInt(ints) => (ints as &[_]).into(),
             OwnedColumn::Int(ints) => (ints as &[_]).into(),
             OwnedColumn::BigInt(ints) => (ints as &[_]).into(),
+            OwnedColumn::UInt64(ints) => (ints as &[_]).into(),
+            OwnedColumn::Date(days) => CommittableColumn::Date(days),
             OwnedColumn::Int128(ints) => (ints as &[_]).into(),
             OwnedColumn::Decimal75(precision, scale, decimals) => CommittableColumn::Decimal75(
                 *precision,
@@ -204,6 +215,12 @@ impl<'a> From<&'a [i64]> for CommittableColumn<'a> {
     }
 }
 
+impl<'a> From<&'a [u64]> for CommittableColumn<'a> {
+    fn from(value: &'a [u64]) -> Self {
+        CommittableColumn::UInt64(value)
+    }
+}
+
 impl<'a> From<&'a [i128]> for CommittableColumn<'a> {
     fn from(value: &'a [i128]) -> Self {
         CommittableColumn::Int128(value)
@@ -227,8 +244,9 @@ impl<'a, 'b> From<&'a CommittableColumn<'b>> for Sequence<'a> {
             CommittableColumn::Uint8(ints) => Sequence::from(*ints),
             CommittableColumn::TinyInt(ints) => Sequence::from(*ints),
             CommittableColumn::SmallInt(ints) => Sequence::from(*ints),
-            CommittableColumn::Int(ints) => Sequence::from(*ints),
+            CommittableColumn::Int(ints) | CommittableColumn::Date(ints) => Sequence::from(*ints),
             CommittableColumn::BigInt(ints) => Sequence::from(*ints),
+            CommittableColumn::UInt64(ints) => Sequence::from(*ints),
             CommittableColumn::Int128(ints) => Sequence::from(*ints),
             CommittableColumn::Decimal75(_, _, limbs)
             | CommittableColumn::Scalar(limbs)
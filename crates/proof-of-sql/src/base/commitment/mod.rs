@@ -89,6 +89,30 @@ pub trait Commitment:
     ///
     /// This is also useful for serialization purposes.
     fn to_transcript_bytes(&self) -> Vec<u8>;
+
+    /// Computes the linear combination `sum(weights[i] * commitments[i])` of the given commitments.
+    ///
+    /// Since commitments are homomorphic, this is a commitment to the same linear combination of the committed columns.
+    /// This allows proving properties of whole rows, or across several columns, with a single commitment.
+    /// An empty slice of commitments aggregates to the default (zero) commitment.
+    ///
+    /// # Errors
+    /// Returns [`NumColumnsMismatch`] if the number of weights differs from the number of commitments.
+    fn try_aggregate(
+        commitments: &[Self],
+        weights: &[Self::Scalar],
+    ) -> Result<Self, NumColumnsMismatch> {
+        if commitments.len() != weights.len() {
+            return Err(NumColumnsMismatch);
+        }
+        Ok(commitments.iter().zip(weights).fold(
+            Self::default(),
+            |mut acc, (commitment, weight)| {
+                acc += *weight * commitment;
+                acc
+            },
+        ))
+    }
 }
 
 mod commitment_evaluation_proof;
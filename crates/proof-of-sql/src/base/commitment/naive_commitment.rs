@@ -132,12 +132,15 @@ impl Commitment for NaiveCommitment {
                         .iter()
                         .map(core::convert::Into::into)
                         .collect(),
-                    CommittableColumn::Int(int_vec) => {
+                    CommittableColumn::Int(int_vec) | CommittableColumn::Date(int_vec) => {
                         int_vec.iter().map(core::convert::Into::into).collect()
                     }
                     CommittableColumn::BigInt(big_int_vec) => {
                         big_int_vec.iter().map(core::convert::Into::into).collect()
                     }
+                    CommittableColumn::UInt64(u64_vec) => {
+                        u64_vec.iter().map(core::convert::Into::into).collect()
+                    }
                     CommittableColumn::Int128(int_128_vec) => {
                         int_128_vec.iter().map(core::convert::Into::into).collect()
                     }
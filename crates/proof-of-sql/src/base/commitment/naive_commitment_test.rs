@@ -404,3 +404,43 @@ fn we_can_do_scalar_multiplication() {
 }
 
 // Scalar Multiplication Tests End
+
+#[test]
+fn we_can_aggregate_commitments_with_weights() {
+    let commitment_a = NaiveCommitment(vec![TestScalar::from(1), TestScalar::from(2)]);
+    let commitment_b = NaiveCommitment(vec![
+        TestScalar::from(10),
+        TestScalar::from(20),
+        TestScalar::ONE,
+    ]);
+    let aggregate = NaiveCommitment::try_aggregate(
+        &[commitment_a.clone(), commitment_b.clone()],
+        &[TestScalar::from(3), -TestScalar::ONE],
+    )
+    .unwrap();
+    assert_eq!(
+        aggregate,
+        NaiveCommitment(vec![
+            TestScalar::from(-7),
+            TestScalar::from(-14),
+            -TestScalar::ONE
+        ])
+    );
+    assert_eq!(
+        NaiveCommitment::try_aggregate(&[commitment_a.clone()], &[TestScalar::ONE]).unwrap(),
+        commitment_a
+    );
+    assert_eq!(
+        NaiveCommitment::try_aggregate(&[], &[]).unwrap(),
+        NaiveCommitment::default()
+    );
+}
+
+#[test]
+fn we_cannot_aggregate_commitments_with_mismatched_weights() {
+    let commitment = NaiveCommitment(vec![TestScalar::ONE]);
+    assert!(NaiveCommitment::try_aggregate(&[commitment.clone()], &[]).is_err());
+    assert!(
+        NaiveCommitment::try_aggregate(&[commitment], &[TestScalar::ONE, TestScalar::TWO]).is_err()
+    );
+}
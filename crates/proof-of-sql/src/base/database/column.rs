@@ -36,6 +36,10 @@ pub enum Column<'a, S: Scalar> {
     Int(&'a [i32]),
     /// i64 columns
     BigInt(&'a [i64]),
+    /// u64 columns
+    UInt64(&'a [u64]),
+    /// Date columns, stored as days since the unix epoch
+    Date(&'a [i32]),
     /// i128 columns
     Int128(&'a [i128]),
     /// Decimal columns with a max width of 252 bits
@@ -67,6 +71,8 @@ impl<'a, S: Scalar> Column<'a, S> {
             Self::SmallInt(_) => ColumnType::SmallInt,
             Self::Int(_) => ColumnType::Int,
             Self::BigInt(_) => ColumnType::BigInt,
+            Self::UInt64(_) => ColumnType::UInt64,
+            Self::Date(_) => ColumnType::Date,
             Self::VarChar(_) => ColumnType::VarChar,
             Self::Int128(_) => ColumnType::Int128,
             Self::Scalar(_) => ColumnType::Scalar,
@@ -89,6 +95,8 @@ impl<'a, S: Scalar> Column<'a, S> {
             Self::SmallInt(col) => col.len(),
             Self::Int(col) => col.len(),
             Self::BigInt(col) | Self::TimestampTZ(_, _, col) => col.len(),
+            Self::UInt64(col) => col.len(),
+            Self::Date(col) => col.len(),
             Self::VarChar((col, scals)) => {
                 assert_eq!(col.len(), scals.len());
                 col.len()
@@ -130,6 +138,10 @@ impl<'a, S: Scalar> Column<'a, S> {
             LiteralValue::BigInt(value) => {
                 Column::BigInt(alloc.alloc_slice_fill_copy(length, *value))
             }
+            LiteralValue::UInt64(value) => {
+                Column::UInt64(alloc.alloc_slice_fill_copy(length, *value))
+            }
+            LiteralValue::Date(value) => Column::Date(alloc.alloc_slice_fill_copy(length, *value)),
             LiteralValue::Int128(value) => {
                 Column::Int128(alloc.alloc_slice_fill_copy(length, *value))
             }
@@ -178,6 +190,8 @@ impl<'a, S: Scalar> Column<'a, S> {
             OwnedColumn::SmallInt(col) => Column::SmallInt(col.as_slice()),
             OwnedColumn::Int(col) => Column::Int(col.as_slice()),
             OwnedColumn::BigInt(col) => Column::BigInt(col.as_slice()),
+            OwnedColumn::UInt64(col) => Column::UInt64(col.as_slice()),
+            OwnedColumn::Date(col) => Column::Date(col.as_slice()),
             OwnedColumn::Int128(col) => Column::Int128(col.as_slice()),
             OwnedColumn::Decimal75(precision, scale, col) => {
                 Column::Decimal75(*precision, *scale, col.as_slice())
@@ -257,6 +271,22 @@ impl<'a, S: Scalar> Column<'a, S> {
         }
     }
 
+    /// Returns the column as a slice of u64 if it is a uint64 column. Otherwise, returns None.
+    pub(crate) fn as_uint64(&self) -> Option<&'a [u64]> {
+        match self {
+            Self::UInt64(col) => Some(col),
+            _ => None,
+        }
+    }
+
+    /// Returns the column as a slice of i32 if it is a date column. Otherwise, returns None.
+    pub(crate) fn as_date(&self) -> Option<&'a [i32]> {
+        match self {
+            Self::Date(col) => Some(col),
+            _ => None,
+        }
+    }
+
     /// Returns the column as a slice of i128 if it is an int128 column. Otherwise, returns None.
     pub(crate) fn as_int128(&self) -> Option<&'a [i128]> {
         match self {
@@ -316,6 +346,8 @@ impl<'a, S: Scalar> Column<'a, S> {
             Self::SmallInt(col) => S::from(col[index]),
             Self::Int(col) => S::from(col[index]),
             Self::BigInt(col) | Self::TimestampTZ(_, _, col) => S::from(col[index]),
+            Self::UInt64(col) => S::from(col[index]),
+            Self::Date(col) => S::from(col[index]),
             Self::Int128(col) => S::from(col[index]),
             Self::Scalar(col) | Self::Decimal75(_, _, col) => col[index],
             Self::VarChar((_, scals)) | Self::VarBinary((_, scals)) => scals[index],
@@ -336,6 +368,8 @@ impl<'a, S: Scalar> Column<'a, S> {
             Self::SmallInt(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
             Self::Int(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
             Self::BigInt(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
+            Self::UInt64(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
+            Self::Date(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
             Self::Int128(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
             Self::Scalar(col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
             Self::TimestampTZ(_, _, col) => slice_cast_with(col, |i| S::from(i) * scale_factor),
@@ -389,6 +423,14 @@ pub enum ColumnType {
     /// Mapped to [u8]
     #[serde(alias = "BINARY", alias = "BINARY")]
     VarBinary,
+    /// Mapped to u64
+    #[serde(alias = "UINT64", alias = "uint64")]
+    #[cfg_attr(test, proptest(skip))]
+    UInt64,
+    /// Mapped to i32, which is days since the unix epoch
+    #[serde(alias = "DATE", alias = "date")]
+    #[cfg_attr(test, proptest(skip))]
+    Date,
 }
 
 impl ColumnType {
@@ -513,8 +555,9 @@ impl ColumnType {
         match self {
             Self::Uint8 | Self::TinyInt => Some(3_u8),
             Self::SmallInt => Some(5_u8),
-            Self::Int => Some(10_u8),
+            Self::Int | Self::Date => Some(10_u8),
             Self::BigInt | Self::TimestampTZ(_, _) => Some(19_u8),
+            Self::UInt64 => Some(20_u8),
             Self::Int128 => Some(39_u8),
             Self::Decimal75(precision, _) => Some(precision.value()),
             // Scalars are not in database & are only used for typeless comparisons for testing so we return 0
@@ -533,6 +576,8 @@ impl ColumnType {
             | Self::SmallInt
             | Self::Int
             | Self::BigInt
+            | Self::UInt64
+            | Self::Date
             | Self::Int128
             | Self::Scalar => Some(0),
             Self::Boolean | Self::VarBinary | Self::VarChar => None,
@@ -553,8 +598,9 @@ impl ColumnType {
             Self::Uint8 => size_of::<u8>(),
             Self::TinyInt => size_of::<i8>(),
             Self::SmallInt => size_of::<i16>(),
-            Self::Int => size_of::<i32>(),
+            Self::Int | Self::Date => size_of::<i32>(),
             Self::BigInt | Self::TimestampTZ(_, _) => size_of::<i64>(),
+            Self::UInt64 => size_of::<u64>(),
             Self::Int128 => size_of::<i128>(),
             Self::Scalar | Self::Decimal75(_, _) | Self::VarBinary | Self::VarChar => {
                 size_of::<[u64; 4]>()
@@ -578,13 +624,15 @@ impl ColumnType {
             | Self::Int
             | Self::BigInt
             | Self::Int128
-            | Self::TimestampTZ(_, _) => true,
+            | Self::TimestampTZ(_, _)
+            | Self::Date => true,
             Self::Decimal75(_, _)
             | Self::Scalar
             | Self::VarBinary
             | Self::VarChar
             | Self::Boolean
-            | Self::Uint8 => false,
+            | Self::Uint8
+            | Self::UInt64 => false,
         }
     }
 
@@ -612,6 +660,8 @@ impl Display for ColumnType {
             ColumnType::SmallInt => write!(f, "SMALLINT"),
             ColumnType::Int => write!(f, "INT"),
             ColumnType::BigInt => write!(f, "BIGINT"),
+            ColumnType::UInt64 => write!(f, "UINT64"),
+            ColumnType::Date => write!(f, "DATE"),
             ColumnType::Int128 => write!(f, "DECIMAL"),
             ColumnType::Decimal75(precision, scale) => {
                 write!(
@@ -0,0 +1,50 @@
+use super::{ColumnType, OwnedColumn};
+use crate::base::scalar::Scalar;
+use alloc::{string::String, vec::Vec};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A Rust type that is stored directly in an [`OwnedColumn`] variant.
+///
+/// This trait is sealed and is used by [`OwnedTable::downcast_column`](super::OwnedTable::downcast_column).
+pub trait ColumnDataType: sealed::Sealed + Sized {
+    /// The column type of the variant holding values of this type.
+    const COLUMN_TYPE: ColumnType;
+
+    /// Returns the values of `column` if it is the variant holding this type.
+    fn from_owned_column<S: Scalar>(column: &OwnedColumn<S>) -> Option<&Vec<Self>>;
+}
+
+macro_rules! impl_column_data_type {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $ty {}
+
+            impl ColumnDataType for $ty {
+                const COLUMN_TYPE: ColumnType = ColumnType::$variant;
+
+                fn from_owned_column<S: Scalar>(column: &OwnedColumn<S>) -> Option<&Vec<Self>> {
+                    match column {
+                        OwnedColumn::$variant(values) => Some(values),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_column_data_type!(
+    bool => Boolean,
+    u8 => Uint8,
+    i8 => TinyInt,
+    i16 => SmallInt,
+    i32 => Int,
+    i64 => BigInt,
+    u64 => UInt64,
+    i128 => Int128,
+    String => VarChar,
+    Vec<u8> => VarBinary,
+);
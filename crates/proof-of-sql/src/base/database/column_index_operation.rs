@@ -58,6 +58,20 @@ where
             )?;
             Ok(Column::BigInt(alloc.alloc_slice_copy(&raw_values) as &[_]))
         }
+        ColumnType::UInt64 => {
+            let raw_values = apply_slice_to_indexes(
+                column.as_uint64().expect("Column types should match"),
+                indexes,
+            )?;
+            Ok(Column::UInt64(alloc.alloc_slice_copy(&raw_values) as &[_]))
+        }
+        ColumnType::Date => {
+            let raw_values = apply_slice_to_indexes(
+                column.as_date().expect("Column types should match"),
+                indexes,
+            )?;
+            Ok(Column::Date(alloc.alloc_slice_copy(&raw_values) as &[_]))
+        }
         ColumnType::Int128 => {
             let raw_values = apply_slice_to_indexes(
                 column.as_int128().expect("Column types should match"),
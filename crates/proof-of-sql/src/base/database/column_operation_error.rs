@@ -1,4 +1,7 @@
-use crate::base::{database::ColumnType, math::decimal::DecimalError};
+use crate::base::{
+    database::{ColumnType, DateComponent},
+    math::decimal::DecimalError,
+};
 use alloc::string::String;
 use core::result::Result;
 use snafu::Snafu;
@@ -46,6 +49,20 @@ pub enum ColumnOperationError {
     #[snafu(display("Division by zero"))]
     DivisionByZero,
 
+    /// A timestamp that cannot be represented as a calendar date and time
+    #[snafu(display("Timestamp {timestamp} is out of range"))]
+    TimestampOutOfRange {
+        /// The out of range timestamp
+        timestamp: i64,
+    },
+
+    /// A granularity that timestamps cannot be truncated to
+    #[snafu(display("Cannot truncate timestamps to {granularity:?}"))]
+    UnsupportedDateTruncation {
+        /// The unsupported granularity
+        granularity: DateComponent,
+    },
+
     /// Errors related to decimal operations
     #[snafu(transparent)]
     DecimalConversionError {
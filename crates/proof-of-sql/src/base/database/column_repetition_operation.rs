@@ -54,6 +54,18 @@ pub trait RepetitionOp {
                     iter.next().expect("Iterator should have enough elements")
                 }) as &[_])
             }
+            ColumnType::UInt64 => {
+                let mut iter = Self::op(column.as_uint64().expect("Column types should match"), n);
+                Column::UInt64(alloc.alloc_slice_fill_with(len, |_| {
+                    iter.next().expect("Iterator should have enough elements")
+                }) as &[_])
+            }
+            ColumnType::Date => {
+                let mut iter = Self::op(column.as_date().expect("Column types should match"), n);
+                Column::Date(alloc.alloc_slice_fill_with(len, |_| {
+                    iter.next().expect("Iterator should have enough elements")
+                }) as &[_])
+            }
             ColumnType::Int128 => {
                 let mut iter = Self::op(column.as_int128().expect("Column types should match"), n);
                 Column::Int128(alloc.alloc_slice_fill_with(len, |_| {
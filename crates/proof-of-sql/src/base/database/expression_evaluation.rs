@@ -1,6 +1,6 @@
 use super::{ExpressionEvaluationError, ExpressionEvaluationResult};
 use crate::base::{
-    database::{OwnedColumn, OwnedTable},
+    database::{OwnedColumn, OwnedTable, OwnedTableError},
     math::{
         decimal::{try_convert_intermediate_decimal_to_scalar, DecimalError, Precision},
         BigDecimalExt,
@@ -27,6 +27,25 @@ impl<S: Scalar> OwnedTable<S> {
         }
     }
 
+    /// Returns a copy of the table with an extra column `name` holding the result of evaluating `expr`.
+    ///
+    /// This is a convenience for building test fixtures and does not produce a proof.
+    pub fn with_computed_column(
+        &self,
+        name: Ident,
+        expr: &Expression,
+    ) -> Result<Self, OwnedTableError> {
+        if self.inner_table().contains_key(&name) {
+            return Err(OwnedTableError::DuplicateColumnName {
+                column_name: name.value,
+            });
+        }
+        let column = self.evaluate(expr)?;
+        let mut table = self.inner_table().clone();
+        table.insert(name, column);
+        Self::try_new(table)
+    }
+
     fn evaluate_column(&self, identifier: &Ident) -> ExpressionEvaluationResult<OwnedColumn<S>> {
         Ok(self
             .inner_table()
@@ -1,7 +1,7 @@
 use crate::base::{
     database::{
         owned_table_utility::*, ColumnOperationError, ExpressionEvaluationError, OwnedColumn,
-        OwnedTable,
+        OwnedTable, OwnedTableError,
     },
     math::decimal::Precision,
     scalar::test_scalar::TestScalar,
@@ -242,3 +242,36 @@ fn we_cannot_evaluate_expressions_if_column_operation_errors_out() {
         })
     ));
 }
+
+#[test]
+fn we_can_add_a_computed_column_to_a_table() {
+    let table: OwnedTable<TestScalar> =
+        owned_table([bigint("revenue", [10, 20, 30]), bigint("cost", [3, 25, 30])]);
+    let actual = table
+        .with_computed_column("profit".into(), &sub(col("revenue"), col("cost")))
+        .unwrap();
+    let expected: OwnedTable<TestScalar> = owned_table([
+        bigint("revenue", [10, 20, 30]),
+        bigint("cost", [3, 25, 30]),
+        bigint("profit", [7, -5, 0]),
+    ]);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn we_cannot_add_a_computed_column_with_a_duplicate_name_or_invalid_expression() {
+    let table: OwnedTable<TestScalar> =
+        owned_table([bigint("revenue", [10, 20, 30]), bigint("cost", [3, 25, 30])]);
+    assert_eq!(
+        table.with_computed_column("cost".into(), &lit(1)),
+        Err(OwnedTableError::DuplicateColumnName {
+            column_name: "cost".to_string()
+        })
+    );
+    assert!(matches!(
+        table.with_computed_column("profit".into(), &sub(col("revenue"), col("tax"))),
+        Err(OwnedTableError::ExpressionEvaluationError {
+            source: ExpressionEvaluationError::ColumnNotFound { .. }
+        })
+    ));
+}
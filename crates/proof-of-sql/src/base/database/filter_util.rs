@@ -58,6 +58,12 @@ pub fn filter_column_by_index<'a, S: Scalar>(
         Column::BigInt(col) => {
             Column::BigInt(alloc.alloc_slice_fill_iter(indexes.iter().map(|&i| col[i])))
         }
+        Column::UInt64(col) => {
+            Column::UInt64(alloc.alloc_slice_fill_iter(indexes.iter().map(|&i| col[i])))
+        }
+        Column::Date(col) => {
+            Column::Date(alloc.alloc_slice_fill_iter(indexes.iter().map(|&i| col[i])))
+        }
         Column::Int128(col) => {
             Column::Int128(alloc.alloc_slice_fill_iter(indexes.iter().map(|&i| col[i])))
         }
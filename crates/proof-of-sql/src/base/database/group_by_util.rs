@@ -159,6 +159,7 @@ pub(crate) fn sum_aggregate_column_by_index_counts<'a, S: Scalar>(
         Column::SmallInt(col) => sum_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Int(col) => sum_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::BigInt(col) => sum_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
+        Column::UInt64(col) => sum_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Int128(col) => sum_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Decimal75(_, _, col) => {
             sum_aggregate_slice_by_index_counts(alloc, col, counts, indexes)
@@ -167,6 +168,7 @@ pub(crate) fn sum_aggregate_column_by_index_counts<'a, S: Scalar>(
         // The following should never be reached because the `SUM` function can only be applied to numeric types.
         Column::VarChar(_)
         | Column::TimestampTZ(_, _, _)
+        | Column::Date(_)
         | Column::Boolean(_)
         | Column::VarBinary(_) => {
             unreachable!("SUM can not be applied to non-numeric types")
@@ -193,6 +195,8 @@ pub(crate) fn max_aggregate_column_by_index_counts<'a, S: Scalar>(
         Column::SmallInt(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Int(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::BigInt(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
+        Column::UInt64(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
+        Column::Date(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Int128(col) => max_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Decimal75(_, _, col) => {
             max_aggregate_slice_by_index_counts(alloc, col, counts, indexes)
@@ -230,6 +234,8 @@ pub(crate) fn min_aggregate_column_by_index_counts<'a, S: Scalar>(
         Column::SmallInt(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Int(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::BigInt(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
+        Column::UInt64(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
+        Column::Date(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Int128(col) => min_aggregate_slice_by_index_counts(alloc, col, counts, indexes),
         Column::Decimal75(_, _, col) => {
             min_aggregate_slice_by_index_counts(alloc, col, counts, indexes)
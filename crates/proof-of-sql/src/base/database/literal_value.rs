@@ -12,7 +12,7 @@ use serde::{Deserialize, Serialize};
 /// Note: The types here should correspond to native SQL database types.
 /// See `<https://ignite.apache.org/docs/latest/sql-reference/data-types>` for
 /// a description of the native types used by Apache Ignite.
-#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(Debug, Eq, PartialEq, Clone, Hash, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum LiteralValue {
     /// Boolean literals
@@ -45,6 +45,10 @@ pub enum LiteralValue {
     /// `TimeStamp` defined over a unit (s, ms, ns, etc) and timezone with backing store
     /// mapped to i64, which is time units since unix epoch
     TimeStampTZ(PoSQLTimeUnit, PoSQLTimeZone, i64),
+    /// u64 literals
+    UInt64(u64),
+    /// Date literals, stored as days since the unix epoch
+    Date(i32),
 }
 
 impl LiteralValue {
@@ -58,6 +62,8 @@ impl LiteralValue {
             Self::SmallInt(_) => ColumnType::SmallInt,
             Self::Int(_) => ColumnType::Int,
             Self::BigInt(_) => ColumnType::BigInt,
+            Self::UInt64(_) => ColumnType::UInt64,
+            Self::Date(_) => ColumnType::Date,
             Self::VarChar(_) => ColumnType::VarChar,
             Self::VarBinary(_) => ColumnType::VarBinary,
             Self::Int128(_) => ColumnType::Int128,
@@ -76,6 +82,8 @@ impl LiteralValue {
             Self::SmallInt(i) => i.into(),
             Self::Int(i) => i.into(),
             Self::BigInt(i) => i.into(),
+            Self::UInt64(i) => i.into(),
+            Self::Date(i) => i.into(),
             Self::VarChar(str) => str.into(),
             Self::VarBinary(bytes) => S::from_byte_slice_via_hash(bytes),
             Self::Decimal75(_, _, i) => i.into_scalar(),
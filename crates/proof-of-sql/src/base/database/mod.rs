@@ -58,7 +58,15 @@ pub use table_ref::TableRef;
 pub mod arrow_schema_utility;
 
 mod owned_column;
-pub use owned_column::OwnedColumn;
+pub use owned_column::{OwnedColumn, UNIQUE_VALUES_LIMIT};
+
+mod timestamp_operation;
+pub use timestamp_operation::DateComponent;
+
+#[cfg(feature = "unicode-normalization")]
+mod varchar_normalization;
+#[cfg(feature = "unicode-normalization")]
+pub use varchar_normalization::{normalize_varchar, NormalizationForm};
 
 mod owned_column_error;
 pub(crate) use owned_column_error::ColumnCoercionError;
@@ -67,13 +75,21 @@ pub use owned_column_error::{OwnedColumnError, OwnedColumnResult};
 /// TODO: add docs
 pub(crate) mod owned_column_operation;
 
+mod column_data_type;
+pub use column_data_type::ColumnDataType;
+
 mod owned_table;
 pub(crate) use owned_table::TableCoercionError;
-pub use owned_table::{OwnedTable, OwnedTableError};
+pub use owned_table::{OwnedTable, OwnedTableError, SortKey, MARKDOWN_MAX_STRING_LENGTH};
 #[cfg(test)]
 mod owned_table_test;
 pub mod owned_table_utility;
 
+mod rolling_window;
+pub use rolling_window::{WindowOp, ROLLING_WINDOW_SCALE};
+#[cfg(test)]
+mod rolling_window_test;
+
 mod table;
 #[cfg(test)]
 pub(crate) use table::TableError;
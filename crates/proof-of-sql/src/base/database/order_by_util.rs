@@ -27,6 +27,8 @@ pub(crate) fn compare_indexes_by_columns<S: Scalar>(
             Column::SmallInt(col) => col[i].cmp(&col[j]),
             Column::Int(col) => col[i].cmp(&col[j]),
             Column::BigInt(col) | Column::TimestampTZ(_, _, col) => col[i].cmp(&col[j]),
+            Column::UInt64(col) => col[i].cmp(&col[j]),
+            Column::Date(col) => col[i].cmp(&col[j]),
             Column::Int128(col) => col[i].cmp(&col[j]),
             Column::Decimal75(_, _, col) => col[i].signed_cmp(&col[j]),
             Column::Scalar(col) => col[i].cmp(&col[j]),
@@ -88,6 +90,12 @@ pub(crate) fn compare_single_row_of_tables<S: Scalar>(
             | (Column::TimestampTZ(_, _, left_col), Column::TimestampTZ(_, _, right_col)) => {
                 left_col[left_row_index].cmp(&right_col[right_row_index])
             }
+            (Column::UInt64(left_col), Column::UInt64(right_col)) => {
+                left_col[left_row_index].cmp(&right_col[right_row_index])
+            }
+            (Column::Date(left_col), Column::Date(right_col)) => {
+                left_col[left_row_index].cmp(&right_col[right_row_index])
+            }
             (Column::Int128(left_col), Column::Int128(right_col)) => {
                 left_col[left_row_index].cmp(&right_col[right_row_index])
             }
@@ -119,7 +127,7 @@ pub(crate) fn compare_indexes_by_owned_columns<S: Scalar>(
     let order_by_pairs = order_by
         .iter()
         // ASC is the default direction
-        .map(|&col| (col.clone(), true))
+        .map(|&col| (col, true))
         .collect::<Vec<_>>();
     compare_indexes_by_owned_columns_with_direction(&order_by_pairs, i, j)
 }
@@ -128,7 +136,7 @@ pub(crate) fn compare_indexes_by_owned_columns<S: Scalar>(
 /// `(right[0][j], right[1][j], ...)` in lexicographic order.
 /// Note that direction flips the ordering.
 pub(crate) fn compare_indexes_by_owned_columns_with_direction<S: Scalar>(
-    order_by_pairs: &[(OwnedColumn<S>, bool)],
+    order_by_pairs: &[(&OwnedColumn<S>, bool)],
     i: usize,
     j: usize,
 ) -> Ordering {
@@ -144,6 +152,8 @@ pub(crate) fn compare_indexes_by_owned_columns_with_direction<S: Scalar>(
                 OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => {
                     col[i].cmp(&col[j])
                 }
+                OwnedColumn::UInt64(col) => col[i].cmp(&col[j]),
+                OwnedColumn::Date(col) => col[i].cmp(&col[j]),
                 OwnedColumn::Int128(col) => col[i].cmp(&col[j]),
                 OwnedColumn::Decimal75(_, _, col) => col[i].signed_cmp(&col[j]),
                 OwnedColumn::Scalar(col) => col[i].cmp(&col[j]),
@@ -2,23 +2,33 @@
 /// This is primarily used as an internal result that is used before
 /// converting to the final result in either Arrow format or JSON.
 /// This is the analog of an arrow Array.
-use super::{Column, ColumnCoercionError, ColumnType, OwnedColumnError, OwnedColumnResult};
+use super::{
+    Column, ColumnCoercionError, ColumnType, LiteralValue, OwnedColumnError, OwnedColumnResult,
+    OwnedTableError,
+};
 use crate::base::{
+    database::order_by_util::compare_indexes_by_owned_columns_with_direction,
+    map::IndexSet,
     math::{
         decimal::Precision,
+        i256::I256,
         permutation::{Permutation, PermutationError},
     },
     posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
-    scalar::Scalar,
-    slice_ops::{inner_product_ref_cast, inner_product_with_bytes},
+    scalar::{Scalar, ScalarExt},
+    slice_ops::{inner_product_ref_cast, inner_product_with_bytes, slice_cast_with},
 };
 use alloc::{
     string::{String, ToString},
+    vec,
     vec::Vec,
 };
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
+/// The number of distinct values after which [`OwnedColumn::unique_values`] stops collecting.
+pub const UNIQUE_VALUES_LIMIT: usize = 1_000_000;
+
 #[derive(Debug, PartialEq, Clone, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 #[cfg_attr(test, derive(proptest_derive::Arbitrary))]
@@ -51,6 +61,12 @@ pub enum OwnedColumn<S: Scalar> {
     /// Timestamp columns
     #[cfg_attr(test, proptest(skip))]
     TimestampTZ(PoSQLTimeUnit, PoSQLTimeZone, Vec<i64>),
+    /// u64 columns
+    #[cfg_attr(test, proptest(skip))]
+    UInt64(Vec<u64>),
+    /// Date columns, stored as days since the unix epoch
+    #[cfg_attr(test, proptest(skip))]
+    Date(Vec<i32>),
 }
 
 impl<S: Scalar> OwnedColumn<S> {
@@ -65,6 +81,8 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => {
                 inner_product_ref_cast(col, vec)
             }
+            OwnedColumn::UInt64(col) => inner_product_ref_cast(col, vec),
+            OwnedColumn::Date(col) => inner_product_ref_cast(col, vec),
             OwnedColumn::VarChar(col) => inner_product_ref_cast(col, vec),
             OwnedColumn::VarBinary(col) => inner_product_with_bytes(col, vec),
             OwnedColumn::Int128(col) => inner_product_ref_cast(col, vec),
@@ -84,6 +102,8 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::SmallInt(col) => col.len(),
             OwnedColumn::Int(col) => col.len(),
             OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => col.len(),
+            OwnedColumn::UInt64(col) => col.len(),
+            OwnedColumn::Date(col) => col.len(),
             OwnedColumn::VarChar(col) => col.len(),
             OwnedColumn::VarBinary(col) => col.len(),
             OwnedColumn::Int128(col) => col.len(),
@@ -100,6 +120,8 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::SmallInt(col) => OwnedColumn::SmallInt(permutation.try_apply(col)?),
             OwnedColumn::Int(col) => OwnedColumn::Int(permutation.try_apply(col)?),
             OwnedColumn::BigInt(col) => OwnedColumn::BigInt(permutation.try_apply(col)?),
+            OwnedColumn::UInt64(col) => OwnedColumn::UInt64(permutation.try_apply(col)?),
+            OwnedColumn::Date(col) => OwnedColumn::Date(permutation.try_apply(col)?),
             OwnedColumn::VarChar(col) => OwnedColumn::VarChar(permutation.try_apply(col)?),
             OwnedColumn::VarBinary(col) => OwnedColumn::VarBinary(permutation.try_apply(col)?),
             OwnedColumn::Int128(col) => OwnedColumn::Int128(permutation.try_apply(col)?),
@@ -123,6 +145,8 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::SmallInt(col) => OwnedColumn::SmallInt(col[start..end].to_vec()),
             OwnedColumn::Int(col) => OwnedColumn::Int(col[start..end].to_vec()),
             OwnedColumn::BigInt(col) => OwnedColumn::BigInt(col[start..end].to_vec()),
+            OwnedColumn::UInt64(col) => OwnedColumn::UInt64(col[start..end].to_vec()),
+            OwnedColumn::Date(col) => OwnedColumn::Date(col[start..end].to_vec()),
             OwnedColumn::VarChar(col) => OwnedColumn::VarChar(col[start..end].to_vec()),
             OwnedColumn::VarBinary(col) => OwnedColumn::VarBinary(col[start..end].to_vec()),
             OwnedColumn::Int128(col) => OwnedColumn::Int128(col[start..end].to_vec()),
@@ -136,6 +160,261 @@ impl<S: Scalar> OwnedColumn<S> {
         }
     }
 
+    /// Returns the column with only the entries for which `mask` is `true`.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::RowCountMismatch`] if `mask` does not have one entry per row.
+    pub fn apply_boolean_mask(&self, mask: &[bool]) -> Result<Self, OwnedTableError> {
+        fn filtered<T: Clone>(col: &[T], selection: &[bool]) -> Vec<T> {
+            col.iter()
+                .zip(selection)
+                .filter_map(|(value, &selected)| selected.then(|| value.clone()))
+                .collect()
+        }
+        if mask.len() != self.len() {
+            return Err(OwnedTableError::RowCountMismatch {
+                expected: self.len(),
+                actual: mask.len(),
+            });
+        }
+        Ok(match self {
+            OwnedColumn::Boolean(col) => OwnedColumn::Boolean(filtered(col, mask)),
+            OwnedColumn::TinyInt(col) => OwnedColumn::TinyInt(filtered(col, mask)),
+            OwnedColumn::Uint8(col) => OwnedColumn::Uint8(filtered(col, mask)),
+            OwnedColumn::SmallInt(col) => OwnedColumn::SmallInt(filtered(col, mask)),
+            OwnedColumn::Int(col) => OwnedColumn::Int(filtered(col, mask)),
+            OwnedColumn::BigInt(col) => OwnedColumn::BigInt(filtered(col, mask)),
+            OwnedColumn::UInt64(col) => OwnedColumn::UInt64(filtered(col, mask)),
+            OwnedColumn::Date(col) => OwnedColumn::Date(filtered(col, mask)),
+            OwnedColumn::VarChar(col) => OwnedColumn::VarChar(filtered(col, mask)),
+            OwnedColumn::VarBinary(col) => OwnedColumn::VarBinary(filtered(col, mask)),
+            OwnedColumn::Int128(col) => OwnedColumn::Int128(filtered(col, mask)),
+            OwnedColumn::Decimal75(precision, scale, col) => {
+                OwnedColumn::Decimal75(*precision, *scale, filtered(col, mask))
+            }
+            OwnedColumn::Scalar(col) => OwnedColumn::Scalar(filtered(col, mask)),
+            OwnedColumn::TimestampTZ(tu, tz, col) => {
+                OwnedColumn::TimestampTZ(*tu, *tz, filtered(col, mask))
+            }
+        })
+    }
+
+    /// Returns the row indexes of the column in ascending order of their values.
+    ///
+    /// The sort is stable, so equal values keep their original order. Decimals are compared by their signed
+    /// value and scalars by their canonical representation.
+    #[must_use]
+    pub fn sort_indices(&self) -> Vec<usize> {
+        let order_by_pairs = [(self, true)];
+        let mut indexes: Vec<usize> = (0..self.len()).collect();
+        indexes.sort_by(|&i, &j| {
+            compare_indexes_by_owned_columns_with_direction(&order_by_pairs, i, j)
+        });
+        indexes
+    }
+
+    /// Returns the smallest power of two that is at least the length of the column.
+    ///
+    /// Note: an empty column returns 1, matching [`usize::next_power_of_two`].
+    #[must_use]
+    pub fn next_power_of_two_len(&self) -> usize {
+        self.len().next_power_of_two()
+    }
+
+    /// Returns the column padded with `fill` until it has length `n`.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::ColumnLengthMismatch`] if the column is already longer than `n`
+    /// and [`OwnedTableError::TypeMismatch`] if `fill` does not have the type of the column.
+    pub fn pad_to_length(&self, n: usize, fill: &LiteralValue) -> Result<Self, OwnedTableError> {
+        fn padded<T: Clone>(col: &[T], fill: T, n: usize) -> Vec<T> {
+            let mut result = Vec::with_capacity(n);
+            result.extend_from_slice(col);
+            result.resize(n, fill);
+            result
+        }
+        if self.len() > n {
+            return Err(OwnedTableError::ColumnLengthMismatch);
+        }
+        Ok(match (self, fill) {
+            (OwnedColumn::Boolean(col), LiteralValue::Boolean(v)) => {
+                OwnedColumn::Boolean(padded(col, *v, n))
+            }
+            (OwnedColumn::Uint8(col), LiteralValue::Uint8(v)) => {
+                OwnedColumn::Uint8(padded(col, *v, n))
+            }
+            (OwnedColumn::TinyInt(col), LiteralValue::TinyInt(v)) => {
+                OwnedColumn::TinyInt(padded(col, *v, n))
+            }
+            (OwnedColumn::SmallInt(col), LiteralValue::SmallInt(v)) => {
+                OwnedColumn::SmallInt(padded(col, *v, n))
+            }
+            (OwnedColumn::Int(col), LiteralValue::Int(v)) => OwnedColumn::Int(padded(col, *v, n)),
+            (OwnedColumn::BigInt(col), LiteralValue::BigInt(v)) => {
+                OwnedColumn::BigInt(padded(col, *v, n))
+            }
+            (OwnedColumn::UInt64(col), LiteralValue::UInt64(v)) => {
+                OwnedColumn::UInt64(padded(col, *v, n))
+            }
+            (OwnedColumn::Date(col), LiteralValue::Date(v)) => {
+                OwnedColumn::Date(padded(col, *v, n))
+            }
+            (OwnedColumn::VarChar(col), LiteralValue::VarChar(v)) => {
+                OwnedColumn::VarChar(padded(col, v.clone(), n))
+            }
+            (OwnedColumn::VarBinary(col), LiteralValue::VarBinary(v)) => {
+                OwnedColumn::VarBinary(padded(col, v.clone(), n))
+            }
+            (OwnedColumn::Int128(col), LiteralValue::Int128(v)) => {
+                OwnedColumn::Int128(padded(col, *v, n))
+            }
+            (OwnedColumn::Decimal75(precision, scale, col), LiteralValue::Decimal75(p, s, v))
+                if precision == p && scale == s =>
+            {
+                OwnedColumn::Decimal75(*precision, *scale, padded(col, v.into_scalar(), n))
+            }
+            (OwnedColumn::Scalar(col), LiteralValue::Scalar(limbs)) => {
+                OwnedColumn::Scalar(padded(col, S::from(*limbs), n))
+            }
+            (OwnedColumn::TimestampTZ(tu, tz, col), LiteralValue::TimeStampTZ(u, z, v))
+                if tu == u && tz == z =>
+            {
+                OwnedColumn::TimestampTZ(*tu, *tz, padded(col, *v, n))
+            }
+            _ => {
+                return Err(OwnedTableError::TypeMismatch {
+                    expected: self.column_type(),
+                    actual: fill.column_type(),
+                })
+            }
+        })
+    }
+
+    /// Generate a constant column from a literal value with a given length
+    #[must_use]
+    pub fn from_literal_with_length(literal: &LiteralValue, length: usize) -> Self {
+        match literal {
+            LiteralValue::Boolean(value) => OwnedColumn::Boolean(vec![*value; length]),
+            LiteralValue::Uint8(value) => OwnedColumn::Uint8(vec![*value; length]),
+            LiteralValue::TinyInt(value) => OwnedColumn::TinyInt(vec![*value; length]),
+            LiteralValue::SmallInt(value) => OwnedColumn::SmallInt(vec![*value; length]),
+            LiteralValue::Int(value) => OwnedColumn::Int(vec![*value; length]),
+            LiteralValue::BigInt(value) => OwnedColumn::BigInt(vec![*value; length]),
+            LiteralValue::UInt64(value) => OwnedColumn::UInt64(vec![*value; length]),
+            LiteralValue::Date(value) => OwnedColumn::Date(vec![*value; length]),
+            LiteralValue::Int128(value) => OwnedColumn::Int128(vec![*value; length]),
+            LiteralValue::Scalar(value) => OwnedColumn::Scalar(vec![(*value).into(); length]),
+            LiteralValue::Decimal75(precision, scale, value) => {
+                OwnedColumn::Decimal75(*precision, *scale, vec![value.into_scalar(); length])
+            }
+            LiteralValue::TimeStampTZ(tu, tz, value) => {
+                OwnedColumn::TimestampTZ(*tu, *tz, vec![*value; length])
+            }
+            LiteralValue::VarChar(string) => OwnedColumn::VarChar(vec![string.clone(); length]),
+            LiteralValue::VarBinary(bytes) => OwnedColumn::VarBinary(vec![bytes.clone(); length]),
+        }
+    }
+
+    /// Appends `value` to the end of the column.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::TypeMismatch`] if `value` does not have the type of the column.
+    pub fn try_push_literal(&mut self, value: &LiteralValue) -> Result<(), OwnedTableError> {
+        let column_type = self.column_type();
+        match (self, value) {
+            (OwnedColumn::Boolean(col), LiteralValue::Boolean(v)) => col.push(*v),
+            (OwnedColumn::Uint8(col), LiteralValue::Uint8(v)) => col.push(*v),
+            (OwnedColumn::TinyInt(col), LiteralValue::TinyInt(v)) => col.push(*v),
+            (OwnedColumn::SmallInt(col), LiteralValue::SmallInt(v)) => col.push(*v),
+            (OwnedColumn::Int(col), LiteralValue::Int(v)) => col.push(*v),
+            (OwnedColumn::BigInt(col), LiteralValue::BigInt(v)) => col.push(*v),
+            (OwnedColumn::UInt64(col), LiteralValue::UInt64(v)) => col.push(*v),
+            (OwnedColumn::Date(col), LiteralValue::Date(v)) => col.push(*v),
+            (OwnedColumn::VarChar(col), LiteralValue::VarChar(v)) => col.push(v.clone()),
+            (OwnedColumn::VarBinary(col), LiteralValue::VarBinary(v)) => col.push(v.clone()),
+            (OwnedColumn::Int128(col), LiteralValue::Int128(v)) => col.push(*v),
+            (OwnedColumn::Decimal75(precision, scale, col), LiteralValue::Decimal75(p, s, v))
+                if *precision == *p && *scale == *s =>
+            {
+                col.push(v.into_scalar());
+            }
+            (OwnedColumn::Scalar(col), LiteralValue::Scalar(limbs)) => col.push((*limbs).into()),
+            (OwnedColumn::TimestampTZ(tu, tz, col), LiteralValue::TimeStampTZ(u, z, v))
+                if *tu == *u && *tz == *z =>
+            {
+                col.push(*v);
+            }
+            _ => {
+                return Err(OwnedTableError::TypeMismatch {
+                    expected: column_type,
+                    actual: value.column_type(),
+                })
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends all values of `other` to the end of the column.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::TypeMismatch`] if `other` does not have the type of the column.
+    pub fn try_extend(&mut self, other: Self) -> Result<(), OwnedTableError> {
+        let (column_type, other_type) = (self.column_type(), other.column_type());
+        match (self, other) {
+            (OwnedColumn::Boolean(col), OwnedColumn::Boolean(other)) => col.extend(other),
+            (OwnedColumn::Uint8(col), OwnedColumn::Uint8(other)) => col.extend(other),
+            (OwnedColumn::TinyInt(col), OwnedColumn::TinyInt(other)) => col.extend(other),
+            (OwnedColumn::SmallInt(col), OwnedColumn::SmallInt(other)) => col.extend(other),
+            (OwnedColumn::Int(col), OwnedColumn::Int(other)) => col.extend(other),
+            (OwnedColumn::BigInt(col), OwnedColumn::BigInt(other)) => col.extend(other),
+            (OwnedColumn::UInt64(col), OwnedColumn::UInt64(other)) => col.extend(other),
+            (OwnedColumn::Date(col), OwnedColumn::Date(other)) => col.extend(other),
+            (OwnedColumn::VarChar(col), OwnedColumn::VarChar(other)) => col.extend(other),
+            (OwnedColumn::VarBinary(col), OwnedColumn::VarBinary(other)) => col.extend(other),
+            (OwnedColumn::Int128(col), OwnedColumn::Int128(other)) => col.extend(other),
+            (OwnedColumn::Scalar(col), OwnedColumn::Scalar(other)) => col.extend(other),
+            (OwnedColumn::Decimal75(_, _, col), OwnedColumn::Decimal75(_, _, other))
+                if column_type == other_type =>
+            {
+                col.extend(other);
+            }
+            (OwnedColumn::TimestampTZ(_, _, col), OwnedColumn::TimestampTZ(_, _, other))
+                if column_type == other_type =>
+            {
+                col.extend(other);
+            }
+            _ => {
+                return Err(OwnedTableError::TypeMismatch {
+                    expected: column_type,
+                    actual: other_type,
+                })
+            }
+        }
+        Ok(())
+    }
+
+    /// Reserves capacity for at least `additional` more entries.
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        match self {
+            OwnedColumn::Boolean(col) => col.reserve(additional),
+            OwnedColumn::TinyInt(col) => col.reserve(additional),
+            OwnedColumn::Uint8(col) => col.reserve(additional),
+            OwnedColumn::SmallInt(col) => col.reserve(additional),
+            OwnedColumn::Int(col) => col.reserve(additional),
+            OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => {
+                col.reserve(additional);
+            }
+            OwnedColumn::UInt64(col) => col.reserve(additional),
+            OwnedColumn::Date(col) => col.reserve(additional),
+            OwnedColumn::VarChar(col) => col.reserve(additional),
+            OwnedColumn::VarBinary(col) => col.reserve(additional),
+            OwnedColumn::Int128(col) => col.reserve(additional),
+            OwnedColumn::Decimal75(_, _, col) | OwnedColumn::Scalar(col) => {
+                col.reserve(additional);
+            }
+        }
+    }
+
     /// Returns true if the column is empty.
     #[must_use]
     pub fn is_empty(&self) -> bool {
@@ -146,12 +425,36 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::SmallInt(col) => col.is_empty(),
             OwnedColumn::Int(col) => col.is_empty(),
             OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => col.is_empty(),
+            OwnedColumn::UInt64(col) => col.is_empty(),
+            OwnedColumn::Date(col) => col.is_empty(),
             OwnedColumn::VarChar(col) => col.is_empty(),
             OwnedColumn::VarBinary(col) => col.is_empty(),
             OwnedColumn::Int128(col) => col.is_empty(),
             OwnedColumn::Scalar(col) | OwnedColumn::Decimal75(_, _, col) => col.is_empty(),
         }
     }
+    /// Returns the number of NULL values in the column.
+    ///
+    /// None of the current column variants can hold NULL values, so this is always zero.
+    #[must_use]
+    pub fn null_count(&self) -> usize {
+        match self {
+            OwnedColumn::Boolean(_)
+            | OwnedColumn::Uint8(_)
+            | OwnedColumn::TinyInt(_)
+            | OwnedColumn::SmallInt(_)
+            | OwnedColumn::Int(_)
+            | OwnedColumn::BigInt(_)
+            | OwnedColumn::UInt64(_)
+            | OwnedColumn::Date(_)
+            | OwnedColumn::VarChar(_)
+            | OwnedColumn::VarBinary(_)
+            | OwnedColumn::Int128(_)
+            | OwnedColumn::Decimal75(_, _, _)
+            | OwnedColumn::Scalar(_)
+            | OwnedColumn::TimestampTZ(_, _, _) => 0,
+        }
+    }
     /// Returns the type of the column.
     #[must_use]
     pub fn column_type(&self) -> ColumnType {
@@ -162,6 +465,8 @@ impl<S: Scalar> OwnedColumn<S> {
             OwnedColumn::SmallInt(_) => ColumnType::SmallInt,
             OwnedColumn::Int(_) => ColumnType::Int,
             OwnedColumn::BigInt(_) => ColumnType::BigInt,
+            OwnedColumn::UInt64(_) => ColumnType::UInt64,
+            OwnedColumn::Date(_) => ColumnType::Date,
             OwnedColumn::VarChar(_) => ColumnType::VarChar,
             OwnedColumn::VarBinary(_) => ColumnType::VarBinary,
             OwnedColumn::Int128(_) => ColumnType::Int128,
@@ -173,6 +478,77 @@ impl<S: Scalar> OwnedColumn<S> {
         }
     }
 
+    /// Returns the value at `index` as a [`LiteralValue`], or `None` if `index` is out of bounds.
+    #[must_use]
+    pub fn literal_at(&self, index: usize) -> Option<LiteralValue> {
+        Some(match self {
+            OwnedColumn::Boolean(col) => LiteralValue::Boolean(*col.get(index)?),
+            OwnedColumn::Uint8(col) => LiteralValue::Uint8(*col.get(index)?),
+            OwnedColumn::TinyInt(col) => LiteralValue::TinyInt(*col.get(index)?),
+            OwnedColumn::SmallInt(col) => LiteralValue::SmallInt(*col.get(index)?),
+            OwnedColumn::Int(col) => LiteralValue::Int(*col.get(index)?),
+            OwnedColumn::BigInt(col) => LiteralValue::BigInt(*col.get(index)?),
+            OwnedColumn::UInt64(col) => LiteralValue::UInt64(*col.get(index)?),
+            OwnedColumn::Date(col) => LiteralValue::Date(*col.get(index)?),
+            OwnedColumn::VarChar(col) => LiteralValue::VarChar(col.get(index)?.clone()),
+            OwnedColumn::VarBinary(col) => LiteralValue::VarBinary(col.get(index)?.clone()),
+            OwnedColumn::Int128(col) => LiteralValue::Int128(*col.get(index)?),
+            OwnedColumn::Decimal75(precision, scale, col) => {
+                LiteralValue::Decimal75(*precision, *scale, I256::from_scalar(*col.get(index)?))
+            }
+            OwnedColumn::Scalar(col) => LiteralValue::Scalar((*col.get(index)?).into()),
+            OwnedColumn::TimestampTZ(tu, tz, col) => {
+                LiteralValue::TimeStampTZ(*tu, *tz, *col.get(index)?)
+            }
+        })
+    }
+
+    /// Converts the column to the scalars the proof system works with.
+    ///
+    /// Negative integers map to the negation of their absolute value, `VarChar` values use the
+    /// `From<&String>` encoding and `VarBinary` values are hashed with `from_byte_slice_via_hash`.
+    /// This is the same encoding as [`LiteralValue::to_scalar`] and the unscaled scalars of a [`Column`].
+    #[must_use]
+    pub fn to_scalar_vec(&self) -> Vec<S> {
+        match self {
+            OwnedColumn::Boolean(col) => slice_cast_with(col, |&b| S::from(b)),
+            OwnedColumn::Uint8(col) => slice_cast_with(col, |&i| S::from(i)),
+            OwnedColumn::TinyInt(col) => slice_cast_with(col, |&i| S::from(i)),
+            OwnedColumn::SmallInt(col) => slice_cast_with(col, |&i| S::from(i)),
+            OwnedColumn::Int(col) | OwnedColumn::Date(col) => slice_cast_with(col, |&i| S::from(i)),
+            OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => {
+                slice_cast_with(col, |&i| S::from(i))
+            }
+            OwnedColumn::UInt64(col) => slice_cast_with(col, |&i| S::from(i)),
+            OwnedColumn::Int128(col) => slice_cast_with(col, |&i| S::from(i)),
+            OwnedColumn::VarChar(col) => slice_cast_with(col, S::from),
+            OwnedColumn::VarBinary(col) => slice_cast_with(col, |b| S::from_byte_slice_via_hash(b)),
+            OwnedColumn::Decimal75(_, _, col) | OwnedColumn::Scalar(col) => col.clone(),
+        }
+    }
+
+    /// Returns the distinct values of the column, in order of first occurrence.
+    ///
+    /// Collection stops as soon as more than [`UNIQUE_VALUES_LIMIT`] distinct values have been found,
+    /// so a result with `UNIQUE_VALUES_LIMIT + 1` entries means the column may have further distinct values.
+    #[must_use]
+    pub fn unique_values(&self) -> IndexSet<LiteralValue> {
+        self.unique_values_up_to(UNIQUE_VALUES_LIMIT)
+    }
+
+    /// Returns the distinct values of the column, in order of first occurrence, stopping once more than
+    /// `limit` have been found.
+    fn unique_values_up_to(&self, limit: usize) -> IndexSet<LiteralValue> {
+        let mut values = IndexSet::default();
+        for value in (0..self.len()).map_while(|i| self.literal_at(i)) {
+            values.insert(value);
+            if values.len() > limit {
+                break;
+            }
+        }
+        values
+    }
+
     /// Convert a slice of scalars to a vec of owned columns
     pub fn try_from_scalars(scalars: &[S], column_type: ColumnType) -> OwnedColumnResult<Self> {
         match column_type {
@@ -230,6 +606,24 @@ impl<S: Scalar> OwnedColumn<S> {
                         error: "Overflow in scalar conversions".to_string(),
                     })?,
             )),
+            ColumnType::UInt64 => Ok(OwnedColumn::UInt64(
+                scalars
+                    .iter()
+                    .map(|s| -> Result<u64, _> { TryInto::<u64>::try_into(*s) })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| OwnedColumnError::ScalarConversionError {
+                        error: "Overflow in scalar conversions".to_string(),
+                    })?,
+            )),
+            ColumnType::Date => Ok(OwnedColumn::Date(
+                scalars
+                    .iter()
+                    .map(|s| -> Result<i32, _> { TryInto::<i32>::try_into(*s) })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| OwnedColumnError::ScalarConversionError {
+                        error: "Overflow in scalar conversions".to_string(),
+                    })?,
+            )),
             ColumnType::Int128 => Ok(OwnedColumn::Int128(
                 scalars
                     .iter()
@@ -367,6 +761,8 @@ impl<'a, S: Scalar> From<&Column<'a, S>> for OwnedColumn<S> {
             Column::SmallInt(col) => OwnedColumn::SmallInt(col.to_vec()),
             Column::Int(col) => OwnedColumn::Int(col.to_vec()),
             Column::BigInt(col) => OwnedColumn::BigInt(col.to_vec()),
+            Column::UInt64(col) => OwnedColumn::UInt64(col.to_vec()),
+            Column::Date(col) => OwnedColumn::Date(col.to_vec()),
             Column::VarChar((col, _)) => {
                 OwnedColumn::VarChar(col.iter().map(ToString::to_string).collect())
             }
@@ -383,6 +779,59 @@ impl<'a, S: Scalar> From<&Column<'a, S>> for OwnedColumn<S> {
     }
 }
 
+impl<S: Scalar> TryFrom<OwnedColumn<S>> for Vec<bool> {
+    type Error = OwnedColumnError;
+    fn try_from(column: OwnedColumn<S>) -> Result<Self, Self::Error> {
+        match column {
+            OwnedColumn::Boolean(col) => Ok(col),
+            _ => Err(OwnedColumnError::ColumnTypeMismatch {
+                target_type: "Vec<bool>",
+                column_type: column.column_type(),
+            }),
+        }
+    }
+}
+
+/// Both `BigInt` and `TimestampTZ` columns are backed by `i64` values.
+impl<S: Scalar> TryFrom<OwnedColumn<S>> for Vec<i64> {
+    type Error = OwnedColumnError;
+    fn try_from(column: OwnedColumn<S>) -> Result<Self, Self::Error> {
+        match column {
+            OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => Ok(col),
+            _ => Err(OwnedColumnError::ColumnTypeMismatch {
+                target_type: "Vec<i64>",
+                column_type: column.column_type(),
+            }),
+        }
+    }
+}
+
+impl<S: Scalar> TryFrom<OwnedColumn<S>> for Vec<i128> {
+    type Error = OwnedColumnError;
+    fn try_from(column: OwnedColumn<S>) -> Result<Self, Self::Error> {
+        match column {
+            OwnedColumn::Int128(col) => Ok(col),
+            _ => Err(OwnedColumnError::ColumnTypeMismatch {
+                target_type: "Vec<i128>",
+                column_type: column.column_type(),
+            }),
+        }
+    }
+}
+
+impl<S: Scalar> TryFrom<OwnedColumn<S>> for Vec<String> {
+    type Error = OwnedColumnError;
+    fn try_from(column: OwnedColumn<S>) -> Result<Self, Self::Error> {
+        match column {
+            OwnedColumn::VarChar(col) => Ok(col),
+            _ => Err(OwnedColumnError::ColumnTypeMismatch {
+                target_type: "Vec<String>",
+                column_type: column.column_type(),
+            }),
+        }
+    }
+}
+
 impl<S: Scalar> OwnedColumn<S> {
     /// Attempts to coerce a column of scalars to a numeric column of the specified type.
     /// If the specified type is the same as the current column type, the function will return the column as is.
@@ -475,6 +924,317 @@ mod test {
         assert_eq!(col.slice(1, 4), OwnedColumn::Int128(vec![2, 3, 4]));
     }
 
+    #[test]
+    fn we_can_apply_a_boolean_mask_to_a_column() {
+        let col: OwnedColumn<TestScalar> =
+            OwnedColumn::VarChar(vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+        assert_eq!(
+            col.apply_boolean_mask(&[true, false, true]).unwrap(),
+            OwnedColumn::VarChar(vec!["a".to_string(), "c".to_string()])
+        );
+
+        let col: OwnedColumn<TestScalar> = OwnedColumn::TimestampTZ(
+            PoSQLTimeUnit::Millisecond,
+            PoSQLTimeZone::utc(),
+            vec![1, 2, 3],
+        );
+        assert_eq!(
+            col.apply_boolean_mask(&[false, true, false]).unwrap(),
+            OwnedColumn::TimestampTZ(PoSQLTimeUnit::Millisecond, PoSQLTimeZone::utc(), vec![2])
+        );
+
+        let empty: OwnedColumn<TestScalar> = OwnedColumn::Int128(vec![]);
+        assert_eq!(empty.apply_boolean_mask(&[]).unwrap(), empty);
+    }
+
+    #[test]
+    fn we_cannot_apply_a_boolean_mask_of_the_wrong_length_to_a_column() {
+        let col: OwnedColumn<TestScalar> = OwnedColumn::BigInt(vec![1, 2]);
+        assert_eq!(
+            col.apply_boolean_mask(&[true, false, true]),
+            Err(OwnedTableError::RowCountMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn we_can_get_the_sort_indices_of_a_column() {
+        let sorted: OwnedColumn<TestScalar> = OwnedColumn::BigInt(vec![-1, 0, 5]);
+        assert_eq!(sorted.sort_indices(), vec![0, 1, 2]);
+
+        let reversed: OwnedColumn<TestScalar> =
+            OwnedColumn::VarChar(vec!["c".to_string(), "b".to_string(), "a".to_string()]);
+        assert_eq!(reversed.sort_indices(), vec![2, 1, 0]);
+
+        let repeated: OwnedColumn<TestScalar> = OwnedColumn::TimestampTZ(
+            PoSQLTimeUnit::Second,
+            PoSQLTimeZone::utc(),
+            vec![3, 1, 3, 1, 2],
+        );
+        assert_eq!(repeated.sort_indices(), vec![1, 3, 4, 0, 2]);
+
+        let decimals: OwnedColumn<TestScalar> = OwnedColumn::Decimal75(
+            Precision::new(5).unwrap(),
+            1,
+            vec![TestScalar::ONE, -TestScalar::ONE, TestScalar::ZERO],
+        );
+        assert_eq!(decimals.sort_indices(), vec![1, 2, 0]);
+
+        let empty: OwnedColumn<TestScalar> = OwnedColumn::Boolean(vec![]);
+        assert!(empty.sort_indices().is_empty());
+    }
+
+    #[test]
+    fn we_can_get_the_length_of_every_column_variant() {
+        let columns: Vec<OwnedColumn<TestScalar>> = vec![
+            OwnedColumn::Boolean(vec![true, false]),
+            OwnedColumn::Uint8(vec![1, 2]),
+            OwnedColumn::TinyInt(vec![1, 2]),
+            OwnedColumn::SmallInt(vec![1, 2]),
+            OwnedColumn::Int(vec![1, 2]),
+            OwnedColumn::BigInt(vec![1, 2]),
+            OwnedColumn::UInt64(vec![1, 2]),
+            OwnedColumn::Date(vec![1, 2]),
+            OwnedColumn::VarChar(vec!["a".to_string(), "b".to_string()]),
+            OwnedColumn::VarBinary(vec![vec![1], vec![]]),
+            OwnedColumn::Int128(vec![1, 2]),
+            OwnedColumn::Decimal75(
+                Precision::new(10).unwrap(),
+                2,
+                vec![TestScalar::ONE, TestScalar::TWO],
+            ),
+            OwnedColumn::Scalar(vec![TestScalar::ONE, TestScalar::TWO]),
+            OwnedColumn::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::utc(), vec![1, 2]),
+        ];
+        for column in columns {
+            assert_eq!(column.len(), 2);
+            assert!(!column.is_empty());
+            let empty = column.slice(0, 0);
+            assert_eq!(empty.len(), 0);
+            assert!(empty.is_empty());
+        }
+    }
+
+    #[test]
+    fn we_can_get_a_distinct_column_type_for_every_column_variant() {
+        let columns_and_type_names: Vec<(OwnedColumn<TestScalar>, &str)> = vec![
+            (OwnedColumn::Boolean(vec![]), "BOOLEAN"),
+            (OwnedColumn::Uint8(vec![]), "UINT8"),
+            (OwnedColumn::TinyInt(vec![]), "TINYINT"),
+            (OwnedColumn::SmallInt(vec![]), "SMALLINT"),
+            (OwnedColumn::Int(vec![]), "INT"),
+            (OwnedColumn::BigInt(vec![]), "BIGINT"),
+            (OwnedColumn::UInt64(vec![]), "UINT64"),
+            (OwnedColumn::Date(vec![]), "DATE"),
+            (OwnedColumn::VarChar(vec![]), "VARCHAR"),
+            (OwnedColumn::VarBinary(vec![]), "BINARY"),
+            (OwnedColumn::Int128(vec![]), "DECIMAL"),
+            (
+                OwnedColumn::Decimal75(Precision::new(10).unwrap(), 2, vec![]),
+                "DECIMAL75(PRECISION: 10, SCALE: 2)",
+            ),
+            (OwnedColumn::Scalar(vec![]), "SCALAR"),
+            (
+                OwnedColumn::TimestampTZ(PoSQLTimeUnit::Millisecond, PoSQLTimeZone::utc(), vec![]),
+                "TIMESTAMP(TIMEUNIT: milliseconds (precision: 3), TIMEZONE: +00:00)",
+            ),
+        ];
+        let column_types: IndexSet<ColumnType> = columns_and_type_names
+            .iter()
+            .map(|(column, _)| column.column_type())
+            .collect();
+        assert_eq!(column_types.len(), columns_and_type_names.len());
+        for (column, type_name) in &columns_and_type_names {
+            assert_eq!(column.column_type().to_string(), *type_name);
+        }
+    }
+
+    #[test]
+    fn we_can_get_the_literal_value_at_an_index() {
+        let col: OwnedColumn<TestScalar> =
+            OwnedColumn::VarChar(vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(
+            col.literal_at(1),
+            Some(LiteralValue::VarChar("b".to_string()))
+        );
+        assert_eq!(col.literal_at(2), None);
+
+        let col: OwnedColumn<TestScalar> = OwnedColumn::Decimal75(
+            Precision::new(10).unwrap(),
+            2,
+            vec![TestScalar::from(-123), TestScalar::from(456)],
+        );
+        assert_eq!(
+            col.literal_at(0),
+            Some(LiteralValue::Decimal75(
+                Precision::new(10).unwrap(),
+                2,
+                I256::from(-123)
+            ))
+        );
+
+        let col: OwnedColumn<TestScalar> = OwnedColumn::Scalar(vec![TestScalar::from(7)]);
+        assert_eq!(col.literal_at(0), Some(LiteralValue::Scalar([7, 0, 0, 0])));
+
+        let col: OwnedColumn<TestScalar> = OwnedColumn::TimestampTZ(
+            PoSQLTimeUnit::Second,
+            PoSQLTimeZone::utc(),
+            vec![1_625_072_400],
+        );
+        assert_eq!(
+            col.literal_at(0),
+            Some(LiteralValue::TimeStampTZ(
+                PoSQLTimeUnit::Second,
+                PoSQLTimeZone::utc(),
+                1_625_072_400
+            ))
+        );
+    }
+
+    #[test]
+    fn we_can_get_the_unique_values_of_a_column() {
+        let col: OwnedColumn<TestScalar> = OwnedColumn::BigInt(vec![3, 1, 3, 2, 1]);
+        assert_eq!(
+            col.unique_values().into_iter().collect::<Vec<_>>(),
+            vec![
+                LiteralValue::BigInt(3),
+                LiteralValue::BigInt(1),
+                LiteralValue::BigInt(2)
+            ]
+        );
+
+        let col: OwnedColumn<TestScalar> = OwnedColumn::VarChar(vec![]);
+        assert!(col.unique_values().is_empty());
+    }
+
+    #[test]
+    fn we_stop_collecting_unique_values_past_the_limit() {
+        let col: OwnedColumn<TestScalar> = OwnedColumn::BigInt(vec![3, 1, 3, 2, 4, 5]);
+        assert_eq!(
+            col.unique_values_up_to(2).into_iter().collect::<Vec<_>>(),
+            vec![
+                LiteralValue::BigInt(3),
+                LiteralValue::BigInt(1),
+                LiteralValue::BigInt(2)
+            ]
+        );
+        assert_eq!(col.unique_values_up_to(5).len(), 5);
+        assert_eq!(col.unique_values_up_to(0).len(), 1);
+    }
+
+    #[test]
+    fn we_can_create_a_column_from_a_literal_and_push_literals() {
+        let mut col = OwnedColumn::<TestScalar>::from_literal_with_length(
+            &LiteralValue::VarChar("a".to_string()),
+            2,
+        );
+        assert_eq!(
+            col,
+            OwnedColumn::VarChar(vec!["a".to_string(), "a".to_string()])
+        );
+        col.try_push_literal(&LiteralValue::VarChar("b".to_string()))
+            .unwrap();
+        assert_eq!(
+            col.literal_at(2),
+            Some(LiteralValue::VarChar("b".to_string()))
+        );
+        assert_eq!(
+            col.try_push_literal(&LiteralValue::BigInt(1)),
+            Err(OwnedTableError::TypeMismatch {
+                expected: ColumnType::VarChar,
+                actual: ColumnType::BigInt
+            })
+        );
+        assert_eq!(col.len(), 3);
+
+        let decimal = LiteralValue::Decimal75(Precision::new(10).unwrap(), 2, I256::from(-5));
+        let mut col = OwnedColumn::<TestScalar>::from_literal_with_length(&decimal, 0);
+        assert!(col.is_empty());
+        col.try_push_literal(&decimal).unwrap();
+        assert_eq!(col.literal_at(0), Some(decimal));
+        assert!(col
+            .try_push_literal(&LiteralValue::Decimal75(
+                Precision::new(10).unwrap(),
+                3,
+                I256::from(-5)
+            ))
+            .is_err());
+    }
+
+    #[test]
+    fn we_can_convert_owned_columns_into_vecs() {
+        let col: OwnedColumn<TestScalar> = OwnedColumn::Boolean(vec![true, false]);
+        assert_eq!(Vec::<bool>::try_from(col), Ok(vec![true, false]));
+        let col: OwnedColumn<TestScalar> = OwnedColumn::BigInt(vec![1, -2]);
+        assert_eq!(Vec::<i64>::try_from(col), Ok(vec![1, -2]));
+        let col: OwnedColumn<TestScalar> =
+            OwnedColumn::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::utc(), vec![3, 4]);
+        let timestamps: Vec<i64> = col.try_into().unwrap();
+        assert_eq!(timestamps, vec![3, 4]);
+        let col: OwnedColumn<TestScalar> = OwnedColumn::Int128(vec![i128::MAX]);
+        assert_eq!(Vec::<i128>::try_from(col), Ok(vec![i128::MAX]));
+        let col: OwnedColumn<TestScalar> = OwnedColumn::VarChar(vec!["a".to_string()]);
+        assert_eq!(Vec::<String>::try_from(col), Ok(vec!["a".to_string()]));
+    }
+
+    #[test]
+    fn we_cannot_convert_owned_columns_into_vecs_of_the_wrong_type() {
+        let col: OwnedColumn<TestScalar> = OwnedColumn::Int(vec![1]);
+        assert_eq!(
+            Vec::<i64>::try_from(col.clone()),
+            Err(OwnedColumnError::ColumnTypeMismatch {
+                target_type: "Vec<i64>",
+                column_type: ColumnType::Int
+            })
+        );
+        assert!(Vec::<i128>::try_from(col.clone()).is_err());
+        assert!(Vec::<bool>::try_from(col.clone()).is_err());
+        assert!(Vec::<String>::try_from(col).is_err());
+    }
+
+    #[test]
+    fn we_can_pad_a_column_to_a_given_length() {
+        let col: OwnedColumn<TestScalar> = OwnedColumn::BigInt(vec![1, 2, 3]);
+        assert_eq!(col.next_power_of_two_len(), 4);
+        assert_eq!(
+            col.pad_to_length(4, &LiteralValue::BigInt(0)).unwrap(),
+            OwnedColumn::BigInt(vec![1, 2, 3, 0])
+        );
+        assert_eq!(col.pad_to_length(3, &LiteralValue::BigInt(0)).unwrap(), col);
+
+        let col: OwnedColumn<TestScalar> = OwnedColumn::VarChar(vec!["a".to_string()]);
+        assert_eq!(
+            col.pad_to_length(2, &LiteralValue::VarChar("b".to_string()))
+                .unwrap(),
+            OwnedColumn::VarChar(vec!["a".to_string(), "b".to_string()])
+        );
+
+        let col: OwnedColumn<TestScalar> = OwnedColumn::Boolean(vec![]);
+        assert_eq!(col.next_power_of_two_len(), 1);
+        assert_eq!(
+            col.pad_to_length(2, &LiteralValue::Boolean(true)).unwrap(),
+            OwnedColumn::Boolean(vec![true, true])
+        );
+    }
+
+    #[test]
+    fn we_cannot_pad_a_column_that_is_too_long_or_with_the_wrong_type() {
+        let col: OwnedColumn<TestScalar> = OwnedColumn::BigInt(vec![1, 2, 3]);
+        assert_eq!(
+            col.pad_to_length(2, &LiteralValue::BigInt(0)),
+            Err(OwnedTableError::ColumnLengthMismatch)
+        );
+        assert_eq!(
+            col.pad_to_length(4, &LiteralValue::Int(0)),
+            Err(OwnedTableError::TypeMismatch {
+                expected: ColumnType::BigInt,
+                actual: ColumnType::Int
+            })
+        );
+    }
+
     #[test]
     fn we_can_permute_a_column() {
         let col: OwnedColumn<TestScalar> = OwnedColumn::Int128(vec![1, 2, 3, 4, 5]);
@@ -577,6 +1337,100 @@ mod test {
         );
     }
 
+    #[test]
+    fn we_can_convert_owned_columns_to_scalar_vecs() {
+        let columns = [
+            OwnedColumn::<TestScalar>::Boolean(vec![true, false]),
+            OwnedColumn::Uint8(vec![0, u8::MAX]),
+            OwnedColumn::TinyInt(vec![i8::MIN, -1]),
+            OwnedColumn::SmallInt(vec![i16::MIN, i16::MAX]),
+            OwnedColumn::Int(vec![-1, 2]),
+            OwnedColumn::BigInt(vec![i64::MIN, 0]),
+            OwnedColumn::UInt64(vec![u64::MAX, 1]),
+            OwnedColumn::Date(vec![-1, 19_723]),
+            OwnedColumn::Int128(vec![i128::MIN, i128::MAX]),
+            OwnedColumn::VarChar(vec!["".to_string(), "abc".to_string()]),
+            OwnedColumn::VarBinary(vec![vec![], vec![1, 2, 3]]),
+            OwnedColumn::Scalar(vec![TestScalar::TWO, -TestScalar::ONE]),
+            OwnedColumn::Decimal75(
+                Precision::new(10).unwrap(),
+                2,
+                vec![TestScalar::from(-150), TestScalar::ONE],
+            ),
+            OwnedColumn::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::utc(), vec![-1, 1]),
+        ];
+        for column in &columns {
+            let expected: Vec<TestScalar> = (0..column.len())
+                .map(|i| column.literal_at(i).unwrap().to_scalar())
+                .collect();
+            assert_eq!(column.to_scalar_vec(), expected);
+            assert_eq!(
+                column.to_scalar_vec(),
+                Column::from_owned_column(column, &Bump::new()).to_scalar_with_scaling(0)
+            );
+        }
+        assert_eq!(
+            OwnedColumn::<TestScalar>::TinyInt(vec![-5]).to_scalar_vec(),
+            vec![-TestScalar::from(5)]
+        );
+        assert_eq!(
+            OwnedColumn::<TestScalar>::VarChar(vec!["abc".to_string()]).to_scalar_vec(),
+            vec![TestScalar::from("abc")]
+        );
+    }
+
+    #[test]
+    fn we_can_round_trip_uint64_columns_through_scalars() {
+        let values = vec![0, 1, u64::from(u32::MAX) + 1, 1 << 63, u64::MAX];
+        let owned_col = OwnedColumn::<TestScalar>::UInt64(values.clone());
+        let scalars = owned_col.to_scalar_vec();
+        assert_eq!(scalars[4], TestScalar::from(u64::MAX));
+        assert_eq!(
+            OwnedColumn::try_from_scalars(&scalars, ColumnType::UInt64).unwrap(),
+            OwnedColumn::UInt64(values)
+        );
+
+        let res = OwnedColumn::<TestScalar>::try_from_scalars(
+            &[TestScalar::from(u128::from(u64::MAX) + 1)],
+            ColumnType::UInt64,
+        );
+        assert!(matches!(
+            res,
+            Err(OwnedColumnError::ScalarConversionError { .. })
+        ));
+        let res = OwnedColumn::<TestScalar>::try_from_scalars(
+            &[TestScalar::from(-1)],
+            ColumnType::UInt64,
+        );
+        assert!(matches!(
+            res,
+            Err(OwnedColumnError::ScalarConversionError { .. })
+        ));
+    }
+
+    #[test]
+    fn we_can_round_trip_date_columns_through_scalars() {
+        let values = vec![0, -1, 19_723, i32::MIN, i32::MAX];
+        let owned_col = OwnedColumn::<TestScalar>::Date(values.clone());
+        assert_eq!(owned_col.column_type(), ColumnType::Date);
+        assert_eq!(owned_col.literal_at(1), Some(LiteralValue::Date(-1)));
+        let scalars = owned_col.to_scalar_vec();
+        assert_eq!(scalars[1], -TestScalar::ONE);
+        assert_eq!(
+            OwnedColumn::try_from_scalars(&scalars, ColumnType::Date).unwrap(),
+            OwnedColumn::Date(values)
+        );
+
+        let res = OwnedColumn::<TestScalar>::try_from_scalars(
+            &[TestScalar::from(i64::from(i32::MAX) + 1)],
+            ColumnType::Date,
+        );
+        assert!(matches!(
+            res,
+            Err(OwnedColumnError::ScalarConversionError { .. })
+        ));
+    }
+
     #[test]
     fn we_cannot_convert_scalars_to_owned_columns_if_varchar() {
         let scalars = ["a", "b", "c", "d", "e"]
@@ -19,6 +19,14 @@ pub enum OwnedColumnError {
         /// The underlying error
         error: String,
     },
+    /// The column does not have a type that can be converted into the target type.
+    #[snafu(display("Can not convert a column of type {column_type} into {target_type}"))]
+    ColumnTypeMismatch {
+        /// The type into which we are trying to convert.
+        target_type: &'static str,
+        /// The type of the column.
+        column_type: ColumnType,
+    },
     /// Unsupported operation.
     #[snafu(display("Unsupported operation: {error}"))]
     Unsupported {
@@ -1,9 +1,24 @@
-use super::{ColumnField, OwnedColumn, Table};
+use super::{
+    ColumnDataType, ColumnField, ColumnType, ExpressionEvaluationError, LiteralValue, OwnedColumn,
+    Table,
+};
 use crate::base::{
-    database::ColumnCoercionError, map::IndexMap, polynomial::compute_evaluation_vector,
+    database::{
+        order_by_util::compare_indexes_by_owned_columns_with_direction, ColumnCoercionError,
+    },
+    map::IndexMap,
+    math::permutation::Permutation,
+    polynomial::compute_evaluation_vector,
     scalar::Scalar,
 };
-use alloc::{vec, vec::Vec};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use bigdecimal::BigDecimal;
+use core::ops::{Bound, RangeBounds};
 use itertools::{EitherOrBoth, Itertools};
 use serde::{Deserialize, Serialize};
 use snafu::Snafu;
@@ -11,10 +26,71 @@ use sqlparser::ast::Ident;
 
 /// An error that occurs when working with tables.
 #[derive(Snafu, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum OwnedTableError {
     /// The columns have different lengths.
     #[snafu(display("Columns have different lengths"))]
     ColumnLengthMismatch,
+    /// A value does not have the type of the column it is used with.
+    #[snafu(display("Type mismatch: expected {expected}, got {actual}"))]
+    TypeMismatch {
+        /// The type of the column
+        expected: ColumnType,
+        /// The type of the offending value
+        actual: ColumnType,
+    },
+    /// A column with the given name does not exist in the table.
+    #[snafu(display("Column not found: {column_name}"))]
+    ColumnNotFound {
+        /// The name of the missing column
+        column_name: String,
+    },
+    /// A column with the given name already exists in the table.
+    #[snafu(display("Duplicate column name: {column_name}"))]
+    DuplicateColumnName {
+        /// The name of the duplicated column
+        column_name: String,
+    },
+    /// An error occurred while evaluating an expression on the table.
+    #[snafu(transparent)]
+    ExpressionEvaluationError {
+        /// The underlying source error
+        source: ExpressionEvaluationError,
+    },
+    /// A row does not have a value for one of the columns of the table.
+    #[snafu(display("Missing value for column {column_name}"))]
+    MissingColumn {
+        /// The name of the column without a value
+        column_name: String,
+    },
+    /// The tables do not have the same column names.
+    #[snafu(display("Tables have different column names"))]
+    ColumnSchemaMismatch,
+    /// No fill value was provided for a column that needs padding.
+    #[snafu(display("Missing fill value for column {column_name}"))]
+    MissingFillValue {
+        /// The name of the column without a fill value
+        column_name: String,
+    },
+    /// A rolling window must contain at least one row.
+    #[snafu(display("Window size must be positive"))]
+    InvalidWindowSize,
+    /// The table does not have the expected number of rows.
+    #[snafu(display("Expected {expected} rows, got {actual}"))]
+    RowCountMismatch {
+        /// The expected number of rows
+        expected: usize,
+        /// The actual number of rows
+        actual: usize,
+    },
+    /// The column does not hold integer values.
+    #[snafu(display("Column {column_name} has non-integer type {column_type}"))]
+    NonIntegerColumn {
+        /// The name of the offending column
+        column_name: String,
+        /// The type of the offending column
+        column_type: ColumnType,
+    },
 }
 
 /// Errors that can occur when coercing a table.
@@ -30,6 +106,19 @@ pub(crate) enum TableCoercionError {
     ColumnCountMismatch,
 }
 
+/// A column to sort by in [`OwnedTable::sort_by_keys`], together with its sort direction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortKey {
+    /// The name of the column
+    pub column: Ident,
+    /// Whether the column is sorted in descending rather than ascending order
+    pub descending: bool,
+    /// Whether NULLs are placed after rather than before all other values
+    ///
+    /// Columns cannot contain NULLs yet, so this currently has no effect.
+    pub nulls_last: bool,
+}
+
 /// A table of data, with schema included. This is simply a map from `Ident` to `OwnedColumn`,
 /// where columns order matters.
 /// This is primarily used as an internal result that is used before
@@ -59,6 +148,41 @@ impl<S: Scalar> OwnedTable<S> {
         Self::try_new(IndexMap::from_iter(iter))
     }
 
+    /// Creates a new [`OwnedTable`] from rows given as maps from column names to values.
+    ///
+    /// The columns are the union of the keys of all rows, in order of first appearance, and the type of each
+    /// column is the type of its first value. Since columns are not nullable, every row must have a value for every column.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::MissingColumn`] if a row has no value for some column and
+    /// [`OwnedTableError::TypeMismatch`] if a value does not have the type of its column.
+    pub fn from_dicts<R>(rows: impl IntoIterator<Item = R>) -> Result<Self, OwnedTableError>
+    where
+        R: IntoIterator<Item = (String, LiteralValue)>,
+    {
+        let rows: Vec<IndexMap<String, LiteralValue>> = rows
+            .into_iter()
+            .map(|row| row.into_iter().collect())
+            .collect();
+        let mut table: IndexMap<Ident, OwnedColumn<S>> = IndexMap::default();
+        for (name, value) in rows.iter().flatten() {
+            table
+                .entry(Ident::new(name.clone()))
+                .or_insert_with(|| OwnedColumn::from_literal_with_length(value, 0));
+        }
+        for row in &rows {
+            for (name, column) in &mut table {
+                let value =
+                    row.get(name.value.as_str())
+                        .ok_or_else(|| OwnedTableError::MissingColumn {
+                            column_name: name.value.clone(),
+                        })?;
+                column.try_push_literal(value)?;
+            }
+        }
+        Self::try_new(table)
+    }
+
     #[expect(
         clippy::missing_panics_doc,
         reason = "Mapping from one table to another should not result in column mismatch"
@@ -104,6 +228,7 @@ impl<S: Scalar> OwnedTable<S> {
     }
     /// Number of rows in the table.
     #[must_use]
+    #[inline]
     pub fn num_rows(&self) -> usize {
         if self.table.is_empty() {
             0
@@ -130,11 +255,506 @@ impl<S: Scalar> OwnedTable<S> {
     pub fn column_names(&self) -> impl Iterator<Item = &Ident> {
         self.table.keys()
     }
+    /// Renames the column `old` to `new`, keeping its position.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::ColumnNotFound`] if `old` is not in the table and
+    /// [`OwnedTableError::DuplicateColumnName`] if another column is already named `new`.
+    pub fn rename_column(&mut self, old: &Ident, new: Ident) -> Result<(), OwnedTableError> {
+        if !self.table.contains_key(old) {
+            return Err(OwnedTableError::ColumnNotFound {
+                column_name: old.value.clone(),
+            });
+        }
+        if *old == new {
+            return Ok(());
+        }
+        if self.table.contains_key(&new) {
+            return Err(OwnedTableError::DuplicateColumnName {
+                column_name: new.value,
+            });
+        }
+        self.table = core::mem::take(&mut self.table)
+            .into_iter()
+            .map(|(name, column)| {
+                if name == *old {
+                    (new.clone(), column)
+                } else {
+                    (name, column)
+                }
+            })
+            .collect();
+        Ok(())
+    }
+    /// Returns the column names and columns of this table, in column order.
+    pub fn iter_columns(&self) -> impl Iterator<Item = (&Ident, &OwnedColumn<S>)> {
+        self.table.iter()
+    }
     /// Returns the column with the given position.
     #[must_use]
     pub fn column_by_index(&self, index: usize) -> Option<&OwnedColumn<S>> {
         self.table.get_index(index).map(|(_, v)| v)
     }
+    /// Returns the column with the given name.
+    #[must_use]
+    pub fn get_column(&self, name: &Ident) -> Option<&OwnedColumn<S>> {
+        self.table.get(name)
+    }
+
+    /// Returns the values of the column with the given name as a vector of `T`.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::ColumnNotFound`] if there is no such column and
+    /// [`OwnedTableError::TypeMismatch`] if the column does not hold values of type `T`.
+    pub fn downcast_column<T: ColumnDataType>(
+        &self,
+        name: &Ident,
+    ) -> Result<&Vec<T>, OwnedTableError> {
+        let column = self
+            .table
+            .get(name)
+            .ok_or_else(|| OwnedTableError::ColumnNotFound {
+                column_name: name.value.clone(),
+            })?;
+        T::from_owned_column(column).ok_or(OwnedTableError::TypeMismatch {
+            expected: T::COLUMN_TYPE,
+            actual: column.column_type(),
+        })
+    }
+
+    /// Returns the number of NULL values in each column, in column order.
+    #[must_use]
+    pub fn null_count_per_column(&self) -> IndexMap<Ident, usize> {
+        self.table
+            .iter()
+            .map(|(name, column)| (name.clone(), column.null_count()))
+            .collect()
+    }
+
+    /// Returns the type of each column, in column order.
+    #[must_use]
+    pub fn schema(&self) -> IndexMap<Ident, ColumnType> {
+        self.table
+            .iter()
+            .map(|(name, column)| (name.clone(), column.column_type()))
+            .collect()
+    }
+
+    /// Returns `true` if both tables have the same column names and types in the same order.
+    ///
+    /// Unlike `==`, the data in the columns is not compared.
+    #[must_use]
+    pub fn equal_schema(&self, other: &Self) -> bool {
+        self.schema_diff(other).is_empty()
+    }
+
+    /// Returns the positions at which the schemas of the two tables differ.
+    ///
+    /// Each entry pairs the field of `self` with the field of `other` at the same position.
+    /// [`EitherOrBoth::Left`] or [`EitherOrBoth::Right`] entries are returned when one table has
+    /// more columns than the other.
+    #[must_use]
+    pub fn schema_diff(&self, other: &Self) -> Vec<EitherOrBoth<ColumnField>> {
+        let fields = |table: &Self| {
+            table
+                .table
+                .iter()
+                .map(|(name, column)| ColumnField::new(name.clone(), column.column_type()))
+                .collect::<Vec<_>>()
+        };
+        fields(self)
+            .into_iter()
+            .zip_longest(fields(other))
+            .filter(|pair| !matches!(pair, EitherOrBoth::Both(a, b) if a == b))
+            .collect()
+    }
+
+    /// Returns the columns with the given names, in the order requested.
+    fn columns_by_name(&self, names: &[Ident]) -> Result<Vec<&OwnedColumn<S>>, OwnedTableError> {
+        names
+            .iter()
+            .map(|name| {
+                self.table
+                    .get(name)
+                    .ok_or_else(|| OwnedTableError::ColumnNotFound {
+                        column_name: name.value.clone(),
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns a new table with only the columns in `cols`, in the order given.
+    ///
+    /// This is the column selection performed by `SELECT col1, col2`.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::ColumnNotFound`] if any of `cols` is not in the table and
+    /// [`OwnedTableError::DuplicateColumnName`] if a column is requested more than once.
+    pub fn project(&self, cols: &[Ident]) -> Result<Self, OwnedTableError> {
+        let columns = self.columns_by_name(cols)?;
+        let mut table = IndexMap::default();
+        for (name, column) in cols.iter().zip(columns) {
+            if table.insert(name.clone(), column.clone()).is_some() {
+                return Err(OwnedTableError::DuplicateColumnName {
+                    column_name: name.value.clone(),
+                });
+            }
+        }
+        Self::try_new(table)
+    }
+
+    /// Returns, for every row, the values of the columns in `cols`, in the order given.
+    ///
+    /// This is useful as a multi-column key, e.g. for joins or grouping.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::ColumnNotFound`] if any of `cols` is not in the table.
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "All columns in a table have the same length"
+    )]
+    pub fn composite_key(&self, cols: &[Ident]) -> Result<Vec<Vec<LiteralValue>>, OwnedTableError> {
+        let columns = self.columns_by_name(cols)?;
+        Ok((0..self.num_rows())
+            .map(|i| {
+                columns
+                    .iter()
+                    .map(|column| column.literal_at(i).expect("row index is in bounds"))
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Returns the values of the only row of the table, in column order.
+    ///
+    /// This is useful for extracting e.g. the result of an aggregation without `GROUP BY`.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::RowCountMismatch`] if the table does not have exactly one row.
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "The table has one row, so the row index is in bounds"
+    )]
+    pub fn expect_single_row(&self) -> Result<Vec<LiteralValue>, OwnedTableError> {
+        if self.num_rows() != 1 {
+            return Err(OwnedTableError::RowCountMismatch {
+                expected: 1,
+                actual: self.num_rows(),
+            });
+        }
+        Ok(self
+            .table
+            .values()
+            .map(|column| column.literal_at(0).expect("row index is in bounds"))
+            .collect())
+    }
+
+    /// Asserts that the table has `expected` rows.
+    ///
+    /// # Panics
+    /// Panics with the actual row count and the column names if the row count is different.
+    #[cfg(test)]
+    pub(crate) fn assert_row_count(&self, expected: usize) {
+        assert_eq!(
+            self.num_rows(),
+            expected,
+            "expected {expected} rows, but the table has {} rows and columns [{}]",
+            self.num_rows(),
+            self.column_names().join(", ")
+        );
+    }
+
+    /// Returns, for every row, a 64-bit hash of [`OwnedTable::composite_key`].
+    ///
+    /// Equal keys always hash to the same value, so this can be used to sort or bucket rows
+    /// cheaply. Distinct keys collide with probability about `2^-64` per pair, so for `n` rows the
+    /// chance of any collision is roughly `n^2 / 2^65`. Callers that need exact results should
+    /// compare the full keys of rows with equal hashes.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::ColumnNotFound`] if any of `cols` is not in the table.
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "Serializing literal values into a vector cannot fail"
+    )]
+    pub fn composite_sort_key(&self, cols: &[Ident]) -> Result<Vec<u64>, OwnedTableError> {
+        Ok(self
+            .composite_key(cols)?
+            .iter()
+            .map(|key| {
+                let bytes = bincode::serde::encode_to_vec(
+                    key,
+                    bincode::config::legacy()
+                        .with_fixed_int_encoding()
+                        .with_big_endian(),
+                )
+                .expect("literal values should always serialize");
+                let hash: [u8; 32] = blake3::hash(&bytes).into();
+                u64::from_le_bytes(hash[..8].try_into().expect("slice has 8 bytes"))
+            })
+            .collect())
+    }
+
+    /// Appends the rows of `other` below the rows of this table.
+    ///
+    /// Both tables must have the same column names, but the columns of `other` may be in a different order.
+    /// The columns of the result are in the order of `self`.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::ColumnSchemaMismatch`] if the tables do not have the same column names and
+    /// [`OwnedTableError::TypeMismatch`] if columns with the same name have different types.
+    pub fn concat(mut self, other: Self) -> Result<Self, OwnedTableError> {
+        self.try_append(other)?;
+        Self::try_new(self.table)
+    }
+
+    /// Appends the rows of all `tables`, in order, into a single table.
+    ///
+    /// This is [`OwnedTable::concat`] for many tables, except that the columns are allocated once with the
+    /// total number of rows. An empty `tables` gives a table without columns.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::ColumnSchemaMismatch`] if any table does not have the same column names as the first,
+    /// and [`OwnedTableError::TypeMismatch`] if columns with the same name have different types.
+    pub fn concat_many(tables: Vec<Self>) -> Result<Self, OwnedTableError> {
+        let num_rows: usize = tables.iter().map(Self::num_rows).sum();
+        let mut tables = tables.into_iter();
+        let Some(mut result) = tables.next() else {
+            return Self::try_new(IndexMap::default());
+        };
+        for column in result.table.values_mut() {
+            column.reserve(num_rows - column.len());
+        }
+        for table in tables {
+            result.try_append(table)?;
+        }
+        Self::try_new(result.table)
+    }
+
+    /// Extends the columns of this table with the columns of the same name of `other`.
+    pub(crate) fn try_append(&mut self, other: Self) -> Result<(), OwnedTableError> {
+        if self.num_columns() != other.num_columns() {
+            return Err(OwnedTableError::ColumnSchemaMismatch);
+        }
+        let mut other = other.table;
+        for (name, column) in &mut self.table {
+            let other_column = other
+                .swap_remove(name)
+                .ok_or(OwnedTableError::ColumnSchemaMismatch)?;
+            column.try_extend(other_column)?;
+        }
+        Ok(())
+    }
+
+    /// Returns a new table with the rows sorted by `keys`, i.e. by the first key, with ties broken by the following keys.
+    ///
+    /// The sort is stable, so rows that are equal on all keys keep their original order.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::ColumnNotFound`] if the column of any key is not in the table.
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "The sorted indexes are a permutation of the rows and all columns have the same length"
+    )]
+    pub fn sort_by_keys(&self, keys: &[SortKey]) -> Result<Self, OwnedTableError> {
+        let names: Vec<Ident> = keys.iter().map(|key| key.column.clone()).collect();
+        let order_by_pairs: Vec<(&OwnedColumn<S>, bool)> = self
+            .columns_by_name(&names)?
+            .into_iter()
+            .zip(keys)
+            .map(|(column, key)| (column, !key.descending))
+            .collect();
+        let mut indexes: Vec<usize> = (0..self.num_rows()).collect();
+        indexes.sort_by(|&i, &j| {
+            compare_indexes_by_owned_columns_with_direction(&order_by_pairs, i, j)
+        });
+        let permutation = Permutation::try_new(indexes).expect("indexes are a permutation");
+        Self::try_from_iter(self.table.iter().map(|(name, column)| {
+            (
+                name.clone(),
+                column
+                    .try_permute(&permutation)
+                    .expect("permutation has the length of the column"),
+            )
+        }))
+    }
+
+    /// Returns a new table with only the rows for which `predicate` holds on the value of `col`.
+    ///
+    /// This is a convenience for tests and diagnostics and is not backed by any proof.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::ColumnNotFound`] if `col` is not in the table.
+    pub fn select_rows_where(
+        &self,
+        col: &Ident,
+        predicate: impl Fn(&LiteralValue) -> bool,
+    ) -> Result<Self, OwnedTableError> {
+        let column = self
+            .table
+            .get(col)
+            .ok_or_else(|| OwnedTableError::ColumnNotFound {
+                column_name: col.value.clone(),
+            })?;
+        let mask: Vec<bool> = (0..column.len())
+            .map_while(|i| column.literal_at(i))
+            .map(|value| predicate(&value))
+            .collect();
+        self.filter(&mask)
+    }
+
+    /// Returns a new table with the rows in `range`.
+    ///
+    /// Unlike slice indexing, a range that extends past the end of the table is clamped to the table,
+    /// which gives the semantics of `LIMIT` and `OFFSET`.
+    #[must_use]
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        let num_rows = self.num_rows();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        }
+        .min(num_rows);
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => num_rows,
+        }
+        .clamp(start, num_rows);
+        Self {
+            table: self
+                .table
+                .iter()
+                .map(|(name, column)| (name.clone(), column.slice(start, end)))
+                .collect(),
+        }
+    }
+
+    /// Returns a new table with only the rows for which `mask` is `true`.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::RowCountMismatch`] if `mask` does not have one entry per row.
+    pub fn filter(&self, mask: &[bool]) -> Result<Self, OwnedTableError> {
+        if mask.len() != self.num_rows() {
+            return Err(OwnedTableError::RowCountMismatch {
+                expected: self.num_rows(),
+                actual: mask.len(),
+            });
+        }
+        Self::try_from_iter(
+            self.table
+                .iter()
+                .map(|(name, column)| Ok((name.clone(), column.apply_boolean_mask(mask)?)))
+                .collect::<Result<Vec<_>, OwnedTableError>>()?,
+        )
+    }
+
+    /// Appends a row to the table, given as a map from column names to values.
+    ///
+    /// The row is validated before anything is appended, so on error the table is left unchanged.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::ColumnNotFound`] if the row has a value for a column that is not in the table,
+    /// [`OwnedTableError::MissingColumn`] if the row has no value for some column of the table and
+    /// [`OwnedTableError::TypeMismatch`] if a value does not have the type of its column.
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "The types of all values are checked before any of them are appended"
+    )]
+    pub fn append_row(
+        &mut self,
+        row: IndexMap<Ident, LiteralValue>,
+    ) -> Result<(), OwnedTableError> {
+        if let Some(name) = row.keys().find(|name| !self.table.contains_key(*name)) {
+            return Err(OwnedTableError::ColumnNotFound {
+                column_name: name.value.clone(),
+            });
+        }
+        for (name, column) in &self.table {
+            let value = row
+                .get(name)
+                .ok_or_else(|| OwnedTableError::MissingColumn {
+                    column_name: name.value.clone(),
+                })?;
+            if value.column_type() != column.column_type() {
+                return Err(OwnedTableError::TypeMismatch {
+                    expected: column.column_type(),
+                    actual: value.column_type(),
+                });
+            }
+        }
+        for (name, column) in &mut self.table {
+            column
+                .try_push_literal(&row[name])
+                .expect("value types were checked above");
+        }
+        Ok(())
+    }
+
+    /// Renders the table as a GitHub-flavored Markdown table.
+    ///
+    /// Numeric columns are right-aligned and all other columns are left-aligned. Strings longer than
+    /// [`MARKDOWN_MAX_STRING_LENGTH`] characters are truncated and end with `…`, and `|` characters
+    /// are escaped so that they do not end a cell. A table without columns renders as an empty string.
+    #[must_use]
+    pub fn to_markdown_table(&self) -> String {
+        if self.table.is_empty() {
+            return String::new();
+        }
+        let header = self
+            .table
+            .keys()
+            .map(|name| escape_markdown(&name.value))
+            .join(" | ");
+        let alignment = self
+            .table
+            .values()
+            .map(|column| {
+                if column.column_type().is_numeric() {
+                    "---:"
+                } else {
+                    ":---"
+                }
+            })
+            .join(" | ");
+        let cells = self.table.values().map(markdown_cells).collect::<Vec<_>>();
+        let rows = (0..self.num_rows()).map(|i| cells.iter().map(|column| &column[i]).join(" | "));
+        core::iter::once(header)
+            .chain(core::iter::once(alignment))
+            .chain(rows)
+            .map(|row| format!("| {row} |\n"))
+            .collect()
+    }
+
+    /// Pads every column of the table with the matching value from `fill` so that the number of
+    /// rows becomes the next power of two.
+    ///
+    /// Columns only need a fill value if the table is not already a power of two in length.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::MissingFillValue`] if a column has no entry in `fill` and
+    /// [`OwnedTableError::TypeMismatch`] if a fill value does not match the type of its column.
+    pub fn pad_all_to_power_of_two(
+        &self,
+        fill: &IndexMap<Ident, LiteralValue>,
+    ) -> Result<Self, OwnedTableError> {
+        let num_rows = self.num_rows().next_power_of_two();
+        if num_rows == self.num_rows() {
+            return Ok(self.clone());
+        }
+        self.table
+            .iter()
+            .map(|(name, column)| -> Result<_, OwnedTableError> {
+                let fill_value =
+                    fill.get(name)
+                        .ok_or_else(|| OwnedTableError::MissingFillValue {
+                            column_name: name.value.clone(),
+                        })?;
+                Ok((name.clone(), column.pad_to_length(num_rows, fill_value)?))
+            })
+            .process_results(Self::try_from_iter)?
+    }
 
     pub(crate) fn mle_evaluations(&self, evaluation_point: &[S]) -> Vec<S> {
         let mut evaluation_vector = vec![S::ZERO; self.num_rows()];
@@ -146,6 +766,67 @@ impl<S: Scalar> OwnedTable<S> {
     }
 }
 
+/// The maximum number of characters of a string shown by [`OwnedTable::to_markdown_table`].
+pub const MARKDOWN_MAX_STRING_LENGTH: usize = 50;
+
+/// Escapes `text` so that it can be used as the content of a Markdown table cell.
+fn escape_markdown(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+/// Renders every value of `column` as the content of a Markdown table cell.
+fn markdown_cells<S: Scalar>(column: &OwnedColumn<S>) -> Vec<String> {
+    match column {
+        OwnedColumn::Boolean(col) => col.iter().map(ToString::to_string).collect(),
+        OwnedColumn::Uint8(col) => col.iter().map(ToString::to_string).collect(),
+        OwnedColumn::TinyInt(col) => col.iter().map(ToString::to_string).collect(),
+        OwnedColumn::SmallInt(col) => col.iter().map(ToString::to_string).collect(),
+        OwnedColumn::Int(col) | OwnedColumn::Date(col) => {
+            col.iter().map(ToString::to_string).collect()
+        }
+        OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => {
+            col.iter().map(ToString::to_string).collect()
+        }
+        OwnedColumn::UInt64(col) => col.iter().map(ToString::to_string).collect(),
+        OwnedColumn::Int128(col) => col.iter().map(ToString::to_string).collect(),
+        OwnedColumn::VarChar(col) => col
+            .iter()
+            .map(|string| {
+                if string.chars().count() > MARKDOWN_MAX_STRING_LENGTH {
+                    let truncated: String =
+                        string.chars().take(MARKDOWN_MAX_STRING_LENGTH).collect();
+                    escape_markdown(&truncated) + "…"
+                } else {
+                    escape_markdown(string)
+                }
+            })
+            .collect(),
+        OwnedColumn::VarBinary(col) => col
+            .iter()
+            .map(|bytes| {
+                core::iter::once(String::from("0x"))
+                    .chain(bytes.iter().map(|byte| format!("{byte:02x}")))
+                    .collect()
+            })
+            .collect(),
+        OwnedColumn::Scalar(col) => col
+            .iter()
+            .map(|value| Into::<num_bigint::BigInt>::into(*value).to_string())
+            .collect(),
+        OwnedColumn::Decimal75(_, scale, col) => col
+            .iter()
+            .map(|value| BigDecimal::new((*value).into(), (*scale).into()).to_string())
+            .collect(),
+    }
+}
+
+/// Formats the table as [`OwnedTable::to_markdown_table`] does.
+impl<S: Scalar> core::fmt::Display for OwnedTable<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_markdown_table())
+    }
+}
+
 // Note: we modify the default PartialEq for IndexMap to also check for column ordering.
 // This is to align with the behaviour of a `RecordBatch`.
 impl<S: Scalar> PartialEq for OwnedTable<S> {
@@ -1,12 +1,17 @@
 use crate::{
     base::{
-        database::{owned_table_utility::*, OwnedColumn, OwnedTable, OwnedTableError},
-        map::IndexMap,
+        database::{
+            owned_table_utility::*, ColumnField, ColumnType, LiteralValue, OwnedColumn, OwnedTable,
+            OwnedTableError, SortKey, MARKDOWN_MAX_STRING_LENGTH,
+        },
+        map::{indexmap, IndexMap},
+        math::decimal::Precision,
         posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
         scalar::test_scalar::TestScalar,
     },
     proof_primitive::dory::DoryScalar,
 };
+use itertools::EitherOrBoth;
 use sqlparser::ast::Ident;
 #[test]
 fn we_can_create_an_owned_table_with_no_columns() {
@@ -31,6 +36,19 @@ fn we_can_create_an_empty_owned_table() {
     assert_eq!(owned_table.into_inner(), table);
 }
 #[test]
+fn we_can_get_the_number_of_rows_in_an_owned_table() {
+    let no_columns = OwnedTable::<TestScalar>::try_new(IndexMap::default()).unwrap();
+    assert_eq!(no_columns.num_rows(), 0);
+
+    let no_rows = owned_table::<TestScalar>([bigint("a", [0; 0]), varchar("b", ["0"; 0])]);
+    assert_eq!(no_rows.num_columns(), 2);
+    assert_eq!(no_rows.num_rows(), 0);
+
+    let populated =
+        owned_table::<TestScalar>([bigint("a", [1, 2, 3]), varchar("b", ["x", "y", "z"])]);
+    assert_eq!(populated.num_rows(), 3);
+}
+#[test]
 fn we_can_create_an_owned_table_with_data() {
     let owned_table = owned_table([
         bigint("bigint", [0, 1, 2, 3, 4, 5, 6, i64::MIN, i64::MAX]),
@@ -102,6 +120,33 @@ fn we_can_create_an_owned_table_with_data() {
     assert_eq!(owned_table.into_inner(), table);
 }
 #[test]
+fn we_can_create_timestamp_columns_with_precision_specific_helpers() {
+    let tz = PoSQLTimeZone::new(19800);
+    let data = [-1, 0, 1_625_072_400];
+    let table = owned_table::<TestScalar>([
+        timestamptz_s("s", tz, data),
+        timestamptz_ms("ms", tz, data),
+        timestamptz_us("us", tz, data),
+        timestamptz_ns("ns", tz, data),
+    ]);
+    let expected = owned_table::<TestScalar>([
+        timestamptz("s", PoSQLTimeUnit::Second, tz, data),
+        timestamptz("ms", PoSQLTimeUnit::Millisecond, tz, data),
+        timestamptz("us", PoSQLTimeUnit::Microsecond, tz, data),
+        timestamptz("ns", PoSQLTimeUnit::Nanosecond, tz, data),
+    ]);
+    assert_eq!(table, expected);
+    assert_eq!(table.num_rows(), 3);
+    assert_eq!(
+        table.column_by_index(3),
+        Some(&OwnedColumn::TimestampTZ(
+            PoSQLTimeUnit::Nanosecond,
+            tz,
+            data.to_vec()
+        ))
+    );
+}
+#[test]
 fn we_get_inequality_between_tables_with_differing_column_order() {
     let owned_table_a: OwnedTable<TestScalar> = owned_table([
         bigint("a", [0; 0]),
@@ -167,3 +212,935 @@ fn we_cannot_create_an_owned_table_with_differing_column_lengths() {
         Err(OwnedTableError::ColumnLengthMismatch)
     ));
 }
+#[test]
+fn we_cannot_create_an_owned_table_with_int_columns_of_differing_lengths() {
+    assert!(matches!(
+        OwnedTable::<TestScalar>::try_from_iter([int("a", [i32::MIN, i32::MAX]), int("b", [0]),]),
+        Err(OwnedTableError::ColumnLengthMismatch)
+    ));
+    assert!(matches!(
+        OwnedTable::<TestScalar>::try_from_iter([int("a", [0; 0]), bigint("b", [1])]),
+        Err(OwnedTableError::ColumnLengthMismatch)
+    ));
+}
+#[test]
+fn we_can_pad_all_columns_of_a_table_to_a_power_of_two() {
+    let table: OwnedTable<TestScalar> =
+        owned_table([bigint("a", [1, 2, 3]), varchar("b", ["x", "y", "z"])]);
+    let fill = indexmap! {
+        Ident::new("a") => LiteralValue::BigInt(0),
+        Ident::new("b") => LiteralValue::VarChar(String::new()),
+    };
+    let expected: OwnedTable<TestScalar> =
+        owned_table([bigint("a", [1, 2, 3, 0]), varchar("b", ["x", "y", "z", ""])]);
+    assert_eq!(table.pad_all_to_power_of_two(&fill).unwrap(), expected);
+
+    // Tables that are already a power of two in length are left untouched
+    assert_eq!(
+        expected
+            .pad_all_to_power_of_two(&IndexMap::default())
+            .unwrap(),
+        expected
+    );
+}
+#[test]
+fn we_cannot_pad_a_table_with_missing_or_mistyped_fill_values() {
+    let table: OwnedTable<TestScalar> = owned_table([bigint("a", [1, 2, 3])]);
+    assert_eq!(
+        table.pad_all_to_power_of_two(&IndexMap::default()),
+        Err(OwnedTableError::MissingFillValue {
+            column_name: "a".to_string()
+        })
+    );
+    assert_eq!(
+        table.pad_all_to_power_of_two(&indexmap! {Ident::new("a") => LiteralValue::Boolean(false)}),
+        Err(OwnedTableError::TypeMismatch {
+            expected: ColumnType::BigInt,
+            actual: ColumnType::Boolean
+        })
+    );
+}
+
+#[test]
+fn we_can_compare_the_schemas_of_owned_tables() {
+    let table = owned_table::<TestScalar>([bigint("a", [1, 2]), varchar("b", ["x", "y"])]);
+    let same_schema = owned_table::<TestScalar>([bigint("a", [3]), varchar("b", ["z"])]);
+    assert!(table.equal_schema(&same_schema));
+    assert!(table.schema_diff(&same_schema).is_empty());
+
+    let different_type = owned_table::<TestScalar>([int("a", [1, 2]), varchar("b", ["x", "y"])]);
+    assert!(!table.equal_schema(&different_type));
+    assert_eq!(
+        table.schema_diff(&different_type),
+        vec![EitherOrBoth::Both(
+            ColumnField::new("a".into(), ColumnType::BigInt),
+            ColumnField::new("a".into(), ColumnType::Int)
+        )]
+    );
+
+    let different_order =
+        owned_table::<TestScalar>([varchar("b", ["x", "y"]), bigint("a", [1, 2])]);
+    assert!(!table.equal_schema(&different_order));
+    assert_eq!(table.schema_diff(&different_order).len(), 2);
+
+    let fewer_columns = owned_table::<TestScalar>([bigint("a", [1, 2])]);
+    assert!(!table.equal_schema(&fewer_columns));
+    assert_eq!(
+        table.schema_diff(&fewer_columns),
+        vec![EitherOrBoth::Left(ColumnField::new(
+            "b".into(),
+            ColumnType::VarChar
+        ))]
+    );
+}
+
+#[test]
+fn we_can_compute_composite_keys_of_an_owned_table() {
+    let table = owned_table::<TestScalar>([
+        bigint("year", [2023, 2024, 2023]),
+        varchar("month", ["jan", "feb", "jan"]),
+        boolean("flag", [true, false, false]),
+    ]);
+    let cols = [Ident::new("year"), Ident::new("month")];
+    assert_eq!(
+        table.composite_key(&cols).unwrap(),
+        vec![
+            vec![
+                LiteralValue::BigInt(2023),
+                LiteralValue::VarChar("jan".to_string())
+            ],
+            vec![
+                LiteralValue::BigInt(2024),
+                LiteralValue::VarChar("feb".to_string())
+            ],
+            vec![
+                LiteralValue::BigInt(2023),
+                LiteralValue::VarChar("jan".to_string())
+            ],
+        ]
+    );
+    let sort_key = table.composite_sort_key(&cols).unwrap();
+    assert_eq!(sort_key.len(), 3);
+    assert_eq!(sort_key[0], sort_key[2]);
+    assert_ne!(sort_key[0], sort_key[1]);
+    assert!(table.composite_key(&[]).unwrap().iter().all(Vec::is_empty));
+}
+
+#[test]
+fn we_cannot_compute_composite_keys_with_missing_columns() {
+    let table = owned_table::<TestScalar>([bigint("year", [2023])]);
+    let cols = [Ident::new("year"), Ident::new("month")];
+    assert_eq!(
+        table.composite_key(&cols),
+        Err(OwnedTableError::ColumnNotFound {
+            column_name: "month".to_string()
+        })
+    );
+    assert_eq!(
+        table.composite_sort_key(&cols),
+        Err(OwnedTableError::ColumnNotFound {
+            column_name: "month".to_string()
+        })
+    );
+}
+
+#[test]
+fn we_can_count_the_nulls_in_each_column_of_an_owned_table() {
+    let table = owned_table::<TestScalar>([
+        varchar("b", ["x", "y"]),
+        bigint("a", [1, 2]),
+        boolean("c", [true, false]),
+    ]);
+    let null_counts = table.null_count_per_column();
+    assert_eq!(
+        null_counts,
+        indexmap! {
+            Ident::new("b") => 0,
+            Ident::new("a") => 0,
+            Ident::new("c") => 0,
+        }
+    );
+    assert!(null_counts.keys().eq(table.column_names()));
+    assert!(OwnedTable::<TestScalar>::try_new(IndexMap::default())
+        .unwrap()
+        .null_count_per_column()
+        .is_empty());
+}
+
+#[test]
+fn we_can_get_the_schema_of_an_owned_table() {
+    assert!(OwnedTable::<TestScalar>::try_new(IndexMap::default())
+        .unwrap()
+        .schema()
+        .is_empty());
+    assert_eq!(
+        owned_table::<TestScalar>([varchar("a", ["x"])]).schema(),
+        indexmap! { Ident::new("a") => ColumnType::VarChar }
+    );
+    let table = owned_table::<TestScalar>([
+        bigint("c", [1]),
+        decimal75("a", 10, 2, [1]),
+        timestamptz("b", PoSQLTimeUnit::Second, PoSQLTimeZone::utc(), [1]),
+    ]);
+    let schema = table.schema();
+    assert_eq!(
+        schema,
+        indexmap! {
+            Ident::new("c") => ColumnType::BigInt,
+            Ident::new("a") => ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+            Ident::new("b") => ColumnType::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::utc()),
+        }
+    );
+    assert!(schema.keys().eq(table.column_names()));
+}
+
+#[test]
+fn we_can_project_an_owned_table() {
+    let table = owned_table::<TestScalar>([
+        bigint("a", [1, 2]),
+        varchar("b", ["x", "y"]),
+        boolean("c", [true, false]),
+    ]);
+    let all = [Ident::new("a"), Ident::new("b"), Ident::new("c")];
+    assert_eq!(table.project(&all).unwrap(), table);
+    assert_eq!(
+        table.project(&[Ident::new("c")]).unwrap(),
+        owned_table([boolean("c", [true, false])])
+    );
+    let reordered = table.project(&[Ident::new("c"), Ident::new("a")]).unwrap();
+    assert!(reordered
+        .column_names()
+        .eq(&[Ident::new("c"), Ident::new("a")]));
+    assert_eq!(
+        reordered,
+        owned_table([boolean("c", [true, false]), bigint("a", [1, 2])])
+    );
+    assert!(table.project(&[]).unwrap().is_empty());
+}
+
+#[test]
+fn we_cannot_project_missing_or_duplicate_columns() {
+    let table = owned_table::<TestScalar>([bigint("a", [1, 2]), varchar("b", ["x", "y"])]);
+    assert_eq!(
+        table.project(&[Ident::new("a"), Ident::new("d")]),
+        Err(OwnedTableError::ColumnNotFound {
+            column_name: "d".to_string()
+        })
+    );
+    assert_eq!(
+        table.project(&[Ident::new("a"), Ident::new("a")]),
+        Err(OwnedTableError::DuplicateColumnName {
+            column_name: "a".to_string()
+        })
+    );
+}
+
+#[test]
+fn we_can_get_the_column_names_and_columns_of_an_owned_table() {
+    let table = owned_table::<TestScalar>([
+        bigint("b", [1, 2]),
+        varchar("a", ["x", "y"]),
+        boolean("c", [true, false]),
+    ]);
+    assert!(table
+        .column_names()
+        .eq(&[Ident::new("b"), Ident::new("a"), Ident::new("c")]));
+    assert_eq!(
+        table.get_column(&"a".into()),
+        Some(&OwnedColumn::VarChar(vec![
+            "x".to_string(),
+            "y".to_string()
+        ]))
+    );
+    assert_eq!(table.get_column(&"missing".into()), None);
+}
+
+#[test]
+fn we_can_iterate_over_the_columns_of_an_owned_table() {
+    let table = owned_table::<TestScalar>([bigint("b", [1, 2]), varchar("a", ["x", "y"])]);
+    let columns: Vec<_> = table.iter_columns().collect();
+    assert_eq!(
+        columns,
+        vec![
+            (&Ident::new("b"), &OwnedColumn::BigInt(vec![1, 2])),
+            (
+                &Ident::new("a"),
+                &OwnedColumn::VarChar(vec!["x".to_string(), "y".to_string()])
+            ),
+        ]
+    );
+    assert!(OwnedTable::<TestScalar>::try_new(IndexMap::default())
+        .unwrap()
+        .iter_columns()
+        .next()
+        .is_none());
+}
+
+#[test]
+fn we_can_rename_a_column_of_an_owned_table() {
+    let mut table = owned_table::<TestScalar>([
+        bigint("a", [1, 2]),
+        varchar("b", ["x", "y"]),
+        boolean("c", [true, false]),
+    ]);
+    table.rename_column(&"b".into(), "alias".into()).unwrap();
+    assert!(table
+        .column_names()
+        .eq(&[Ident::new("a"), Ident::new("alias"), Ident::new("c")]));
+    assert_eq!(
+        table,
+        owned_table([
+            bigint("a", [1, 2]),
+            varchar("alias", ["x", "y"]),
+            boolean("c", [true, false]),
+        ])
+    );
+    table.rename_column(&"a".into(), "a".into()).unwrap();
+    assert_eq!(table.column_names().next(), Some(&Ident::new("a")));
+}
+
+#[test]
+fn we_cannot_rename_a_missing_column_or_to_an_existing_name() {
+    let mut table = owned_table::<TestScalar>([bigint("a", [1]), bigint("b", [2])]);
+    assert_eq!(
+        table.rename_column(&"missing".into(), "c".into()),
+        Err(OwnedTableError::ColumnNotFound {
+            column_name: "missing".to_string()
+        })
+    );
+    assert_eq!(
+        table.rename_column(&"a".into(), "b".into()),
+        Err(OwnedTableError::DuplicateColumnName {
+            column_name: "b".to_string()
+        })
+    );
+    assert_eq!(table, owned_table([bigint("a", [1]), bigint("b", [2])]));
+}
+
+#[test]
+fn we_can_slice_an_owned_table() {
+    let table = owned_table::<TestScalar>([
+        bigint("a", [1, 2, 3, 4]),
+        varchar("b", ["w", "x", "y", "z"]),
+    ]);
+    assert_eq!(
+        table.slice(0..2),
+        owned_table([bigint("a", [1, 2]), varchar("b", ["w", "x"])])
+    );
+    assert_eq!(
+        table.slice(1..=2),
+        owned_table([bigint("a", [2, 3]), varchar("b", ["x", "y"])])
+    );
+    assert_eq!(table.slice(..), table);
+    assert_eq!(table.slice(0..4), table);
+}
+
+#[test]
+fn we_can_slice_an_owned_table_past_its_end() {
+    let table =
+        owned_table::<TestScalar>([bigint("a", [1, 2, 3]), boolean("b", [true, false, true])]);
+    assert_eq!(
+        table.slice(2..10),
+        owned_table([bigint("a", [3]), boolean("b", [true])])
+    );
+    let none = table.slice(5..);
+    assert_eq!(none.num_rows(), 0);
+    assert!(none.equal_schema(&table));
+    assert_eq!(table.slice(3..3).num_rows(), 0);
+
+    let empty = OwnedTable::<TestScalar>::try_new(IndexMap::default()).unwrap();
+    assert_eq!(empty.slice(1..3), empty);
+}
+
+#[test]
+fn we_can_filter_an_owned_table_with_a_mask() {
+    let table = owned_table::<TestScalar>([
+        bigint("a", [1, 2, 3, 4]),
+        varchar("b", ["w", "x", "y", "z"]),
+        decimal75("c", 10, 2, [5, 6, 7, 8]),
+        timestamptz(
+            "d",
+            PoSQLTimeUnit::Second,
+            PoSQLTimeZone::utc(),
+            [9, 10, 11, 12],
+        ),
+    ]);
+    assert_eq!(table.filter(&[true; 4]).unwrap(), table);
+    let none = table.filter(&[false; 4]).unwrap();
+    assert_eq!(none.num_rows(), 0);
+    assert!(none.equal_schema(&table));
+    assert_eq!(
+        table.filter(&[true, false, true, false]).unwrap(),
+        owned_table([
+            bigint("a", [1, 3]),
+            varchar("b", ["w", "y"]),
+            decimal75("c", 10, 2, [5, 7]),
+            timestamptz("d", PoSQLTimeUnit::Second, PoSQLTimeZone::utc(), [9, 11]),
+        ])
+    );
+}
+
+#[test]
+fn we_cannot_filter_an_owned_table_with_a_mask_of_the_wrong_length() {
+    let table = owned_table::<TestScalar>([bigint("a", [1, 2, 3])]);
+    assert_eq!(
+        table.filter(&[true, false]),
+        Err(OwnedTableError::RowCountMismatch {
+            expected: 3,
+            actual: 2
+        })
+    );
+}
+
+#[test]
+fn we_can_create_an_owned_table_from_dicts() {
+    let rows = vec![
+        indexmap! {
+            "b".to_string() => LiteralValue::BigInt(1),
+            "a".to_string() => LiteralValue::VarChar("x".to_string()),
+        },
+        indexmap! {
+            "a".to_string() => LiteralValue::VarChar("y".to_string()),
+            "b".to_string() => LiteralValue::BigInt(2),
+        },
+    ];
+    let table = OwnedTable::<TestScalar>::from_dicts(rows).unwrap();
+    assert_eq!(
+        table,
+        owned_table([bigint("b", [1, 2]), varchar("a", ["x", "y"])])
+    );
+    assert_eq!(
+        OwnedTable::<TestScalar>::from_dicts(Vec::<Vec<(String, LiteralValue)>>::new()).unwrap(),
+        owned_table::<TestScalar>([])
+    );
+}
+
+#[test]
+fn we_cannot_create_an_owned_table_from_dicts_with_missing_or_mistyped_values() {
+    let rows = vec![
+        vec![("a".to_string(), LiteralValue::BigInt(1))],
+        vec![
+            ("a".to_string(), LiteralValue::BigInt(2)),
+            ("b".to_string(), LiteralValue::Boolean(true)),
+        ],
+    ];
+    assert_eq!(
+        OwnedTable::<TestScalar>::from_dicts(rows),
+        Err(OwnedTableError::MissingColumn {
+            column_name: "b".to_string()
+        })
+    );
+
+    let rows = vec![
+        vec![("a".to_string(), LiteralValue::BigInt(1))],
+        vec![("a".to_string(), LiteralValue::Int(2))],
+    ];
+    assert_eq!(
+        OwnedTable::<TestScalar>::from_dicts(rows),
+        Err(OwnedTableError::TypeMismatch {
+            expected: ColumnType::BigInt,
+            actual: ColumnType::Int
+        })
+    );
+}
+
+#[test]
+fn we_can_select_rows_of_an_owned_table_where_a_predicate_holds() {
+    let table = owned_table::<TestScalar>([
+        bigint("a", [1, 2, 3, 4]),
+        varchar("b", ["w", "x", "y", "z"]),
+    ]);
+    let selected = table
+        .select_rows_where(
+            &"a".into(),
+            |value| matches!(value, LiteralValue::BigInt(v) if v % 2 == 0),
+        )
+        .unwrap();
+    assert_eq!(
+        selected,
+        owned_table([bigint("a", [2, 4]), varchar("b", ["x", "z"])])
+    );
+    let none = table.select_rows_where(&"b".into(), |_| false).unwrap();
+    assert_eq!(
+        none,
+        owned_table([bigint("a", [0; 0]), varchar("b", [""; 0])])
+    );
+}
+
+#[test]
+fn we_cannot_select_rows_of_an_owned_table_using_a_missing_column() {
+    let table = owned_table::<TestScalar>([bigint("a", [1, 2])]);
+    assert_eq!(
+        table.select_rows_where(&"c".into(), |_| true),
+        Err(OwnedTableError::ColumnNotFound {
+            column_name: "c".to_string()
+        })
+    );
+}
+
+#[test]
+fn we_can_append_rows_to_an_owned_table() {
+    let mut table = owned_table::<TestScalar>([bigint("a", [1]), varchar("b", ["x"])]);
+    table
+        .append_row(indexmap! {
+            "b".into() => LiteralValue::VarChar("y".to_string()),
+            "a".into() => LiteralValue::BigInt(2),
+        })
+        .unwrap();
+    assert_eq!(
+        table,
+        owned_table([bigint("a", [1, 2]), varchar("b", ["x", "y"])])
+    );
+}
+
+#[test]
+fn we_cannot_append_invalid_rows_to_an_owned_table() {
+    let mut table = owned_table::<TestScalar>([bigint("a", [1]), varchar("b", ["x"])]);
+    let expected = table.clone();
+    assert_eq!(
+        table.append_row(indexmap! {
+            "a".into() => LiteralValue::BigInt(2),
+            "b".into() => LiteralValue::VarChar("y".to_string()),
+            "c".into() => LiteralValue::Boolean(true),
+        }),
+        Err(OwnedTableError::ColumnNotFound {
+            column_name: "c".to_string()
+        })
+    );
+    assert_eq!(
+        table.append_row(indexmap! {
+            "a".into() => LiteralValue::BigInt(2),
+        }),
+        Err(OwnedTableError::MissingColumn {
+            column_name: "b".to_string()
+        })
+    );
+    assert_eq!(
+        table.append_row(indexmap! {
+            "a".into() => LiteralValue::BigInt(2),
+            "b".into() => LiteralValue::Int(3),
+        }),
+        Err(OwnedTableError::TypeMismatch {
+            expected: ColumnType::VarChar,
+            actual: ColumnType::Int
+        })
+    );
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn we_can_render_an_owned_table_as_markdown() {
+    let table = owned_table::<TestScalar>([
+        bigint("id", [1, -20]),
+        varchar("name", ["a|b".to_string(), "x".repeat(60)]),
+        boolean("flag", [true, false]),
+        decimal75("price", 10, 2, [12345, -5]),
+    ]);
+    let expected = format!(
+        "| id | name | flag | price |\n\
+         | ---: | :--- | :--- | ---: |\n\
+         | 1 | a\\|b | true | 123.45 |\n\
+         | -20 | {}… | false | -0.05 |\n",
+        "x".repeat(50)
+    );
+    assert_eq!(table.to_markdown_table(), expected);
+}
+
+#[test]
+fn we_can_display_an_owned_table_as_markdown() {
+    let table = owned_table::<TestScalar>([
+        scalar("s", [-1]),
+        varchar("name", ["y".repeat(MARKDOWN_MAX_STRING_LENGTH + 1)]),
+        int("n", [7]),
+    ]);
+    let expected = format!(
+        "| s | name | n |\n\
+         | ---: | :--- | ---: |\n\
+         | -1 | {}… | 7 |\n",
+        "y".repeat(MARKDOWN_MAX_STRING_LENGTH)
+    );
+    assert_eq!(table.to_string(), expected);
+    assert_eq!(table.to_string(), table.to_markdown_table());
+
+    let no_rows = owned_table::<TestScalar>([bigint("a", [0; 0])]);
+    assert_eq!(no_rows.to_string(), "| a |\n| ---: |\n");
+}
+
+#[test]
+fn we_can_render_tables_without_rows_or_columns_as_markdown() {
+    let table = owned_table::<TestScalar>([bigint("a", [0; 0]), varchar("b", [""; 0])]);
+    assert_eq!(table.to_markdown_table(), "| a | b |\n| ---: | :--- |\n");
+    let empty = OwnedTable::<TestScalar>::try_new(IndexMap::default()).unwrap();
+    assert_eq!(empty.to_markdown_table(), "");
+}
+
+#[test]
+fn we_can_sort_an_owned_table_by_multiple_keys() {
+    let table = owned_table::<TestScalar>([
+        bigint("a", [2, 1, 2, 1, 2]),
+        varchar("b", ["x", "y", "y", "x", "x"]),
+        int128("c", [0, 1, 2, 3, 4]),
+    ]);
+    let keys = [
+        SortKey {
+            column: "a".into(),
+            descending: false,
+            nulls_last: true,
+        },
+        SortKey {
+            column: "b".into(),
+            descending: true,
+            nulls_last: false,
+        },
+    ];
+    let expected = owned_table([
+        bigint("a", [1, 1, 2, 2, 2]),
+        varchar("b", ["y", "x", "y", "x", "x"]),
+        int128("c", [1, 3, 2, 0, 4]),
+    ]);
+    assert_eq!(table.sort_by_keys(&keys).unwrap(), expected);
+
+    // No keys keep the original order
+    assert_eq!(table.sort_by_keys(&[]).unwrap(), table);
+}
+
+#[test]
+fn we_can_sort_an_owned_table_by_a_single_key_in_either_direction() {
+    let table = owned_table::<TestScalar>([
+        varchar("a", ["b", "c", "a"]),
+        boolean("b", [true, false, true]),
+    ]);
+    let key = |descending| SortKey {
+        column: "a".into(),
+        descending,
+        nulls_last: false,
+    };
+    assert_eq!(
+        table.sort_by_keys(&[key(false)]).unwrap(),
+        owned_table([
+            varchar("a", ["a", "b", "c"]),
+            boolean("b", [true, true, false])
+        ])
+    );
+    assert_eq!(
+        table.sort_by_keys(&[key(true)]).unwrap(),
+        owned_table([
+            varchar("a", ["c", "b", "a"]),
+            boolean("b", [false, true, true])
+        ])
+    );
+
+    let empty = owned_table::<TestScalar>([varchar("a", [""; 0]), boolean("b", [true; 0])]);
+    assert_eq!(empty.sort_by_keys(&[key(true)]).unwrap(), empty);
+}
+
+#[test]
+fn we_cannot_sort_an_owned_table_by_a_missing_column() {
+    let table = owned_table::<TestScalar>([bigint("a", [2, 1])]);
+    let keys = [SortKey {
+        column: "missing".into(),
+        descending: false,
+        nulls_last: false,
+    }];
+    assert_eq!(
+        table.sort_by_keys(&keys),
+        Err(OwnedTableError::ColumnNotFound {
+            column_name: "missing".to_string()
+        })
+    );
+}
+
+#[test]
+fn we_can_get_the_single_row_of_an_owned_table() {
+    let table = owned_table::<TestScalar>([
+        bigint("count", [3]),
+        varchar("name", ["a"]),
+        boolean("flag", [true]),
+    ]);
+    table.assert_row_count(1);
+    assert_eq!(
+        table.expect_single_row().unwrap(),
+        vec![
+            LiteralValue::BigInt(3),
+            LiteralValue::VarChar("a".to_string()),
+            LiteralValue::Boolean(true)
+        ]
+    );
+}
+
+#[test]
+fn we_cannot_get_the_single_row_of_an_owned_table_without_exactly_one_row() {
+    let table = owned_table::<TestScalar>([bigint("a", [1, 2])]);
+    table.assert_row_count(2);
+    assert_eq!(
+        table.expect_single_row(),
+        Err(OwnedTableError::RowCountMismatch {
+            expected: 1,
+            actual: 2
+        })
+    );
+    let empty = owned_table::<TestScalar>([bigint("a", [0; 0])]);
+    assert_eq!(
+        empty.expect_single_row(),
+        Err(OwnedTableError::RowCountMismatch {
+            expected: 1,
+            actual: 0
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "expected 3 rows, but the table has 2 rows and columns [a, b]")]
+fn we_get_the_row_count_and_column_names_when_a_row_count_assertion_fails() {
+    owned_table::<TestScalar>([bigint("a", [1, 2]), varchar("b", ["x", "y"])]).assert_row_count(3);
+}
+
+#[test]
+fn we_can_concat_owned_tables() {
+    let top = owned_table::<TestScalar>([bigint("a", [1, 2]), varchar("b", ["x", "y"])]);
+    let bottom = owned_table([varchar("b", ["z"]), bigint("a", [3])]);
+    let result = top.concat(bottom).unwrap();
+    assert!(result
+        .column_names()
+        .eq(&[Ident::new("a"), Ident::new("b")]));
+    assert_eq!(
+        result,
+        owned_table([bigint("a", [1, 2, 3]), varchar("b", ["x", "y", "z"])])
+    );
+}
+
+#[test]
+fn we_can_concat_an_owned_table_without_rows_on_either_side() {
+    let empty = owned_table::<TestScalar>([bigint("a", [0; 0]), boolean("b", [true; 0])]);
+    let table = owned_table::<TestScalar>([bigint("a", [1, 2]), boolean("b", [true, false])]);
+    assert_eq!(empty.clone().concat(table.clone()).unwrap(), table);
+    assert_eq!(table.clone().concat(empty).unwrap(), table);
+}
+
+#[test]
+fn we_cannot_concat_owned_tables_with_different_schemas() {
+    let table = owned_table::<TestScalar>([bigint("a", [1]), bigint("b", [2])]);
+    assert_eq!(
+        table
+            .clone()
+            .concat(owned_table([bigint("a", [1]), bigint("c", [2])])),
+        Err(OwnedTableError::ColumnSchemaMismatch)
+    );
+    assert_eq!(
+        table.clone().concat(owned_table([bigint("a", [1])])),
+        Err(OwnedTableError::ColumnSchemaMismatch)
+    );
+    assert_eq!(
+        table.concat(owned_table([bigint("a", [1]), varchar("b", ["2"])])),
+        Err(OwnedTableError::TypeMismatch {
+            expected: ColumnType::BigInt,
+            actual: ColumnType::VarChar
+        })
+    );
+}
+
+#[test]
+fn we_can_concat_many_owned_tables() {
+    let tables = vec![
+        owned_table::<TestScalar>([bigint("a", [1, 2]), varchar("b", ["x", "y"])]),
+        owned_table([varchar("b", ["z"]), bigint("a", [3])]),
+        owned_table([bigint("a", [0; 0]), varchar("b", [""; 0])]),
+        owned_table([bigint("a", [4, 5]), varchar("b", ["v", "w"])]),
+    ];
+    assert_eq!(
+        OwnedTable::concat_many(tables).unwrap(),
+        owned_table([
+            bigint("a", [1, 2, 3, 4, 5]),
+            varchar("b", ["x", "y", "z", "v", "w"]),
+        ])
+    );
+}
+
+#[test]
+fn we_can_concat_many_owned_tables_with_zero_or_one_table() {
+    assert_eq!(
+        OwnedTable::<TestScalar>::concat_many(vec![]).unwrap(),
+        OwnedTable::try_new(IndexMap::default()).unwrap()
+    );
+    let table = owned_table::<TestScalar>([bigint("a", [1, 2]), boolean("b", [true, false])]);
+    assert_eq!(OwnedTable::concat_many(vec![table.clone()]).unwrap(), table);
+}
+
+#[test]
+fn we_cannot_concat_many_owned_tables_with_different_schemas() {
+    let first = owned_table::<TestScalar>([bigint("a", [1]), bigint("b", [2])]);
+    assert_eq!(
+        OwnedTable::concat_many(vec![
+            first.clone(),
+            first.clone(),
+            owned_table([bigint("a", [1]), bigint("c", [2])]),
+        ]),
+        Err(OwnedTableError::ColumnSchemaMismatch)
+    );
+    assert_eq!(
+        OwnedTable::concat_many(vec![first.clone(), owned_table([bigint("a", [1])])]),
+        Err(OwnedTableError::ColumnSchemaMismatch)
+    );
+    assert_eq!(
+        OwnedTable::concat_many(vec![first, owned_table([bigint("a", [1]), int("b", [2])])]),
+        Err(OwnedTableError::TypeMismatch {
+            expected: ColumnType::BigInt,
+            actual: ColumnType::Int
+        })
+    );
+}
+#[test]
+fn we_can_downcast_owned_table_columns_to_their_values() {
+    let table = owned_table::<TestScalar>([
+        bigint("a", [1, -2]),
+        int128("b", [3, 4]),
+        varchar("c", ["x", "y"]),
+        boolean("d", [true, false]),
+        tinyint("e", [i8::MIN, i8::MAX]),
+        smallint("f", [5_i16, 6]),
+        int("g", [7, 8]),
+    ]);
+    assert_eq!(
+        table.downcast_column::<i64>(&Ident::new("a")).unwrap(),
+        &vec![1, -2]
+    );
+    assert_eq!(
+        table.downcast_column::<i128>(&Ident::new("b")).unwrap(),
+        &vec![3, 4]
+    );
+    assert_eq!(
+        table.downcast_column::<String>(&Ident::new("c")).unwrap(),
+        &vec!["x".to_string(), "y".to_string()]
+    );
+    assert_eq!(
+        table.downcast_column::<bool>(&Ident::new("d")).unwrap(),
+        &vec![true, false]
+    );
+    assert_eq!(
+        table.downcast_column::<i8>(&Ident::new("e")).unwrap(),
+        &vec![i8::MIN, i8::MAX]
+    );
+    assert_eq!(
+        table.downcast_column::<i16>(&Ident::new("f")).unwrap(),
+        &vec![5, 6]
+    );
+    assert_eq!(
+        table.downcast_column::<i32>(&Ident::new("g")).unwrap(),
+        &vec![7, 8]
+    );
+}
+#[test]
+fn we_cannot_downcast_missing_or_mistyped_owned_table_columns() {
+    let table = owned_table::<TestScalar>([bigint("a", [1, 2]), varchar("c", ["x", "y"])]);
+    assert_eq!(
+        table.downcast_column::<i64>(&Ident::new("missing")),
+        Err(OwnedTableError::ColumnNotFound {
+            column_name: "missing".to_string()
+        })
+    );
+    assert_eq!(
+        table.downcast_column::<i128>(&Ident::new("a")),
+        Err(OwnedTableError::TypeMismatch {
+            expected: ColumnType::Int128,
+            actual: ColumnType::BigInt,
+        })
+    );
+    assert_eq!(
+        table.downcast_column::<i64>(&Ident::new("c")),
+        Err(OwnedTableError::TypeMismatch {
+            expected: ColumnType::BigInt,
+            actual: ColumnType::VarChar,
+        })
+    );
+}
+#[test]
+fn we_can_create_binary_columns_with_empty_and_ragged_values() {
+    let digest = [0xAB_u8; 32];
+    let table = owned_table::<TestScalar>([binary("a", [&digest[..], &[], &[1, 2, 3]])]);
+    assert_eq!(
+        table,
+        owned_table([varbinary("a", [digest.to_vec(), vec![], vec![1, 2, 3]])])
+    );
+    assert_eq!(
+        table.inner_table()[&Ident::new("a")].column_type(),
+        ColumnType::VarBinary
+    );
+}
+#[test]
+fn we_can_detect_binary_columns_that_differ_in_a_single_row() {
+    let table_a = owned_table::<TestScalar>([
+        bigint("id", [1, 2, 3]),
+        binary("hash", [&[1_u8, 2][..], &[], &[3]]),
+    ]);
+    let table_b = owned_table::<TestScalar>([
+        bigint("id", [1, 2, 3]),
+        binary("hash", [&[1_u8, 2][..], &[0], &[3]]),
+    ]);
+    let table_c = owned_table::<TestScalar>([
+        bigint("id", [1, 2, 3]),
+        binary("hash", [&[1_u8, 2][..], &[], &[3, 0]]),
+    ]);
+    assert_ne!(table_a, table_b);
+    assert_ne!(table_a, table_c);
+    assert_eq!(table_a, table_a.clone());
+}
+#[test]
+fn we_can_mix_decimal_columns_with_different_scales_in_one_table() {
+    let table = owned_table::<TestScalar>([
+        decimal("price", 10, 2, [1_250, -1]),
+        decimal("rate", 38, -3, [7, i128::from(i64::MAX)]),
+        decimal("count", 5, 0, [3, 4]),
+    ]);
+    assert_eq!(
+        table
+            .inner_table()
+            .values()
+            .map(OwnedColumn::column_type)
+            .collect::<Vec<_>>(),
+        vec![
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+            ColumnType::Decimal75(Precision::new(38).unwrap(), -3),
+            ColumnType::Decimal75(Precision::new(5).unwrap(), 0),
+        ]
+    );
+    assert_eq!(
+        table.inner_table()[&Ident::new("price")],
+        OwnedColumn::Decimal75(
+            Precision::new(10).unwrap(),
+            2,
+            vec![TestScalar::from(1_250), -TestScalar::ONE]
+        )
+    );
+}
+#[test]
+#[should_panic(expected = "decimal precision must be between 1 and 38, got 0")]
+fn we_cannot_create_a_decimal_column_with_zero_precision() {
+    let _ = decimal::<TestScalar>("a", 0, 0, [1]);
+}
+#[test]
+#[should_panic(expected = "decimal precision must be between 1 and 38, got 39")]
+fn we_cannot_create_a_decimal_column_with_precision_beyond_i128() {
+    let _ = decimal::<TestScalar>("a", 39, 0, [1]);
+}
+#[test]
+#[should_panic(expected = "decimal scale 6 must not exceed precision 5")]
+fn we_cannot_create_a_decimal_column_with_scale_exceeding_precision() {
+    let _ = decimal::<TestScalar>("a", 5, 6, [1]);
+}
+#[test]
+fn we_can_create_a_decimal_column_with_values_at_the_precision() {
+    let _ = decimal::<TestScalar>("a", 3, 0, [999, -999, 0]);
+    let _ = decimal::<TestScalar>("a", 38, 0, [10_i128.pow(38) - 1, 1 - 10_i128.pow(38)]);
+}
+#[test]
+#[should_panic(expected = "decimal value -1000 has more than 3 digits")]
+fn we_cannot_create_a_decimal_column_with_values_beyond_the_precision() {
+    let _ = decimal::<TestScalar>("a", 3, 0, [999, -1000]);
+}
+#[test]
+#[should_panic(
+    expected = "decimal value 170141183460469231731687303715884105727 has more than 38 digits"
+)]
+fn we_cannot_create_a_decimal_column_with_i128_max_at_precision_38() {
+    let _ = decimal::<TestScalar>("a", 38, 0, [i128::MAX]);
+}
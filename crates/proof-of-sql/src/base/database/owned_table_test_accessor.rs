@@ -97,6 +97,8 @@ impl<CP: CommitmentEvaluationProof> DataAccessor<CP::Scalar> for OwnedTableTestA
             OwnedColumn::SmallInt(col) => Column::SmallInt(col),
             OwnedColumn::Int(col) => Column::Int(col),
             OwnedColumn::BigInt(col) => Column::BigInt(col),
+            OwnedColumn::UInt64(col) => Column::UInt64(col),
+            OwnedColumn::Date(col) => Column::Date(col),
             OwnedColumn::Int128(col) => Column::Int128(col),
             OwnedColumn::Decimal75(precision, scale, col) => {
                 Column::Decimal75(*precision, *scale, col)
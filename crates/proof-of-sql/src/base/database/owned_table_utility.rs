@@ -156,6 +156,49 @@ pub fn bigint<S: Scalar>(
     )
 }
 
+/// Creates a `(Ident, OwnedColumn)` pair for a uint64 column.
+/// This is primarily intended for use in conjunction with [`owned_table`].
+/// # Example
+/// ```rust
+/// use proof_of_sql::base::{database::owned_table_utility::*};
+/// # use proof_of_sql::base::scalar::MontScalar;
+/// # pub type MyScalar = MontScalar<ark_curve25519::FrConfig>;
+/// let result = owned_table::<MyScalar>([
+///     uint64("a", [1, 2, u64::MAX]),
+/// ]);
+/// ```
+pub fn uint64<S: Scalar>(
+    name: impl Into<Ident>,
+    data: impl IntoIterator<Item = impl Into<u64>>,
+) -> (Ident, OwnedColumn<S>) {
+    (
+        name.into(),
+        OwnedColumn::UInt64(data.into_iter().map(Into::into).collect()),
+    )
+}
+
+/// Creates a `(Ident, OwnedColumn)` pair for a date column.
+/// The values are days since the unix epoch, so `1970-01-01` is `0`.
+/// This is primarily intended for use in conjunction with [`owned_table`].
+/// # Example
+/// ```rust
+/// use proof_of_sql::base::{database::owned_table_utility::*};
+/// # use proof_of_sql::base::scalar::MontScalar;
+/// # pub type MyScalar = MontScalar<ark_curve25519::FrConfig>;
+/// let result = owned_table::<MyScalar>([
+///     date("a", [0, -1, 19_723]),
+/// ]);
+/// ```
+pub fn date<S: Scalar>(
+    name: impl Into<Ident>,
+    data: impl IntoIterator<Item = impl Into<i32>>,
+) -> (Ident, OwnedColumn<S>) {
+    (
+        name.into(),
+        OwnedColumn::Date(data.into_iter().map(Into::into).collect()),
+    )
+}
+
 /// Creates a `(Ident, OwnedColumn)` pair for a boolean column.
 /// This is primarily intended for use in conjunction with [`owned_table`].
 /// # Example
@@ -261,6 +304,28 @@ pub fn varbinary<S: Scalar>(
     )
 }
 
+/// Creates a `(Ident, OwnedColumn)` pair for a varbinary column from anything that can be viewed as bytes.
+/// Unlike [`varbinary`], this accepts borrowed data such as hash digests or byte slices of different lengths.
+/// This is primarily intended for use in conjunction with [`owned_table`].
+/// # Example
+/// ```
+/// use proof_of_sql::base::{database::owned_table_utility::*};
+/// # use proof_of_sql::base::scalar::MontScalar;
+/// # pub type MyScalar = MontScalar<ark_curve25519::FrConfig>;
+/// let result = owned_table::<MyScalar>([
+///    binary("a", [&[1, 2, 3][..], &[], &[4, 5]]),
+/// ]);
+/// ```
+pub fn binary<S: Scalar>(
+    name: impl Into<Ident>,
+    data: impl IntoIterator<Item = impl AsRef<[u8]>>,
+) -> (Ident, OwnedColumn<S>) {
+    (
+        name.into(),
+        OwnedColumn::VarBinary(data.into_iter().map(|b| b.as_ref().to_vec()).collect()),
+    )
+}
+
 /// Creates a `(Ident, OwnedColumn)` pair for a decimal75 column.
 /// This is primarily intended for use in conjunction with [`owned_table`].
 /// # Example
@@ -291,6 +356,47 @@ pub fn decimal75<S: Scalar>(
     )
 }
 
+/// Creates a `(Ident, OwnedColumn)` pair for a decimal column backed by `i128` values.
+/// The values are the unscaled integers, so `12.5` with a scale of `1` is `125`.
+/// This is primarily intended for use in conjunction with [`owned_table`].
+/// # Example
+/// ```
+/// use proof_of_sql::base::{database::owned_table_utility::*};
+/// # use proof_of_sql::base::scalar::MontScalar;
+/// # pub type MyScalar = MontScalar<ark_curve25519::FrConfig>;
+/// let result = owned_table::<MyScalar>([
+///     decimal("a", 19, 2, [125, -1, i128::from(i64::MAX)]),
+/// ]);
+/// ```
+///
+/// # Panics
+/// - Panics if `precision` is zero or greater than 38, the number of digits an `i128` can hold.
+/// - Panics if `scale` is greater than `precision`.
+/// - Panics if any value has more than `precision` digits.
+pub fn decimal<S: Scalar>(
+    name: impl Into<Ident>,
+    precision: u8,
+    scale: i8,
+    data: impl IntoIterator<Item = i128>,
+) -> (Ident, OwnedColumn<S>) {
+    assert!(
+        (1..=38).contains(&precision),
+        "decimal precision must be between 1 and 38, got {precision}"
+    );
+    assert!(
+        i16::from(scale) <= i16::from(precision),
+        "decimal scale {scale} must not exceed precision {precision}"
+    );
+    let data: Vec<i128> = data.into_iter().collect();
+    if let Some(value) = data
+        .iter()
+        .find(|value| value.unsigned_abs() >= 10_u128.pow(precision.into()))
+    {
+        panic!("decimal value {value} has more than {precision} digits");
+    }
+    decimal75(name, precision, scale, data)
+}
+
 /// Creates a `(Ident, OwnedColumn)` pair for a timestamp column.
 /// This is primarily intended for use in conjunction with [`owned_table`].
 ///
@@ -320,3 +426,79 @@ pub fn timestamptz<S: Scalar>(
         OwnedColumn::TimestampTZ(time_unit, timezone, data.into_iter().collect()),
     )
 }
+
+/// Creates a `(Ident, OwnedColumn)` pair for a timestamp column with values in seconds.
+/// This is shorthand for [`timestamptz`] with [`PoSQLTimeUnit::Second`].
+/// # Example
+/// ```
+/// use proof_of_sql::base::{database::owned_table_utility::*, posql_time::PoSQLTimeZone};
+/// # use proof_of_sql::base::scalar::MontScalar;
+/// # pub type MyScalar = MontScalar<ark_curve25519::FrConfig>;
+/// let result = owned_table::<MyScalar>([
+///     timestamptz_s("event_time", PoSQLTimeZone::utc(), vec![1625072400, 1625076000]),
+/// ]);
+/// ```
+pub fn timestamptz_s<S: Scalar>(
+    name: impl Into<Ident>,
+    timezone: PoSQLTimeZone,
+    data: impl IntoIterator<Item = i64>,
+) -> (Ident, OwnedColumn<S>) {
+    timestamptz(name, PoSQLTimeUnit::Second, timezone, data)
+}
+
+/// Creates a `(Ident, OwnedColumn)` pair for a timestamp column with values in milliseconds.
+/// This is shorthand for [`timestamptz`] with [`PoSQLTimeUnit::Millisecond`].
+/// # Example
+/// ```
+/// use proof_of_sql::base::{database::owned_table_utility::*, posql_time::PoSQLTimeZone};
+/// # use proof_of_sql::base::scalar::MontScalar;
+/// # pub type MyScalar = MontScalar<ark_curve25519::FrConfig>;
+/// let result = owned_table::<MyScalar>([
+///     timestamptz_ms("event_time", PoSQLTimeZone::utc(), vec![1625072400000, 1625076000000]),
+/// ]);
+/// ```
+pub fn timestamptz_ms<S: Scalar>(
+    name: impl Into<Ident>,
+    timezone: PoSQLTimeZone,
+    data: impl IntoIterator<Item = i64>,
+) -> (Ident, OwnedColumn<S>) {
+    timestamptz(name, PoSQLTimeUnit::Millisecond, timezone, data)
+}
+
+/// Creates a `(Ident, OwnedColumn)` pair for a timestamp column with values in microseconds.
+/// This is shorthand for [`timestamptz`] with [`PoSQLTimeUnit::Microsecond`].
+/// # Example
+/// ```
+/// use proof_of_sql::base::{database::owned_table_utility::*, posql_time::PoSQLTimeZone};
+/// # use proof_of_sql::base::scalar::MontScalar;
+/// # pub type MyScalar = MontScalar<ark_curve25519::FrConfig>;
+/// let result = owned_table::<MyScalar>([
+///     timestamptz_us("event_time", PoSQLTimeZone::utc(), vec![1625072400000000, 1625076000000000]),
+/// ]);
+/// ```
+pub fn timestamptz_us<S: Scalar>(
+    name: impl Into<Ident>,
+    timezone: PoSQLTimeZone,
+    data: impl IntoIterator<Item = i64>,
+) -> (Ident, OwnedColumn<S>) {
+    timestamptz(name, PoSQLTimeUnit::Microsecond, timezone, data)
+}
+
+/// Creates a `(Ident, OwnedColumn)` pair for a timestamp column with values in nanoseconds.
+/// This is shorthand for [`timestamptz`] with [`PoSQLTimeUnit::Nanosecond`].
+/// # Example
+/// ```
+/// use proof_of_sql::base::{database::owned_table_utility::*, posql_time::PoSQLTimeZone};
+/// # use proof_of_sql::base::scalar::MontScalar;
+/// # pub type MyScalar = MontScalar<ark_curve25519::FrConfig>;
+/// let result = owned_table::<MyScalar>([
+///     timestamptz_ns("event_time", PoSQLTimeZone::utc(), vec![1625072400000000000, 1625076000000000000]),
+/// ]);
+/// ```
+pub fn timestamptz_ns<S: Scalar>(
+    name: impl Into<Ident>,
+    timezone: PoSQLTimeZone,
+    data: impl IntoIterator<Item = i64>,
+) -> (Ident, OwnedColumn<S>) {
+    timestamptz(name, PoSQLTimeUnit::Nanosecond, timezone, data)
+}
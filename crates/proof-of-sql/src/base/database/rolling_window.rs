@@ -0,0 +1,136 @@
+//! Rolling window statistics over [`OwnedTable`] columns.
+use super::{
+    order_by_util::compare_indexes_by_owned_columns, OwnedColumn, OwnedTable, OwnedTableError,
+};
+use crate::base::{
+    math::decimal::{Precision, MAX_SUPPORTED_PRECISION},
+    scalar::Scalar,
+};
+use alloc::{vec, vec::Vec};
+use num_bigint::BigInt;
+use sqlparser::ast::Ident;
+
+/// The number of fractional digits in the results of [`WindowOp::Mean`] and [`WindowOp::StdDev`].
+pub const ROLLING_WINDOW_SCALE: i8 = 6;
+
+/// A statistic computed over each window by [`OwnedTable::rolling_window`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowOp {
+    /// The sum of the values in the window.
+    Sum,
+    /// The arithmetic mean of the values in the window.
+    Mean,
+    /// The smallest value in the window.
+    Min,
+    /// The largest value in the window.
+    Max,
+    /// The population standard deviation of the values in the window.
+    StdDev,
+}
+
+impl WindowOp {
+    /// Computes the statistic of a nonempty window, scaled by `10^scale` where `scale` is given by [`WindowOp::scale`].
+    fn apply(self, window: &[i128]) -> BigInt {
+        let count = BigInt::from(window.len());
+        let sum: BigInt = window.iter().copied().map(BigInt::from).sum();
+        let fractional = BigInt::from(10).pow(ROLLING_WINDOW_SCALE.unsigned_abs().into());
+        match self {
+            WindowOp::Sum => sum,
+            WindowOp::Mean => sum * fractional / count,
+            WindowOp::Min => window.iter().copied().min().unwrap_or_default().into(),
+            WindowOp::Max => window.iter().copied().max().unwrap_or_default().into(),
+            WindowOp::StdDev => {
+                let sum_of_squares: BigInt = window
+                    .iter()
+                    .map(|&value| BigInt::from(value) * value)
+                    .sum();
+                // n * stddev = sqrt(n * sum(x^2) - sum(x)^2)
+                let scaled_variance =
+                    (&count * sum_of_squares - &sum * &sum) * &fractional * &fractional;
+                scaled_variance.sqrt() / count
+            }
+        }
+    }
+
+    /// The scale of the decimal column produced by this statistic.
+    fn scale(self) -> i8 {
+        match self {
+            WindowOp::Sum | WindowOp::Min | WindowOp::Max => 0,
+            WindowOp::Mean | WindowOp::StdDev => ROLLING_WINDOW_SCALE,
+        }
+    }
+}
+
+impl<S: Scalar> OwnedTable<S> {
+    /// Computes `op` over a trailing window of `window` rows of `value_col`, with rows ordered by `order_col`.
+    ///
+    /// For each row, the window consists of the row itself and the up to `window - 1` rows that precede it
+    /// when the table is sorted by `order_col`, so the first few windows are shorter. Ties in `order_col` keep
+    /// their original order. The result has one entry per row, in the original row order, so it can be added
+    /// to the table as a new column.
+    ///
+    /// The result is a [`OwnedColumn::Decimal75`] with precision 75. Sums, minima and maxima are exact and have
+    /// scale 0. Means and standard deviations have scale [`ROLLING_WINDOW_SCALE`] and are truncated toward zero.
+    ///
+    /// This is a convenience for generating and inspecting data and is not backed by any proof.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::ColumnNotFound`] if either column is not in the table,
+    /// [`OwnedTableError::NonIntegerColumn`] if `value_col` is not an integer column and
+    /// [`OwnedTableError::InvalidWindowSize`] if `window` is zero.
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "The precision is supported and the statistics of integer windows always fit in a scalar"
+    )]
+    pub fn rolling_window(
+        &self,
+        order_col: &Ident,
+        value_col: &Ident,
+        window: usize,
+        op: WindowOp,
+    ) -> Result<OwnedColumn<S>, OwnedTableError> {
+        let column = |name: &Ident| {
+            self.inner_table()
+                .get(name)
+                .ok_or_else(|| OwnedTableError::ColumnNotFound {
+                    column_name: name.value.clone(),
+                })
+        };
+        let order_column = column(order_col)?;
+        let value_column = column(value_col)?;
+        let values: Vec<i128> = match value_column {
+            OwnedColumn::Uint8(col) => col.iter().copied().map(i128::from).collect(),
+            OwnedColumn::TinyInt(col) => col.iter().copied().map(i128::from).collect(),
+            OwnedColumn::SmallInt(col) => col.iter().copied().map(i128::from).collect(),
+            OwnedColumn::Int(col) => col.iter().copied().map(i128::from).collect(),
+            OwnedColumn::BigInt(col) => col.iter().copied().map(i128::from).collect(),
+            OwnedColumn::UInt64(col) => col.iter().copied().map(i128::from).collect(),
+            OwnedColumn::Int128(col) => col.clone(),
+            _ => {
+                return Err(OwnedTableError::NonIntegerColumn {
+                    column_name: value_col.value.clone(),
+                    column_type: value_column.column_type(),
+                })
+            }
+        };
+        if window == 0 {
+            return Err(OwnedTableError::InvalidWindowSize);
+        }
+
+        let mut order: Vec<usize> = (0..values.len()).collect();
+        order.sort_by(|&i, &j| compare_indexes_by_owned_columns(&[order_column], i, j));
+        let sorted_values: Vec<i128> = order.iter().map(|&i| values[i]).collect();
+
+        let mut result = vec![S::ZERO; values.len()];
+        for (position, &row) in order.iter().enumerate() {
+            let start = (position + 1).saturating_sub(window);
+            result[row] = S::try_from(op.apply(&sorted_values[start..=position]))
+                .expect("statistics of integer windows fit in a scalar");
+        }
+        Ok(OwnedColumn::Decimal75(
+            Precision::new(MAX_SUPPORTED_PRECISION).expect("the maximum precision is supported"),
+            op.scale(),
+            result,
+        ))
+    }
+}
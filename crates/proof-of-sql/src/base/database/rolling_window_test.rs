@@ -0,0 +1,136 @@
+use crate::base::{
+    database::{
+        owned_table_utility::*, ColumnType, OwnedColumn, OwnedTable, OwnedTableError, WindowOp,
+        ROLLING_WINDOW_SCALE,
+    },
+    scalar::test_scalar::TestScalar,
+};
+use sqlparser::ast::Ident;
+
+fn time_series() -> OwnedTable<TestScalar> {
+    owned_table([
+        bigint("time", [3, 1, 2, 4]),
+        int("value", [30, 10, 20, 40]),
+        varchar("label", ["c", "a", "b", "d"]),
+    ])
+}
+
+fn rolling(
+    table: &OwnedTable<TestScalar>,
+    window: usize,
+    op: WindowOp,
+) -> Result<OwnedColumn<TestScalar>, OwnedTableError> {
+    table.rolling_window(&Ident::new("time"), &Ident::new("value"), window, op)
+}
+
+#[test]
+fn we_can_compute_rolling_sums_min_and_max_in_original_row_order() {
+    let table = time_series();
+    assert_eq!(
+        rolling(&table, 2, WindowOp::Sum).unwrap(),
+        decimal75("", 75, 0, [50, 10, 30, 70]).1
+    );
+    assert_eq!(
+        rolling(&table, 2, WindowOp::Min).unwrap(),
+        decimal75("", 75, 0, [20, 10, 10, 30]).1
+    );
+    assert_eq!(
+        rolling(&table, 2, WindowOp::Max).unwrap(),
+        decimal75("", 75, 0, [30, 10, 20, 40]).1
+    );
+}
+
+#[test]
+fn we_can_compute_rolling_means_and_standard_deviations() {
+    let table = time_series();
+    assert_eq!(
+        rolling(&table, 2, WindowOp::Mean).unwrap(),
+        decimal75(
+            "",
+            75,
+            ROLLING_WINDOW_SCALE,
+            [25_000_000, 10_000_000, 15_000_000, 35_000_000]
+        )
+        .1
+    );
+    assert_eq!(
+        rolling(&table, 2, WindowOp::StdDev).unwrap(),
+        decimal75(
+            "",
+            75,
+            ROLLING_WINDOW_SCALE,
+            [5_000_000, 0, 5_000_000, 5_000_000]
+        )
+        .1
+    );
+}
+
+#[test]
+fn we_can_compute_a_rolling_standard_deviation_over_a_full_window() {
+    let table = owned_table::<TestScalar>([
+        smallint("time", [0_i16, 1, 2, 3, 4, 5, 6, 7]),
+        bigint("value", [2, 4, 4, 4, 5, 5, 7, 9]),
+    ]);
+    let column = rolling(&table, 8, WindowOp::StdDev).unwrap();
+    assert_eq!(
+        column,
+        decimal75(
+            "",
+            75,
+            ROLLING_WINDOW_SCALE,
+            [0, 1_000_000, 942_809, 866_025, 979_795, 1_000_000, 1_399_708, 2_000_000]
+        )
+        .1
+    );
+}
+
+#[test]
+fn we_can_compute_rolling_means_of_negative_values_truncated_toward_zero() {
+    let table =
+        owned_table::<TestScalar>([bigint("time", [1, 2, 3]), int128("value", [-1, -2, 2])]);
+    assert_eq!(
+        rolling(&table, 2, WindowOp::Mean).unwrap(),
+        decimal75("", 75, ROLLING_WINDOW_SCALE, [-1_000_000, -1_500_000, 0]).1
+    );
+}
+
+#[test]
+fn we_can_compute_a_rolling_window_larger_than_the_table_or_over_no_rows() {
+    let table = time_series();
+    assert_eq!(
+        rolling(&table, 10, WindowOp::Sum).unwrap(),
+        decimal75("", 75, 0, [60, 10, 30, 100]).1
+    );
+    let empty = owned_table::<TestScalar>([bigint("time", [0; 0]), int("value", [0; 0])]);
+    assert_eq!(
+        rolling(&empty, 3, WindowOp::Max).unwrap(),
+        decimal75("", 75, 0, [0; 0]).1
+    );
+}
+
+#[test]
+fn we_cannot_compute_a_rolling_window_with_invalid_arguments() {
+    let table = time_series();
+    assert_eq!(
+        rolling(&table, 0, WindowOp::Sum),
+        Err(OwnedTableError::InvalidWindowSize)
+    );
+    assert_eq!(
+        table.rolling_window(
+            &Ident::new("missing"),
+            &Ident::new("value"),
+            2,
+            WindowOp::Sum
+        ),
+        Err(OwnedTableError::ColumnNotFound {
+            column_name: "missing".to_string()
+        })
+    );
+    assert_eq!(
+        table.rolling_window(&Ident::new("time"), &Ident::new("label"), 2, WindowOp::Sum),
+        Err(OwnedTableError::NonIntegerColumn {
+            column_name: "label".to_string(),
+            column_type: ColumnType::VarChar
+        })
+    );
+}
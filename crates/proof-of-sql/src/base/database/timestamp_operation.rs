@@ -0,0 +1,575 @@
+use super::{
+    slice_operation::{try_add, try_mul, try_sub},
+    ColumnOperationError, ColumnOperationResult, OwnedColumn,
+};
+use crate::base::{
+    posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
+    scalar::Scalar,
+};
+use alloc::{string::ToString, vec::Vec};
+use chrono::{DateTime, Datelike, NaiveDateTime, Timelike};
+
+/// A component of a date and time that can be extracted from a timestamp, as in SQL's `EXTRACT`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateComponent {
+    /// The year
+    Year,
+    /// The month, from 1 to 12
+    Month,
+    /// The day of the month, from 1 to 31
+    Day,
+    /// The hour, from 0 to 23
+    Hour,
+    /// The minute, from 0 to 59
+    Minute,
+    /// The whole second, from 0 to 59
+    Second,
+    /// The day of the week, from 0 (Sunday) to 6 (Saturday)
+    DayOfWeek,
+    /// The day of the year, from 1 to 366
+    DayOfYear,
+}
+
+impl DateComponent {
+    #[expect(clippy::cast_possible_wrap)]
+    fn of(self, datetime: &NaiveDateTime) -> i32 {
+        match self {
+            DateComponent::Year => datetime.year(),
+            DateComponent::Month => datetime.month() as i32,
+            DateComponent::Day => datetime.day() as i32,
+            DateComponent::Hour => datetime.hour() as i32,
+            DateComponent::Minute => datetime.minute() as i32,
+            DateComponent::Second => datetime.second() as i32,
+            DateComponent::DayOfWeek => datetime.weekday().num_days_from_sunday() as i32,
+            DateComponent::DayOfYear => datetime.ordinal() as i32,
+        }
+    }
+}
+
+/// Converts a timestamp to the local date and time in the timezone `tz`.
+fn to_local_datetime(
+    unit: PoSQLTimeUnit,
+    tz: PoSQLTimeZone,
+    timestamp: i64,
+) -> ColumnOperationResult<NaiveDateTime> {
+    let units_per_second = unit.units_per_second();
+    let seconds = timestamp.div_euclid(units_per_second);
+    let nanos = timestamp.rem_euclid(units_per_second) * (1_000_000_000 / units_per_second);
+    seconds
+        .checked_add(i64::from(tz.offset()))
+        .zip(u32::try_from(nanos).ok())
+        .and_then(|(seconds, nanos)| DateTime::from_timestamp(seconds, nanos))
+        .map(|datetime| datetime.naive_utc())
+        .ok_or(ColumnOperationError::TimestampOutOfRange { timestamp })
+}
+
+impl<S: Scalar> OwnedColumn<S> {
+    /// Adds `duration` to every timestamp in a `TimestampTZ` column.
+    ///
+    /// The duration is in the column's own time unit and may be negative to subtract it.
+    /// The result keeps the time unit and timezone of the column.
+    ///
+    /// # Errors
+    /// Returns [`ColumnOperationError::IntegerOverflow`] if any timestamp overflows and
+    /// [`ColumnOperationError::UnaryOperationInvalidColumnType`] if the column is not a `TimestampTZ` column.
+    pub fn add_duration(&self, duration: i64) -> ColumnOperationResult<Self> {
+        match self {
+            Self::TimestampTZ(tu, tz, col) => Ok(Self::TimestampTZ(
+                *tu,
+                *tz,
+                col.iter()
+                    .map(|timestamp| try_add(timestamp, &duration))
+                    .collect::<ColumnOperationResult<Vec<_>>>()?,
+            )),
+            _ => Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+                operator: "ADD DURATION".to_string(),
+                operand_type: self.column_type(),
+            }),
+        }
+    }
+
+    /// Extracts `component` from every timestamp in a `TimestampTZ` column, as in SQL's `EXTRACT`.
+    ///
+    /// The column's timezone offset is applied first, so the components are those of the local time.
+    /// The result is an `Int` column.
+    ///
+    /// # Errors
+    /// Returns [`ColumnOperationError::TimestampOutOfRange`] if a timestamp is outside the range of supported dates and
+    /// [`ColumnOperationError::UnaryOperationInvalidColumnType`] if the column is not a `TimestampTZ` column.
+    pub fn extract_component(&self, component: DateComponent) -> ColumnOperationResult<Self> {
+        match self {
+            Self::TimestampTZ(tu, tz, col) => Ok(Self::Int(
+                col.iter()
+                    .map(|timestamp| {
+                        to_local_datetime(*tu, *tz, *timestamp)
+                            .map(|datetime| component.of(&datetime))
+                    })
+                    .collect::<ColumnOperationResult<Vec<_>>>()?,
+            )),
+            _ => Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+                operator: "EXTRACT".to_string(),
+                operand_type: self.column_type(),
+            }),
+        }
+    }
+
+    /// Truncates every timestamp in a `TimestampTZ` column down to the start of its `granularity`, as in SQL's
+    /// `DATE_TRUNC`.
+    ///
+    /// Only [`DateComponent::Second`], [`DateComponent::Minute`], [`DateComponent::Hour`] and [`DateComponent::Day`]
+    /// are supported. The column's timezone offset is applied first, so e.g. days start at local midnight.
+    /// Timestamps are rounded towards negative infinity, so timestamps before the epoch also round down.
+    /// The result keeps the time unit and timezone of the column.
+    ///
+    /// # Errors
+    /// Returns [`ColumnOperationError::UnsupportedDateTruncation`] for any other granularity,
+    /// [`ColumnOperationError::IntegerOverflow`] if rounding a timestamp down overflows and
+    /// [`ColumnOperationError::UnaryOperationInvalidColumnType`] if the column is not a `TimestampTZ` column.
+    pub fn date_trunc(&self, granularity: DateComponent) -> ColumnOperationResult<Self> {
+        match self {
+            Self::TimestampTZ(tu, tz, col) => {
+                let seconds = match granularity {
+                    DateComponent::Second => 1,
+                    DateComponent::Minute => 60,
+                    DateComponent::Hour => 3_600,
+                    DateComponent::Day => 86_400,
+                    _ => {
+                        return Err(ColumnOperationError::UnsupportedDateTruncation { granularity })
+                    }
+                };
+                let factor = seconds * tu.units_per_second();
+                let offset = (i64::from(tz.offset()) * tu.units_per_second()).rem_euclid(factor);
+                Ok(Self::TimestampTZ(
+                    *tu,
+                    *tz,
+                    col.iter()
+                        .map(|timestamp| {
+                            try_sub(
+                                timestamp,
+                                &((timestamp.rem_euclid(factor) + offset) % factor),
+                            )
+                        })
+                        .collect::<ColumnOperationResult<Vec<_>>>()?,
+                ))
+            }
+            _ => Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+                operator: "DATE_TRUNC".to_string(),
+                operand_type: self.column_type(),
+            }),
+        }
+    }
+
+    /// Subtracts each timestamp in `rhs` from the one in `self`, for two `TimestampTZ` columns.
+    ///
+    /// The result is a `BigInt` column of differences in the finer of the two columns' time units.
+    /// Timestamps are instants, so the timezones of the columns do not affect the differences.
+    ///
+    /// # Errors
+    /// Returns [`ColumnOperationError::DifferentColumnLength`] if the columns have different lengths,
+    /// [`ColumnOperationError::IntegerOverflow`] if a difference overflows, and
+    /// [`ColumnOperationError::BinaryOperationInvalidColumnType`] if either column is not a `TimestampTZ` column.
+    pub fn date_diff(&self, rhs: &Self) -> ColumnOperationResult<Self> {
+        if self.len() != rhs.len() {
+            return Err(ColumnOperationError::DifferentColumnLength {
+                len_a: self.len(),
+                len_b: rhs.len(),
+            });
+        }
+        match (self, rhs) {
+            (Self::TimestampTZ(lhs_tu, _, lhs), Self::TimestampTZ(rhs_tu, _, rhs)) => {
+                let unit = (*lhs_tu).max(*rhs_tu);
+                let lhs_factor = unit.units_per_second() / lhs_tu.units_per_second();
+                let rhs_factor = unit.units_per_second() / rhs_tu.units_per_second();
+                Ok(Self::BigInt(
+                    lhs.iter()
+                        .zip(rhs)
+                        .map(|(l, r)| try_sub(&try_mul(l, &lhs_factor)?, &try_mul(r, &rhs_factor)?))
+                        .collect::<ColumnOperationResult<Vec<_>>>()?,
+                ))
+            }
+            _ => Err(ColumnOperationError::BinaryOperationInvalidColumnType {
+                operator: "DATE_DIFF".to_string(),
+                left_type: self.column_type(),
+                right_type: rhs.column_type(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{database::ColumnType, scalar::test_scalar::TestScalar};
+
+    const ONE_DAY_IN_SECONDS: i64 = 86_400;
+
+    fn timestamps(unit: PoSQLTimeUnit, values: Vec<i64>) -> OwnedColumn<TestScalar> {
+        OwnedColumn::TimestampTZ(unit, PoSQLTimeZone::new(19800), values)
+    }
+
+    #[test]
+    fn we_can_add_a_duration_to_a_timestamp_column() {
+        let column = timestamps(PoSQLTimeUnit::Second, vec![-1, 0, 1_700_000_000]);
+        assert_eq!(column.add_duration(0).unwrap(), column);
+        assert_eq!(
+            column.add_duration(ONE_DAY_IN_SECONDS).unwrap(),
+            timestamps(PoSQLTimeUnit::Second, vec![86_399, 86_400, 1_700_086_400])
+        );
+        assert_eq!(
+            column.add_duration(-ONE_DAY_IN_SECONDS).unwrap(),
+            timestamps(PoSQLTimeUnit::Second, vec![-86_401, -86_400, 1_699_913_600])
+        );
+
+        let column = timestamps(PoSQLTimeUnit::Millisecond, vec![1_000]);
+        assert_eq!(
+            column.add_duration(500).unwrap(),
+            timestamps(PoSQLTimeUnit::Millisecond, vec![1_500])
+        );
+    }
+
+    #[test]
+    fn we_can_add_a_duration_to_an_empty_timestamp_column() {
+        let column = timestamps(PoSQLTimeUnit::Nanosecond, vec![]);
+        assert_eq!(column.add_duration(ONE_DAY_IN_SECONDS).unwrap(), column);
+    }
+
+    #[test]
+    fn adding_a_duration_that_overflows_fails() {
+        let column = timestamps(PoSQLTimeUnit::Second, vec![0, i64::MAX]);
+        assert!(matches!(
+            column.add_duration(1),
+            Err(ColumnOperationError::IntegerOverflow { .. })
+        ));
+        let column = timestamps(PoSQLTimeUnit::Second, vec![i64::MIN]);
+        assert!(matches!(
+            column.add_duration(-1),
+            Err(ColumnOperationError::IntegerOverflow { .. })
+        ));
+    }
+
+    #[test]
+    fn we_cannot_add_a_duration_to_a_non_timestamp_column() {
+        let column = OwnedColumn::<TestScalar>::BigInt(vec![1, 2]);
+        assert_eq!(
+            column.add_duration(1),
+            Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+                operator: "ADD DURATION".to_string(),
+                operand_type: ColumnType::BigInt,
+            })
+        );
+    }
+
+    fn extract_all(column: &OwnedColumn<TestScalar>) -> Vec<OwnedColumn<TestScalar>> {
+        [
+            DateComponent::Year,
+            DateComponent::Month,
+            DateComponent::Day,
+            DateComponent::Hour,
+            DateComponent::Minute,
+            DateComponent::Second,
+            DateComponent::DayOfWeek,
+            DateComponent::DayOfYear,
+        ]
+        .into_iter()
+        .map(|component| column.extract_component(component).unwrap())
+        .collect()
+    }
+
+    #[test]
+    fn we_can_extract_components_from_the_epoch() {
+        let column = OwnedColumn::<TestScalar>::TimestampTZ(
+            PoSQLTimeUnit::Second,
+            PoSQLTimeZone::utc(),
+            vec![0],
+        );
+        // 1970-01-01 was a Thursday
+        assert_eq!(
+            extract_all(&column),
+            [1970, 1, 1, 0, 0, 0, 4, 1].map(|value| OwnedColumn::Int(vec![value]))
+        );
+    }
+
+    #[test]
+    fn we_can_extract_components_across_a_month_boundary() {
+        // 1970-01-31 23:59:59 and 1970-02-01 00:00:00
+        let column = OwnedColumn::<TestScalar>::TimestampTZ(
+            PoSQLTimeUnit::Millisecond,
+            PoSQLTimeZone::utc(),
+            vec![2_678_399_000, 2_678_400_000],
+        );
+        assert_eq!(
+            extract_all(&column),
+            [
+                [1970, 1970],
+                [1, 2],
+                [31, 1],
+                [23, 0],
+                [59, 0],
+                [59, 0],
+                [6, 0],
+                [31, 32]
+            ]
+            .map(|values| OwnedColumn::Int(values.to_vec()))
+        );
+    }
+
+    #[test]
+    fn we_can_extract_components_in_a_timezone() {
+        // The epoch is 1970-01-01 05:30 in +05:30 and 1969-12-31 23:00 in -01:00
+        assert_eq!(
+            extract_all(&timestamps(PoSQLTimeUnit::Second, vec![0])),
+            [1970, 1, 1, 5, 30, 0, 4, 1].map(|value| OwnedColumn::Int(vec![value]))
+        );
+        let column = OwnedColumn::<TestScalar>::TimestampTZ(
+            PoSQLTimeUnit::Second,
+            PoSQLTimeZone::new(-3600),
+            vec![0],
+        );
+        assert_eq!(
+            extract_all(&column),
+            [1969, 12, 31, 23, 0, 0, 3, 365].map(|value| OwnedColumn::Int(vec![value]))
+        );
+    }
+
+    #[test]
+    fn we_can_extract_components_from_timestamps_before_the_epoch() {
+        // One unit before the epoch is 1969-12-31 23:59:59 in every unit
+        for unit in [
+            PoSQLTimeUnit::Second,
+            PoSQLTimeUnit::Millisecond,
+            PoSQLTimeUnit::Microsecond,
+            PoSQLTimeUnit::Nanosecond,
+        ] {
+            let column =
+                OwnedColumn::<TestScalar>::TimestampTZ(unit, PoSQLTimeZone::utc(), vec![-1]);
+            assert_eq!(
+                extract_all(&column),
+                [1969, 12, 31, 23, 59, 59, 3, 365].map(|value| OwnedColumn::Int(vec![value]))
+            );
+        }
+    }
+
+    #[test]
+    fn we_cannot_extract_components_from_out_of_range_or_non_timestamp_columns() {
+        assert_eq!(
+            timestamps(PoSQLTimeUnit::Second, vec![0, i64::MAX])
+                .extract_component(DateComponent::Year),
+            Err(ColumnOperationError::TimestampOutOfRange {
+                timestamp: i64::MAX
+            })
+        );
+        assert_eq!(
+            OwnedColumn::<TestScalar>::Int(vec![1]).extract_component(DateComponent::Year),
+            Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+                operator: "EXTRACT".to_string(),
+                operand_type: ColumnType::Int,
+            })
+        );
+    }
+
+    #[test]
+    fn we_can_truncate_timestamps_to_a_second() {
+        // 2024-06-20 12:34:56.123456789 in +05:30
+        let column = timestamps(
+            PoSQLTimeUnit::Nanosecond,
+            vec![1_718_867_096_123_456_789, 0],
+        );
+        assert_eq!(
+            column.date_trunc(DateComponent::Second).unwrap(),
+            timestamps(
+                PoSQLTimeUnit::Nanosecond,
+                vec![1_718_867_096_000_000_000, 0]
+            )
+        );
+        let column = timestamps(PoSQLTimeUnit::Millisecond, vec![999, 1_000, 1_001]);
+        assert_eq!(
+            column.date_trunc(DateComponent::Second).unwrap(),
+            timestamps(PoSQLTimeUnit::Millisecond, vec![0, 1_000, 1_000])
+        );
+    }
+
+    #[test]
+    fn we_can_truncate_timestamps_to_a_minute() {
+        // 2024-06-20 12:34:56.123 and 12:35:00 in +05:30
+        let column = timestamps(
+            PoSQLTimeUnit::Millisecond,
+            vec![1_718_867_096_123, 1_718_867_100_000],
+        );
+        assert_eq!(
+            column.date_trunc(DateComponent::Minute).unwrap(),
+            timestamps(
+                PoSQLTimeUnit::Millisecond,
+                vec![1_718_867_040_000, 1_718_867_100_000]
+            )
+        );
+    }
+
+    #[test]
+    fn we_can_truncate_timestamps_to_a_local_hour_and_day() {
+        // 2024-06-20 12:34:56 in +05:30, whose hour starts at 12:00 and day at 00:00 local time
+        let column = timestamps(PoSQLTimeUnit::Second, vec![1_718_867_096]);
+        assert_eq!(
+            column.date_trunc(DateComponent::Hour).unwrap(),
+            timestamps(PoSQLTimeUnit::Second, vec![1_718_865_000])
+        );
+        assert_eq!(
+            column.date_trunc(DateComponent::Day).unwrap(),
+            timestamps(PoSQLTimeUnit::Second, vec![1_718_821_800])
+        );
+        let column = OwnedColumn::<TestScalar>::TimestampTZ(
+            PoSQLTimeUnit::Second,
+            PoSQLTimeZone::utc(),
+            vec![1_718_867_096],
+        );
+        assert_eq!(
+            column.date_trunc(DateComponent::Day).unwrap(),
+            OwnedColumn::TimestampTZ(
+                PoSQLTimeUnit::Second,
+                PoSQLTimeZone::utc(),
+                vec![1_718_841_600]
+            )
+        );
+    }
+
+    #[test]
+    fn we_can_truncate_the_epoch() {
+        // The epoch is 1970-01-01 05:30 in +05:30
+        let column = timestamps(PoSQLTimeUnit::Nanosecond, vec![0]);
+        for (granularity, expected) in [
+            (DateComponent::Second, 0),
+            (DateComponent::Minute, 0),
+            (DateComponent::Hour, -1_800_000_000_000),
+            (DateComponent::Day, -19_800_000_000_000),
+        ] {
+            assert_eq!(
+                column.date_trunc(granularity).unwrap(),
+                timestamps(PoSQLTimeUnit::Nanosecond, vec![expected])
+            );
+        }
+    }
+
+    #[test]
+    fn truncating_timestamps_before_the_epoch_rounds_down() {
+        let column = timestamps(PoSQLTimeUnit::Microsecond, vec![-1, -1_000_000, -1_000_001]);
+        assert_eq!(
+            column.date_trunc(DateComponent::Second).unwrap(),
+            timestamps(
+                PoSQLTimeUnit::Microsecond,
+                vec![-1_000_000, -1_000_000, -2_000_000]
+            )
+        );
+        assert_eq!(
+            column.date_trunc(DateComponent::Minute).unwrap(),
+            timestamps(
+                PoSQLTimeUnit::Microsecond,
+                vec![-60_000_000, -60_000_000, -60_000_000]
+            )
+        );
+    }
+
+    #[test]
+    fn truncating_timestamps_to_their_own_unit_is_a_no_op() {
+        let column = timestamps(PoSQLTimeUnit::Second, vec![-1, 0, 1_234]);
+        assert_eq!(column.date_trunc(DateComponent::Second).unwrap(), column);
+        assert_eq!(
+            timestamps(PoSQLTimeUnit::Second, vec![])
+                .date_trunc(DateComponent::Day)
+                .unwrap(),
+            timestamps(PoSQLTimeUnit::Second, vec![])
+        );
+    }
+
+    #[test]
+    fn we_cannot_truncate_to_unsupported_granularities() {
+        let column = timestamps(PoSQLTimeUnit::Second, vec![0]);
+        for granularity in [
+            DateComponent::Year,
+            DateComponent::Month,
+            DateComponent::DayOfWeek,
+            DateComponent::DayOfYear,
+        ] {
+            assert_eq!(
+                column.date_trunc(granularity),
+                Err(ColumnOperationError::UnsupportedDateTruncation { granularity })
+            );
+        }
+    }
+
+    #[test]
+    fn we_cannot_truncate_overflowing_or_non_timestamp_columns() {
+        assert!(matches!(
+            timestamps(PoSQLTimeUnit::Nanosecond, vec![i64::MIN]).date_trunc(DateComponent::Second),
+            Err(ColumnOperationError::IntegerOverflow { .. })
+        ));
+        assert_eq!(
+            OwnedColumn::<TestScalar>::BigInt(vec![1]).date_trunc(DateComponent::Second),
+            Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+                operator: "DATE_TRUNC".to_string(),
+                operand_type: ColumnType::BigInt,
+            })
+        );
+    }
+
+    #[test]
+    fn we_can_diff_timestamp_columns() {
+        let lhs = timestamps(PoSQLTimeUnit::Second, vec![-5, 0, 10, 100]);
+        assert_eq!(
+            lhs.date_diff(&lhs).unwrap(),
+            OwnedColumn::BigInt(vec![0, 0, 0, 0])
+        );
+        let rhs = timestamps(PoSQLTimeUnit::Second, vec![-10, -10, 0, 0]);
+        assert_eq!(
+            lhs.date_diff(&rhs).unwrap(),
+            OwnedColumn::BigInt(vec![5, 10, 10, 100])
+        );
+        assert_eq!(
+            rhs.date_diff(&lhs).unwrap(),
+            OwnedColumn::BigInt(vec![-5, -10, -10, -100])
+        );
+    }
+
+    #[test]
+    fn we_can_diff_timestamp_columns_with_different_units_and_timezones() {
+        let lhs = timestamps(PoSQLTimeUnit::Second, vec![1, 2]);
+        let rhs = OwnedColumn::TimestampTZ(
+            PoSQLTimeUnit::Millisecond,
+            PoSQLTimeZone::new(-25200),
+            vec![1_000, 500],
+        );
+        assert_eq!(
+            lhs.date_diff(&rhs).unwrap(),
+            OwnedColumn::BigInt(vec![0, 1_500])
+        );
+        assert_eq!(
+            rhs.date_diff(&lhs).unwrap(),
+            OwnedColumn::BigInt(vec![0, -1_500])
+        );
+    }
+
+    #[test]
+    fn we_cannot_diff_mismatched_or_overflowing_timestamp_columns() {
+        let lhs = timestamps(PoSQLTimeUnit::Second, vec![1, 2]);
+        assert_eq!(
+            lhs.date_diff(&timestamps(PoSQLTimeUnit::Second, vec![1])),
+            Err(ColumnOperationError::DifferentColumnLength { len_a: 2, len_b: 1 })
+        );
+        assert_eq!(
+            lhs.date_diff(&OwnedColumn::BigInt(vec![1, 2])),
+            Err(ColumnOperationError::BinaryOperationInvalidColumnType {
+                operator: "DATE_DIFF".to_string(),
+                left_type: lhs.column_type(),
+                right_type: ColumnType::BigInt,
+            })
+        );
+        assert!(matches!(
+            timestamps(PoSQLTimeUnit::Second, vec![i64::MAX])
+                .date_diff(&timestamps(PoSQLTimeUnit::Nanosecond, vec![0])),
+            Err(ColumnOperationError::IntegerOverflow { .. })
+        ));
+        assert!(matches!(
+            timestamps(PoSQLTimeUnit::Second, vec![i64::MIN])
+                .date_diff(&timestamps(PoSQLTimeUnit::Second, vec![1])),
+            Err(ColumnOperationError::IntegerOverflow { .. })
+        ));
+    }
+}
@@ -103,6 +103,26 @@ pub fn column_union<'a, S: Scalar>(
                 iter.next().expect("Iterator should have enough elements")
             }) as &[_])
         }
+        ColumnType::UInt64 => {
+            let mut iter = columns
+                .iter()
+                .flat_map(|col| col.as_uint64().expect("Column types should match"))
+                .copied();
+
+            Column::UInt64(alloc.alloc_slice_fill_with(len, |_| {
+                iter.next().expect("Iterator should have enough elements")
+            }) as &[_])
+        }
+        ColumnType::Date => {
+            let mut iter = columns
+                .iter()
+                .flat_map(|col| col.as_date().expect("Column types should match"))
+                .copied();
+
+            Column::Date(alloc.alloc_slice_fill_with(len, |_| {
+                iter.next().expect("Iterator should have enough elements")
+            }) as &[_])
+        }
         ColumnType::Int128 => {
             let mut iter = columns
                 .iter()
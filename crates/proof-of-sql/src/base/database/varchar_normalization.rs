@@ -0,0 +1,63 @@
+use alloc::string::String;
+use unicode_normalization::UnicodeNormalization;
+
+/// A Unicode normalization form, see <https://unicode.org/reports/tr15/>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NormalizationForm {
+    /// Canonical decomposition followed by canonical composition
+    Nfc,
+    /// Canonical decomposition
+    Nfd,
+    /// Compatibility decomposition followed by canonical composition
+    Nfkc,
+    /// Compatibility decomposition
+    Nfkd,
+}
+
+/// Normalizes `s` to the Unicode normalization `form`.
+///
+/// Strings are hashed to scalars byte by byte, so two strings only compare equal in a proof if they
+/// have the same bytes. Normalizing maps Unicode-equivalent strings, such as `"é"` as a single code point
+/// and `"e"` followed by a combining acute accent, to the same bytes.
+///
+/// `VarChar` columns and literals are not normalized automatically. To compare Unicode-equivalent strings,
+/// normalize the committed data and the query literals to the same form.
+#[must_use]
+pub fn normalize_varchar(s: &str, form: NormalizationForm) -> String {
+    match form {
+        NormalizationForm::Nfc => s.nfc().collect(),
+        NormalizationForm::Nfd => s.nfd().collect(),
+        NormalizationForm::Nfkc => s.nfkc().collect(),
+        NormalizationForm::Nfkd => s.nfkd().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPOSED: &str = "caf\u{e9}";
+    const DECOMPOSED: &str = "cafe\u{301}";
+
+    #[test]
+    fn we_can_normalize_varchars_to_every_form() {
+        assert_eq!(
+            normalize_varchar(DECOMPOSED, NormalizationForm::Nfc),
+            COMPOSED
+        );
+        assert_eq!(
+            normalize_varchar(COMPOSED, NormalizationForm::Nfd),
+            DECOMPOSED
+        );
+        // The ligature "ﬁ" is only compatibility equivalent to "fi"
+        assert_eq!(
+            normalize_varchar("\u{fb01}", NormalizationForm::Nfc),
+            "\u{fb01}"
+        );
+        assert_eq!(normalize_varchar("\u{fb01}", NormalizationForm::Nfkc), "fi");
+        assert_eq!(
+            normalize_varchar("\u{fb01}\u{e9}", NormalizationForm::Nfkd),
+            "fie\u{301}"
+        );
+    }
+}
@@ -407,8 +407,8 @@ fn we_can_encode_and_decode_i128_and_test_scalar_the_same() {
     test_encode_and_decode_types_align::<i128, TestScalar>(
         &rng.gen::<[_; 32]>(),
         &[
-            TestScalar::from(i128::MAX) + TestScalar::one(),
-            TestScalar::from(i128::MIN) - TestScalar::one(),
+            TestScalar::from(i128::MAX) + TestScalar::ONE,
+            TestScalar::from(i128::MIN) - TestScalar::ONE,
             TestScalar::from(i128::MAX) * TestScalar::from(1000),
             TestScalar::from(i128::MIN) * TestScalar::from(1000),
         ],
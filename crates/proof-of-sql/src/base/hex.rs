@@ -0,0 +1,51 @@
+use alloc::{string::String, vec::Vec};
+
+/// Encodes `bytes` as lowercase hex digits, two per byte, in order.
+pub(crate) fn encode_hex(bytes: &[u8]) -> String {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+    bytes
+        .iter()
+        .flat_map(|byte| [byte >> 4, byte & 0x0F])
+        .map(|nibble| char::from(HEX_DIGITS[usize::from(nibble)]))
+        .collect()
+}
+
+/// Decodes the output of [`encode_hex`]. Uppercase hex digits are also accepted.
+///
+/// Returns `None` if `s` has an odd length or contains a character that is not a hex digit.
+pub(crate) fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return None;
+    }
+    s.as_bytes()
+        .chunks_exact(2)
+        .map(|pair| {
+            let high = char::from(pair[0]).to_digit(16)?;
+            let low = char::from(pair[1]).to_digit(16)?;
+            u8::try_from((high << 4) | low).ok()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn we_can_encode_and_decode_hex() {
+        assert_eq!(encode_hex(&[]), "");
+        assert_eq!(encode_hex(&[0x00, 0x0f, 0xa5, 0xff]), "000fa5ff");
+        assert_eq!(decode_hex(""), Some(Vec::new()));
+        assert_eq!(decode_hex("000fa5ff"), Some(vec![0x00, 0x0f, 0xa5, 0xff]));
+        assert_eq!(decode_hex("000FA5FF"), Some(vec![0x00, 0x0f, 0xa5, 0xff]));
+    }
+
+    #[test]
+    fn we_cannot_decode_invalid_hex() {
+        assert_eq!(decode_hex("abc"), None);
+        assert_eq!(decode_hex("0g"), None);
+        assert_eq!(decode_hex("+1"), None);
+        assert_eq!(decode_hex("é0"), None);
+    }
+}
@@ -0,0 +1,86 @@
+/// Computes the greatest common divisor of the absolute values of `a` and `b` using the binary GCD algorithm.
+fn unsigned_gcd(mut a: u64, mut b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return a | b;
+    }
+    let shift = (a | b).trailing_zeros();
+    a >>= a.trailing_zeros();
+    loop {
+        b >>= b.trailing_zeros();
+        if a > b {
+            core::mem::swap(&mut a, &mut b);
+        }
+        b -= a;
+        if b == 0 {
+            return a << shift;
+        }
+    }
+}
+
+/// Computes the greatest common divisor of `a` and `b`.
+///
+/// The result is always non-negative, and `gcd(a, 0) == |a|`.
+///
+/// # Panics
+/// Panics if the result is `2^63`, which only happens when both inputs are `0` or `i64::MIN`
+/// and at least one is `i64::MIN`.
+#[must_use]
+pub fn gcd(a: i64, b: i64) -> i64 {
+    i64::try_from(unsigned_gcd(a.unsigned_abs(), b.unsigned_abs()))
+        .expect("the gcd of i64 values overflows only for i64::MIN")
+}
+
+/// Computes the least common multiple of `a` and `b`.
+///
+/// The result is always non-negative, and is `0` if either input is `0`.
+/// Returns `None` if the result does not fit in an `i64`.
+#[must_use]
+pub fn lcm(a: i64, b: i64) -> Option<i64> {
+    let (a, b) = (a.unsigned_abs(), b.unsigned_abs());
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / unsigned_gcd(a, b))
+        .checked_mul(b)
+        .and_then(|lcm| i64::try_from(lcm).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn we_can_compute_the_gcd_of_i64_values() {
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(-5, 0), 5);
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(-12, -18), 6);
+        assert_eq!(gcd(17, 5), 1);
+        assert_eq!(gcd(1 << 40, 3 << 20), 1 << 20);
+        assert_eq!(gcd(i64::MAX, i64::MAX), i64::MAX);
+        assert_eq!(gcd(i64::MIN, 6), 2);
+        assert_eq!(gcd(i64::MIN, i64::MAX), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "the gcd of i64 values overflows only for i64::MIN")]
+    fn we_cannot_compute_the_gcd_of_i64_min_and_zero() {
+        let _ = gcd(i64::MIN, 0);
+    }
+
+    #[test]
+    fn we_can_compute_the_lcm_of_i64_values() {
+        assert_eq!(lcm(0, 0), Some(0));
+        assert_eq!(lcm(0, 7), Some(0));
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(-4, 6), Some(12));
+        assert_eq!(lcm(100_000, 1_000), Some(100_000));
+        assert_eq!(lcm(17, 5), Some(85));
+        assert_eq!(lcm(i64::MAX, 1), Some(i64::MAX));
+        assert_eq!(lcm(i64::MIN, 2), None);
+        assert_eq!(lcm(i64::MAX, 2), None);
+        assert_eq!(lcm(1 << 62, 3), None);
+    }
+}
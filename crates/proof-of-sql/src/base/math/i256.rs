@@ -1,12 +1,13 @@
 use crate::base::scalar::Scalar;
 use ark_ff::BigInteger;
-use core::ops::Neg;
+use core::{cmp::Ordering, ops::Neg};
 use serde::{Deserialize, Serialize};
 
-/// A 256-bit data type with some conversions implemented that interpret it as a signed integer.
+/// A signed 256-bit integer in two's complement, stored as four little-endian `u64` limbs.
 ///
-/// This should only implement conversions. If anything else is needed, we should strongly consider an alternative design.
-#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy)]
+/// Besides conversions to and from scalars and other integer types, it supports checked addition,
+/// subtraction and multiplication, which return `None` on overflow.
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy, Hash)]
 pub struct I256([u64; 4]);
 
 impl Neg for I256 {
@@ -19,12 +20,45 @@ impl Neg for I256 {
     }
 }
 
+/// Orders values as signed two's complement integers.
+impl Ord for I256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Flipping the sign bit maps the signed order onto the unsigned order of the limbs.
+        let key = |value: &Self| {
+            [
+                value.0[3] ^ 0x8000_0000_0000_0000,
+                value.0[2],
+                value.0[1],
+                value.0[0],
+            ]
+        };
+        key(self).cmp(&key(other))
+    }
+}
+
+impl PartialOrd for I256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl I256 {
+    /// The largest value representable by an `I256`, i.e. `2^255 - 1`.
+    pub const MAX: Self = Self([u64::MAX, u64::MAX, u64::MAX, 0x7FFF_FFFF_FFFF_FFFF]);
+    /// The smallest value representable by an `I256`, i.e. `-2^255`.
+    pub const MIN: Self = Self([0, 0, 0, 0x8000_0000_0000_0000]);
+
     /// Make an `I256` from its limbs.
     #[must_use]
-    pub fn new(limbs: [u64; 4]) -> Self {
+    pub const fn new(limbs: [u64; 4]) -> Self {
         Self(limbs)
     }
+
+    /// Get the limbs of an `I256`, least significant first.
+    #[must_use]
+    pub const fn limbs(&self) -> [u64; 4] {
+        self.0
+    }
     #[must_use]
     /// Conversion into a [Scalar] type. The conversion handles negative values. In other words, `-1` maps to `-S::ONE`.
     ///
@@ -39,6 +73,132 @@ impl I256 {
         }
     }
 
+    const fn is_negative(&self) -> bool {
+        self.0[3] & 0x8000_0000_0000_0000 != 0
+    }
+
+    #[must_use]
+    /// Returns the absolute value.
+    ///
+    /// The absolute value of [`I256::MIN`] is not representable. It wraps back to [`I256::MIN`] in release builds.
+    ///
+    /// # Panics
+    /// Panics in debug builds when called on [`I256::MIN`].
+    pub fn abs(&self) -> Self {
+        debug_assert!(
+            *self != Self::MIN,
+            "attempt to take the absolute value of I256::MIN"
+        );
+        if self.is_negative() {
+            self.neg()
+        } else {
+            *self
+        }
+    }
+
+    #[must_use]
+    /// Returns the absolute value, saturating to [`I256::MAX`] for [`I256::MIN`].
+    pub fn saturating_abs(&self) -> Self {
+        if *self == Self::MIN {
+            Self::MAX
+        } else {
+            self.abs()
+        }
+    }
+
+    #[must_use]
+    /// Returns `-1` if the value is negative, `0` if it is zero, and `1` if it is positive.
+    pub fn signum(&self) -> i32 {
+        if self.is_negative() {
+            -1
+        } else if self.0 == [0; 4] {
+            0
+        } else {
+            1
+        }
+    }
+
+    #[must_use]
+    /// Returns `self + rhs`, or `None` if the sum overflows.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let mut res = ark_ff::BigInt(self.0);
+        res.add_with_carry(&ark_ff::BigInt(rhs.0));
+        let res = Self(res.0);
+        // Overflow happens exactly when both operands have the same sign and the result has the other sign.
+        (self.is_negative() != rhs.is_negative() || res.is_negative() == self.is_negative())
+            .then_some(res)
+    }
+
+    #[must_use]
+    /// Returns `self - rhs`, or `None` if the difference overflows.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let mut res = ark_ff::BigInt(self.0);
+        res.sub_with_borrow(&ark_ff::BigInt(rhs.0));
+        let res = Self(res.0);
+        // Overflow happens exactly when the operands have different signs and the result has the sign of `rhs`.
+        (self.is_negative() == rhs.is_negative() || res.is_negative() == self.is_negative())
+            .then_some(res)
+    }
+
+    #[must_use]
+    #[expect(clippy::cast_possible_truncation)]
+    /// Returns `self * rhs`, or `None` if the product overflows.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // The wrapping negative of `MIN` is `2^255` when read as unsigned, so these are the exact magnitudes.
+        let magnitude = |value: Self| {
+            if value.is_negative() {
+                value.neg()
+            } else {
+                value
+            }
+        };
+        let (lhs_abs, rhs_abs) = (magnitude(self).0, magnitude(rhs).0);
+        let mut product = [0u64; 8];
+        for (i, &l) in lhs_abs.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, &r) in rhs_abs.iter().enumerate() {
+                let t = u128::from(l) * u128::from(r) + u128::from(product[i + j]) + carry;
+                product[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            product[i + 4] = carry as u64;
+        }
+        if product[4..] != [0; 4] {
+            return None;
+        }
+        let res = Self([product[0], product[1], product[2], product[3]]);
+        match (self.is_negative() != rhs.is_negative(), res.is_negative()) {
+            (false, false) => Some(res),
+            (true, false) => Some(res.neg()),
+            (true, true) if res == Self::MIN => Some(res),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    /// Conversion from a [Scalar] type. The conversion handles negative values. In other words, `-S::ONE` maps to `-1`.
+    ///
+    /// Values larger than [`Scalar::MAX_SIGNED`] are interpreted as negative.
+    pub fn from_scalar<S: Scalar>(value: S) -> Self {
+        if value > S::MAX_SIGNED {
+            Self((-value).into()).neg()
+        } else {
+            Self(value.into())
+        }
+    }
+
+    #[must_use]
+    /// Serializes the value into 32 bytes in little-endian order, i.e. the least significant limb first.
+    pub fn to_le_bytes(&self) -> [u8; 32] {
+        core::array::from_fn(|i| self.0[i / 8].to_le_bytes()[i % 8])
+    }
+    #[must_use]
+    /// Deserializes a value from 32 bytes in little-endian order. This is the inverse of [`I256::to_le_bytes`].
+    pub fn from_le_bytes(bytes: &[u8; 32]) -> Self {
+        Self(core::array::from_fn(|i| {
+            u64::from_le_bytes(core::array::from_fn(|j| bytes[8 * i + j]))
+        }))
+    }
     #[must_use]
     /// Conversion from a [`num_bigint::BigInt`].
     /// The conversion handles negative values and also wraps when the value is too large for an `I256`.
@@ -102,6 +262,13 @@ mod tests {
         0xFFFF_FFFF_FFFF_FFFF,
         0xFFFF_FFFF_FFFF_FFFF,
     ]);
+    const MAX: I256 = I256([
+        0xFFFF_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_FFFF_FFFF,
+        0x7FFF_FFFF_FFFF_FFFF,
+    ]);
+    const MIN: I256 = I256([0, 0, 0, 0x8000_0000_0000_0000]);
     const A_STR: &str =
         "57896044618658097705508390768957273162799202909612615603626436559492530307074";
     const A: I256 = I256([2, 0, 0, 0x7FFF_FFFF_FFFF_FFFF]);
@@ -191,6 +358,126 @@ mod tests {
             assert_eq!(x.neg().neg(), x);
         }
     }
+    #[test]
+    fn we_can_compute_the_absolute_value_of_an_i256() {
+        assert_eq!(ZERO.abs(), ZERO);
+        assert_eq!(ONE.abs(), ONE);
+        assert_eq!(NEG_ONE.abs(), ONE);
+        assert_eq!(NEG_TWO.abs(), TWO);
+        assert_eq!(A.abs(), A);
+        assert_eq!(NEG_A.abs(), A);
+        assert_eq!(NEG_B.abs(), B);
+        assert_eq!(NEG_C.abs(), C);
+        assert_eq!(MAX.abs(), MAX);
+        assert_eq!(MAX.neg().abs(), MAX);
+
+        let mut rng = thread_rng();
+        for _ in 0..10 {
+            let x = I256::from(rng.gen::<i128>());
+            assert_eq!(x.abs(), x.neg().abs());
+        }
+    }
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to take the absolute value of I256::MIN")]
+    fn we_cannot_compute_the_absolute_value_of_the_minimum_i256_in_debug_builds() {
+        let _ = MIN.abs();
+    }
+    #[test]
+    fn we_can_compute_the_saturating_absolute_value_of_an_i256() {
+        assert_eq!(ZERO.saturating_abs(), ZERO);
+        assert_eq!(NEG_ONE.saturating_abs(), ONE);
+        assert_eq!(NEG_B.saturating_abs(), B);
+        assert_eq!(MAX.saturating_abs(), MAX);
+        assert_eq!(MIN.saturating_abs(), MAX);
+        assert_eq!(I256::MIN.saturating_abs(), I256::MAX);
+    }
+    #[test]
+    fn we_can_compute_the_signum_of_an_i256() {
+        assert_eq!(ZERO.signum(), 0);
+        assert_eq!(ONE.signum(), 1);
+        assert_eq!(TWO.signum(), 1);
+        assert_eq!(MAX.signum(), 1);
+        assert_eq!(C.signum(), 1);
+        assert_eq!(NEG_ONE.signum(), -1);
+        assert_eq!(NEG_C.signum(), -1);
+        assert_eq!(MIN.signum(), -1);
+    }
+
+    #[test]
+    fn we_can_add_i256_values_with_overflow_checks() {
+        assert_eq!(C.checked_add(ZERO), Some(C));
+        assert_eq!(ZERO.checked_add(NEG_C), Some(NEG_C));
+        assert_eq!(C.checked_add(NEG_C), Some(ZERO));
+        assert_eq!(ONE.checked_add(NEG_TWO), Some(NEG_ONE));
+        assert_eq!(
+            I256::from(i128::from(u64::MAX)).checked_add(ONE),
+            Some(I256::new([0, 1, 0, 0]))
+        );
+        assert_eq!(MAX.checked_add(ONE), None);
+        assert_eq!(MAX.checked_add(MAX), None);
+        assert_eq!(MIN.checked_add(NEG_ONE), None);
+        assert_eq!(MIN.checked_add(MAX), Some(NEG_ONE));
+    }
+    #[test]
+    fn we_can_subtract_i256_values_with_overflow_checks() {
+        assert_eq!(C.checked_sub(ZERO), Some(C));
+        assert_eq!(C.checked_sub(C), Some(ZERO));
+        assert_eq!(ZERO.checked_sub(C), Some(NEG_C));
+        assert_eq!(
+            I256::new([0, 1, 0, 0]).checked_sub(ONE),
+            Some(I256::new([u64::MAX, 0, 0, 0]))
+        );
+        assert_eq!(ZERO.checked_sub(MIN), None);
+        assert_eq!(NEG_ONE.checked_sub(MIN), Some(MAX));
+        assert_eq!(MIN.checked_sub(ONE), None);
+        assert_eq!(MAX.checked_sub(NEG_ONE), None);
+    }
+    #[test]
+    fn we_can_multiply_i256_values_with_overflow_checks() {
+        let i64_max = I256::from(i128::from(i64::MAX));
+        assert_eq!(C.checked_mul(ZERO), Some(ZERO));
+        assert_eq!(C.checked_mul(ONE), Some(C));
+        assert_eq!(C.checked_mul(NEG_ONE), Some(NEG_C));
+        assert_eq!(NEG_TWO.checked_mul(NEG_ONE), Some(TWO));
+        assert_eq!(
+            i64_max.checked_mul(i64_max),
+            Some(I256::from(i128::from(i64::MAX) * i128::from(i64::MAX)))
+        );
+        assert_eq!(
+            i64_max.checked_mul(NEG_TWO),
+            Some(I256::from(-2 * i128::from(i64::MAX)))
+        );
+        let two_pow_128 = I256::new([0, 0, 1, 0]);
+        assert_eq!(
+            two_pow_128.checked_mul(i64_max),
+            Some(I256::new([0, 0, i64::MAX.unsigned_abs(), 0]))
+        );
+        assert_eq!(two_pow_128.checked_mul(two_pow_128), None);
+        assert_eq!(MAX.checked_mul(TWO), None);
+        assert_eq!(MIN.checked_mul(ONE), Some(MIN));
+        assert_eq!(MIN.checked_mul(NEG_ONE), None);
+        assert_eq!(
+            I256::new([0, 0, 0, 1 << 62]).checked_mul(NEG_TWO),
+            Some(MIN)
+        );
+    }
+
+    #[test]
+    fn we_can_compare_i256_values() {
+        assert!(ONE < TWO);
+        assert!(C > TWO);
+        assert!(I256::new([0, 0, 1, 0]) > I256::new([u64::MAX, u64::MAX, 0, 0]));
+        assert!(NEG_TWO < NEG_ONE);
+        assert!(NEG_C < NEG_TWO);
+        assert!(NEG_ONE < ZERO);
+        assert_eq!(C.cmp(&C), Ordering::Equal);
+        assert_eq!(NEG_C.cmp(&NEG_C), Ordering::Equal);
+        let mut values = [MAX, ONE, NEG_ONE, ZERO, MIN];
+        values.sort();
+        assert_eq!(values, [MIN, NEG_ONE, ZERO, ONE, MAX]);
+    }
+
     #[test]
     fn we_can_convert_i256_into_scalar() {
         assert_eq!(ZERO.into_scalar::<TestScalar>(), TestScalar::ZERO);
@@ -227,6 +514,47 @@ mod tests {
         }
     }
     #[test]
+    fn we_can_convert_i256_from_scalar() {
+        assert_eq!(I256::from_scalar(TestScalar::ZERO), ZERO);
+        assert_eq!(I256::from_scalar(TestScalar::ONE), ONE);
+        assert_eq!(I256::from_scalar(-TestScalar::ONE), NEG_ONE);
+        assert_eq!(I256::from_scalar(TestScalar::TWO), TWO);
+        assert_eq!(I256::from_scalar(-TestScalar::TWO), NEG_TWO);
+        assert_eq!(I256::from_scalar(C_SCALAR), C);
+        assert_eq!(I256::from_scalar(-C_SCALAR), NEG_C);
+
+        let mut rng = thread_rng();
+        for _ in 0..10 {
+            let x = I256::from(rng.gen::<i128>());
+            assert_eq!(I256::from_scalar(x.into_scalar::<TestScalar>()), x);
+        }
+    }
+    #[test]
+    fn we_can_convert_i256_to_and_from_le_bytes() {
+        assert_eq!(ZERO.to_le_bytes(), [0; 32]);
+        let mut one_bytes = [0; 32];
+        one_bytes[0] = 1;
+        assert_eq!(ONE.to_le_bytes(), one_bytes);
+        assert_eq!(NEG_ONE.to_le_bytes(), [0xFF; 32]);
+        let mut max_bytes = [0xFF; 32];
+        max_bytes[31] = 0x7F;
+        assert_eq!(MAX.to_le_bytes(), max_bytes);
+        let mut min_bytes = [0; 32];
+        min_bytes[31] = 0x80;
+        assert_eq!(MIN.to_le_bytes(), min_bytes);
+        assert_eq!(C.to_le_bytes()[24..], [0, 0, 0, 0, 0, 0, 0, 0x01]);
+
+        for value in [ZERO, ONE, NEG_ONE, MAX, MIN, A, NEG_A, B, NEG_B, C, NEG_C] {
+            assert_eq!(I256::from_le_bytes(&value.to_le_bytes()), value);
+        }
+
+        let mut rng = thread_rng();
+        for _ in 0..10 {
+            let x = I256([rng.gen(), rng.gen(), rng.gen(), rng.gen()]);
+            assert_eq!(I256::from_le_bytes(&x.to_le_bytes()), x);
+        }
+    }
+    #[test]
     fn we_can_convert_i256_from_num_bigint() {
         assert_eq!(I256::from_num_bigint(&"0".parse().unwrap()), ZERO);
         assert_eq!(I256::from_num_bigint(&"1".parse().unwrap()), ONE);
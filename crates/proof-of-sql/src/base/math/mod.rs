@@ -7,6 +7,9 @@ mod decimal_tests;
 pub mod i256;
 mod log;
 pub(crate) use log::log2_up;
+/// Integer utilities used to align the scales of decimals.
+mod gcd;
+pub use gcd::{gcd, lcm};
 /// TODO: add docs
 pub(crate) mod permutation;
 
@@ -8,6 +8,7 @@ pub mod commitment;
 pub mod database;
 /// TODO: add docs
 pub(crate) mod encode;
+pub(crate) mod hex;
 pub mod math;
 /// TODO: add docs
 pub(crate) mod polynomial;
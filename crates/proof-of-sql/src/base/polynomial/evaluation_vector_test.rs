@@ -1,24 +1,26 @@
 use super::compute_evaluation_vector;
-use crate::base::{scalar::test_scalar::TestScalar, slice_ops};
+use crate::base::{
+    scalar::{test_scalar::TestScalar, Scalar},
+    slice_ops,
+};
 use ark_poly::Polynomial;
-use num_traits::{One, Zero};
 
 #[test]
 fn we_compute_the_correct_evaluation_vector_for_a_small_example() {
-    let mut v = [TestScalar::zero(); 2];
+    let mut v = [TestScalar::ZERO; 2];
     compute_evaluation_vector(&mut v, &[TestScalar::from(3u64)]);
     let expected_v = [
-        TestScalar::one() - TestScalar::from(3u64),
+        TestScalar::ONE - TestScalar::from(3u64),
         TestScalar::from(3u64),
     ];
     assert_eq!(v, expected_v);
 
-    let mut v = [TestScalar::zero(); 4];
+    let mut v = [TestScalar::ZERO; 4];
     compute_evaluation_vector(&mut v, &[TestScalar::from(3u64), TestScalar::from(4u64)]);
     let expected_v = [
-        (TestScalar::one() - TestScalar::from(4u64)) * (TestScalar::one() - TestScalar::from(3u64)),
-        (TestScalar::one() - TestScalar::from(4u64)) * TestScalar::from(3u64),
-        TestScalar::from(4u64) * (TestScalar::one() - TestScalar::from(3u64)),
+        (TestScalar::ONE - TestScalar::from(4u64)) * (TestScalar::ONE - TestScalar::from(3u64)),
+        (TestScalar::ONE - TestScalar::from(4u64)) * TestScalar::from(3u64),
+        TestScalar::from(4u64) * (TestScalar::ONE - TestScalar::from(3u64)),
         TestScalar::from(4u64) * TestScalar::from(3u64),
     ];
     assert_eq!(v, expected_v);
@@ -26,23 +28,23 @@ fn we_compute_the_correct_evaluation_vector_for_a_small_example() {
 
 #[test]
 fn we_compute_the_evaluation_vectors_not_a_power_of_2() {
-    let mut v = [TestScalar::zero(); 1];
+    let mut v = [TestScalar::ZERO; 1];
     compute_evaluation_vector(&mut v, &[TestScalar::from(3u64)]);
-    let expected_v = [TestScalar::one() - TestScalar::from(3u64)];
+    let expected_v = [TestScalar::ONE - TestScalar::from(3u64)];
     assert_eq!(v, expected_v);
 
-    let mut v = [TestScalar::zero(); 3];
+    let mut v = [TestScalar::ZERO; 3];
     compute_evaluation_vector(&mut v, &[TestScalar::from(3u64), TestScalar::from(4u64)]);
     let expected_v = [
-        (TestScalar::one() - TestScalar::from(4u64)) * (TestScalar::one() - TestScalar::from(3u64)),
-        (TestScalar::one() - TestScalar::from(4u64)) * TestScalar::from(3u64),
-        TestScalar::from(4u64) * (TestScalar::one() - TestScalar::from(3u64)),
+        (TestScalar::ONE - TestScalar::from(4u64)) * (TestScalar::ONE - TestScalar::from(3u64)),
+        (TestScalar::ONE - TestScalar::from(4u64)) * TestScalar::from(3u64),
+        TestScalar::from(4u64) * (TestScalar::ONE - TestScalar::from(3u64)),
     ];
     assert_eq!(v, expected_v);
 }
 #[test]
 fn we_compute_the_evaluation_vectors_of_any_length() {
-    let mut full_vec = [TestScalar::zero(); 16];
+    let mut full_vec = [TestScalar::ZERO; 16];
     let evaluation_point = [
         TestScalar::from(2u64),
         TestScalar::from(3u64),
@@ -51,7 +53,7 @@ fn we_compute_the_evaluation_vectors_of_any_length() {
     ];
     compute_evaluation_vector(&mut full_vec, &evaluation_point);
     for i in 0..16 {
-        let mut v = vec![TestScalar::zero(); i];
+        let mut v = vec![TestScalar::ZERO; i];
         compute_evaluation_vector(&mut v, &evaluation_point);
         assert_eq!(v, &full_vec[..i]);
     }
@@ -59,9 +61,9 @@ fn we_compute_the_evaluation_vectors_of_any_length() {
 
 #[test]
 fn we_compute_the_evaluation_vector_for_an_empty_point() {
-    let mut v = [TestScalar::zero(); 1];
+    let mut v = [TestScalar::ZERO; 1];
     compute_evaluation_vector(&mut v, &[]);
-    let expected_v = [TestScalar::one()];
+    let expected_v = [TestScalar::ONE];
     assert_eq!(v, expected_v);
 }
 
@@ -82,7 +84,7 @@ fn we_get_the_same_result_using_evaluation_vector_as_direct_evaluation() {
         TestScalar::from(33u64),
         TestScalar::from(22u64),
     ];
-    let mut v = [TestScalar::zero(); 8];
+    let mut v = [TestScalar::ZERO; 8];
     compute_evaluation_vector(&mut v, &point);
     let eval = slice_ops::inner_product(&xs, &v);
 
@@ -127,3 +127,21 @@ where
         })
         .unwrap_or(vec![])
 }
+
+/// Evaluates the polynomial whose coefficients are `reverse_coefficients`, with the leading coefficient first,
+/// at every point of `domain` using Horner's method.
+/// When `domain` is `0, 1, ..., d`, this is the inverse of [`interpolate_evaluations_to_reverse_coefficients`].
+#[allow(dead_code)]
+pub fn evaluate_reverse_coefficients<S>(reverse_coefficients: &[S], domain: &[S]) -> Vec<S>
+where
+    S: Zero + Copy + Mul<Output = S> + Add<Output = S>,
+{
+    domain
+        .iter()
+        .map(|&x| {
+            reverse_coefficients
+                .iter()
+                .fold(S::zero(), |acc, &c| acc * x + c)
+        })
+        .collect()
+}
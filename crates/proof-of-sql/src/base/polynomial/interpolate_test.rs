@@ -4,7 +4,10 @@
  * See third_party/license/arkworks.LICENSE
  */
 use super::interpolate::*;
-use crate::base::scalar::test_scalar::{TestScalar as S, TestScalar};
+use crate::base::scalar::{
+    test_scalar::{TestScalar as S, TestScalar},
+    Scalar,
+};
 use ark_std::UniformRand;
 use core::iter;
 use num_traits::{Inv, Zero};
@@ -29,7 +32,7 @@ fn test_interpolate_uni_poly_for_random_polynomials() {
         let value = interpolate_uni_poly(&evals, query);
         let expected_value = poly
             .iter()
-            .fold(TestScalar::zero(), |acc, &c| acc * query + c);
+            .fold(TestScalar::ZERO, |acc, &c| acc * query + c);
         assert_eq!(value, expected_value);
     }
 }
@@ -161,3 +164,31 @@ fn we_can_interpolate_evaluations_to_reverse_coefficients_with_degree_3_degenera
         vec![S::from(0), S::from(0), S::from(2), S::from(1)]
     );
 }
+
+#[test]
+fn we_can_evaluate_reverse_coefficients_on_a_domain() {
+    // 2x^2 + 3x + 5
+    let coefficients = [S::from(2), S::from(3), S::from(5)];
+    assert_eq!(
+        evaluate_reverse_coefficients(&coefficients, &[S::from(0), S::from(1), S::from(-2)]),
+        vec![S::from(5), S::from(10), S::from(7)]
+    );
+    assert_eq!(
+        evaluate_reverse_coefficients(&[] as &[S], &[S::from(4)]),
+        vec![S::ZERO]
+    );
+    assert!(evaluate_reverse_coefficients(&coefficients, &[]).is_empty());
+}
+
+#[test]
+fn we_can_round_trip_between_evaluations_and_reverse_coefficients() {
+    let mut prng = ark_std::test_rng();
+    for n in [1, 2, 3, 5, 8] {
+        let evals = iter::repeat_with(|| TestScalar::rand(&mut prng))
+            .take(n)
+            .collect::<Vec<_>>();
+        let domain = (0..n).map(|i| S::from(i as u64)).collect::<Vec<_>>();
+        let coefficients = interpolate_evaluations_to_reverse_coefficients(&evals);
+        assert_eq!(evaluate_reverse_coefficients(&coefficients, &domain), evals);
+    }
+}
@@ -3,11 +3,10 @@ use crate::base::{
         compute_evaluation_vector, compute_rho_eval,
         compute_truncated_lagrange_basis_inner_product, compute_truncated_lagrange_basis_sum,
     },
-    scalar::test_scalar::TestScalar,
+    scalar::{test_scalar::TestScalar, Scalar},
 };
 use ark_std::UniformRand;
 use core::iter;
-use num_traits::Zero;
 
 #[test]
 fn compute_truncated_lagrange_basis_sum_gives_correct_values_with_0_variables() {
@@ -367,7 +366,7 @@ fn compute_truncated_lagrange_basis_sum_matches_sum_of_result_from_compute_evalu
         let point: Vec<_> = iter::repeat_with(|| TestScalar::rand(&mut rng))
             .take(variables)
             .collect();
-        let mut eval_vec = vec![TestScalar::zero(); length];
+        let mut eval_vec = vec![TestScalar::ZERO; length];
         compute_evaluation_vector(&mut eval_vec, &point);
         // ---------------- This is the actual test --------------------
         assert_eq!(
@@ -398,8 +397,8 @@ fn compute_truncated_lagrange_basis_inner_product_matches_inner_product_of_resul
         let b: Vec<_> = iter::repeat_with(|| TestScalar::rand(&mut rng))
             .take(variables)
             .collect();
-        let mut eval_vec_a = vec![TestScalar::zero(); length];
-        let mut eval_vec_b = vec![TestScalar::zero(); length];
+        let mut eval_vec_a = vec![TestScalar::ZERO; length];
+        let mut eval_vec_b = vec![TestScalar::ZERO; length];
         compute_evaluation_vector(&mut eval_vec_a, &a);
         compute_evaluation_vector(&mut eval_vec_b, &b);
         // ---------------- This is the actual test --------------------
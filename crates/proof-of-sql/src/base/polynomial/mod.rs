@@ -7,7 +7,10 @@ mod interpolate;
 #[cfg(test)]
 mod interpolate_test;
 #[expect(unused_imports)]
-pub use interpolate::{interpolate_evaluations_to_reverse_coefficients, interpolate_uni_poly};
+pub use interpolate::{
+    evaluate_reverse_coefficients, interpolate_evaluations_to_reverse_coefficients,
+    interpolate_uni_poly,
+};
 
 mod evaluation_vector;
 pub use evaluation_vector::compute_evaluation_vector;
@@ -16,6 +16,13 @@ pub enum PoSQLTimestampError {
     #[snafu(display("invalid timezone offset"))]
     InvalidTimezoneOffset,
 
+    /// Error when a timezone offset is more than 18 hours away from UTC.
+    #[snafu(display("timezone offset of {offset} seconds is out of range"))]
+    TimezoneOffsetOutOfRange {
+        /// The out of range offset in seconds
+        offset: i32,
+    },
+
     /// Indicates a failure to convert between different representations of time units.
     #[snafu(display("Invalid time unit"))]
     InvalidTimeUnit {
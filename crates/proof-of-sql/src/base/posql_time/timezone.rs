@@ -1,6 +1,6 @@
 use super::PoSQLTimestampError;
 use alloc::{string::ToString, sync::Arc};
-use core::fmt;
+use core::{fmt, str::FromStr};
 use serde::{Deserialize, Serialize};
 
 /// Captures a timezone from a timestamp query
@@ -10,6 +10,9 @@ pub struct PoSQLTimeZone {
 }
 
 impl PoSQLTimeZone {
+    /// The largest supported offset from UTC in seconds (18 hours)
+    pub const MAX_OFFSET_SECONDS: i32 = 18 * 3600;
+
     /// Create a timezone from a count of seconds
     #[must_use]
     pub const fn new(offset: i32) -> Self {
@@ -20,6 +23,17 @@ impl PoSQLTimeZone {
     pub const fn utc() -> Self {
         PoSQLTimeZone::new(0)
     }
+    /// Create a timezone from an offset from UTC in seconds, checking that it is at most 18 hours either way
+    ///
+    /// # Errors
+    /// Returns [`PoSQLTimestampError::TimezoneOffsetOutOfRange`] if the offset is outside `[-64800, 64800]`.
+    pub const fn from_offset_seconds(offset: i32) -> Result<Self, PoSQLTimestampError> {
+        if offset < -Self::MAX_OFFSET_SECONDS || offset > Self::MAX_OFFSET_SECONDS {
+            Err(PoSQLTimestampError::TimezoneOffsetOutOfRange { offset })
+        } else {
+            Ok(PoSQLTimeZone::new(offset))
+        }
+    }
     /// Get the underlying offset in seconds
     #[must_use]
     pub const fn offset(self) -> i32 {
@@ -38,29 +52,41 @@ impl TryFrom<&Option<Arc<str>>> for PoSQLTimeZone {
 
     fn try_from(value: &Option<Arc<str>>) -> Result<Self, Self::Error> {
         match value {
-            Some(tz_str) => {
-                let tz = Arc::as_ref(tz_str).to_uppercase();
-                match tz.as_str() {
-                    "Z" | "UTC" | "00:00" | "+00:00" | "0:00" | "+0:00" => Ok(PoSQLTimeZone::utc()),
-                    tz if tz.chars().count() == 6
-                        && (tz.starts_with('+') || tz.starts_with('-')) =>
-                    {
-                        let sign = if tz.starts_with('-') { -1 } else { 1 };
-                        let hours = tz[1..3]
-                            .parse::<i32>()
-                            .map_err(|_| PoSQLTimestampError::InvalidTimezoneOffset)?;
-                        let minutes = tz[4..6]
-                            .parse::<i32>()
-                            .map_err(|_| PoSQLTimestampError::InvalidTimezoneOffset)?;
-                        let total_seconds = sign * ((hours * 3600) + (minutes * 60));
-                        Ok(PoSQLTimeZone::new(total_seconds))
-                    }
-                    _ => Err(PoSQLTimestampError::InvalidTimezone {
-                        timezone: tz.to_string(),
-                    }),
+            Some(tz_str) => tz_str.parse(),
+            None => Ok(PoSQLTimeZone::utc()),
+        }
+    }
+}
+
+/// Parses a timezone in the `±HH:MM` format, or `Z`/`UTC` for UTC.
+impl FromStr for PoSQLTimeZone {
+    type Err = PoSQLTimestampError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let tz = value.to_uppercase();
+        match tz.as_str() {
+            "Z" | "UTC" | "00:00" | "+00:00" | "0:00" | "+0:00" => Ok(PoSQLTimeZone::utc()),
+            tz if tz.len() == 6
+                && tz.is_ascii()
+                && (tz.starts_with('+') || tz.starts_with('-'))
+                && &tz[3..4] == ":" =>
+            {
+                let sign = if tz.starts_with('-') { -1 } else { 1 };
+                let hours = tz[1..3]
+                    .parse::<u8>()
+                    .map_err(|_| PoSQLTimestampError::InvalidTimezoneOffset)?;
+                let minutes = tz[4..6]
+                    .parse::<u8>()
+                    .map_err(|_| PoSQLTimestampError::InvalidTimezoneOffset)?;
+                if minutes >= 60 {
+                    return Err(PoSQLTimestampError::InvalidTimezoneOffset);
                 }
+                let total_seconds = sign * (i32::from(hours) * 3600 + i32::from(minutes) * 60);
+                PoSQLTimeZone::from_offset_seconds(total_seconds)
             }
-            None => Ok(PoSQLTimeZone::utc()),
+            _ => Err(PoSQLTimestampError::InvalidTimezone {
+                timezone: tz.to_string(),
+            }),
         }
     }
 }
@@ -95,9 +121,80 @@ mod timezone_parsing_tests {
         assert_eq!(format!("{timezone}"), "-01:03");
     }
 
+    #[test]
+    fn we_can_create_timezones_from_offset_seconds() {
+        assert_eq!(
+            PoSQLTimeZone::from_offset_seconds(0),
+            Ok(PoSQLTimeZone::utc())
+        );
+        for offset in [19800, -25200, 64800, -64800] {
+            let timezone = PoSQLTimeZone::from_offset_seconds(offset).unwrap();
+            assert_eq!(timezone.offset(), offset);
+        }
+        assert_eq!(
+            format!("{}", PoSQLTimeZone::from_offset_seconds(19800).unwrap()),
+            "+05:30"
+        );
+    }
+
+    #[test]
+    fn we_cannot_create_timezones_from_out_of_range_offset_seconds() {
+        for offset in [64801, -64801, i32::MAX, i32::MIN] {
+            assert_eq!(
+                PoSQLTimeZone::from_offset_seconds(offset),
+                Err(PoSQLTimestampError::TimezoneOffsetOutOfRange { offset })
+            );
+        }
+    }
+
     #[test]
     fn test_display_utc() {
         let timezone = PoSQLTimeZone::utc();
         assert_eq!(format!("{timezone}"), "+00:00");
     }
+
+    #[test]
+    fn we_can_parse_timezones() {
+        for (tz, offset) in [
+            ("UTC", 0),
+            ("utc", 0),
+            ("Z", 0),
+            ("+00:00", 0),
+            ("-00:00", 0),
+            ("+05:30", 19800),
+            ("-07:00", -25200),
+            ("-03:30", -12600),
+            ("+18:00", 64800),
+        ] {
+            assert_eq!(tz.parse(), Ok(PoSQLTimeZone::new(offset)));
+        }
+    }
+
+    #[test]
+    fn parsing_a_displayed_timezone_round_trips() {
+        for offset in [0, 4500, -3780, 19800, -25200, 64800, -64800] {
+            let timezone = PoSQLTimeZone::new(offset);
+            assert_eq!(timezone.to_string().parse(), Ok(timezone));
+        }
+    }
+
+    #[test]
+    fn we_cannot_parse_invalid_timezones() {
+        assert_eq!(
+            "+25:00".parse::<PoSQLTimeZone>(),
+            Err(PoSQLTimestampError::TimezoneOffsetOutOfRange { offset: 90000 })
+        );
+        for tz in ["+05:60", "+0a:00", "+-5:00"] {
+            assert_eq!(
+                tz.parse::<PoSQLTimeZone>(),
+                Err(PoSQLTimestampError::InvalidTimezoneOffset)
+            );
+        }
+        for tz in ["ABC", "", "05:30", "+5:30", "+05-30", "+05:300", "+0é:00"] {
+            assert!(matches!(
+                tz.parse::<PoSQLTimeZone>(),
+                Err(PoSQLTimestampError::InvalidTimezone { .. })
+            ));
+        }
+    }
 }
@@ -3,7 +3,9 @@ use core::fmt;
 use serde::{Deserialize, Serialize};
 
 /// An intermediate type representing the time units from a parsed query
-#[derive(Debug, Clone, Copy, Hash, Serialize, Deserialize, PartialEq, Eq)]
+///
+/// Units are ordered by precision, from coarsest to finest.
+#[derive(Debug, Clone, Copy, Hash, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub enum PoSQLTimeUnit {
     /// Represents seconds with precision 0: ex "2024-06-20 12:34:56"
     Second,
@@ -15,6 +17,19 @@ pub enum PoSQLTimeUnit {
     Nanosecond,
 }
 
+impl PoSQLTimeUnit {
+    /// Returns the number of units in one second
+    #[must_use]
+    pub const fn units_per_second(self) -> i64 {
+        match self {
+            PoSQLTimeUnit::Second => 1,
+            PoSQLTimeUnit::Millisecond => 1_000,
+            PoSQLTimeUnit::Microsecond => 1_000_000,
+            PoSQLTimeUnit::Nanosecond => 1_000_000_000,
+        }
+    }
+}
+
 impl From<PoSQLTimeUnit> for u64 {
     fn from(value: PoSQLTimeUnit) -> u64 {
         match value {
@@ -96,4 +111,38 @@ mod time_unit_tests {
             ));
         }
     }
+
+    #[test]
+    fn time_units_are_ordered_from_coarsest_to_finest() {
+        assert!(PoSQLTimeUnit::Second < PoSQLTimeUnit::Millisecond);
+        assert!(PoSQLTimeUnit::Millisecond < PoSQLTimeUnit::Microsecond);
+        assert!(PoSQLTimeUnit::Microsecond < PoSQLTimeUnit::Nanosecond);
+        assert_eq!(
+            PoSQLTimeUnit::Second.max(PoSQLTimeUnit::Microsecond),
+            PoSQLTimeUnit::Microsecond
+        );
+        assert_eq!(
+            PoSQLTimeUnit::Nanosecond.max(PoSQLTimeUnit::Millisecond),
+            PoSQLTimeUnit::Nanosecond
+        );
+
+        let mut units = vec![
+            PoSQLTimeUnit::Microsecond,
+            PoSQLTimeUnit::Second,
+            PoSQLTimeUnit::Nanosecond,
+            PoSQLTimeUnit::Millisecond,
+            PoSQLTimeUnit::Second,
+        ];
+        units.sort();
+        assert_eq!(
+            units,
+            vec![
+                PoSQLTimeUnit::Second,
+                PoSQLTimeUnit::Second,
+                PoSQLTimeUnit::Millisecond,
+                PoSQLTimeUnit::Microsecond,
+                PoSQLTimeUnit::Nanosecond,
+            ]
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use alloc::string::String;
 use snafu::Snafu;
 
 #[derive(Snafu, Debug)]
@@ -18,6 +19,22 @@ pub enum ProofError {
     /// This error occurs when the number of fields in the result table does not match the query.
     #[snafu(display("Result does not match query: field count mismatch"))]
     FieldCountMismatch,
+    /// This error occurs when the result of a query plan has several columns with the same name, e.g. `SELECT a, a FROM t`.
+    #[snafu(display("Column {column} is selected more than once"))]
+    DuplicateColumnInQuery {
+        /// The name of the duplicate column.
+        column: String,
+    },
+    /// This error occurs when the tables referenced by a query have more rows than the prover setup supports.
+    #[snafu(display(
+        "Circuit too large: the query covers {table_size} rows, but the setup supports at most {max_table_size}"
+    ))]
+    CircuitTooLarge {
+        /// The number of rows covered by the query.
+        table_size: usize,
+        /// The maximum number of rows supported by the setup.
+        max_table_size: usize,
+    },
     #[snafu(transparent)]
     ProofSizeMismatch { source: ProofSizeMismatch },
 }
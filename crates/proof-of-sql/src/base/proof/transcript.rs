@@ -71,6 +71,27 @@ pub trait Transcript {
         self.extend_as_le([transcript.challenge_as_le()]);
         result
     }
+    /// Replays a transcript in which each prover message is followed by exactly one challenge.
+    ///
+    /// Starting from a fresh transcript, every message in `prover_messages` is appended as raw bytes and a challenge is
+    /// then drawn with [`Transcript::challenge_as_le`]. Returns `true` if and only if there is one expected challenge per
+    /// message and every re-derived challenge matches the corresponding one in `expected_challenges`.
+    ///
+    /// This is intended for offline auditing and is not used during verification.
+    fn replay(prover_messages: &[&[u8]], expected_challenges: &[[u8; 32]]) -> bool
+    where
+        Self: Sized,
+    {
+        let mut transcript = Self::new();
+        prover_messages.len() == expected_challenges.len()
+            && prover_messages
+                .iter()
+                .zip(expected_challenges)
+                .all(|(message, expected)| {
+                    transcript.extend_as_le_from_refs([*message]);
+                    transcript.challenge_as_le() == *expected
+                })
+    }
 }
 
 #[cfg(test)]
@@ -145,6 +166,34 @@ mod tests {
         assert_ne!(transcript1.challenge_as_le(), transcript2.challenge_as_le());
     }
 
+    #[test]
+    fn we_can_replay_a_transcript() {
+        let messages: [&[u8]; 3] = [b"first", b"second", b""];
+        let mut transcript: Keccak256Transcript = Transcript::new();
+        let challenges: Vec<[u8; 32]> = messages
+            .iter()
+            .map(|message| {
+                transcript.extend_as_le_from_refs([*message]);
+                transcript.challenge_as_le()
+            })
+            .collect();
+        assert!(Keccak256Transcript::replay(&messages, &challenges));
+        assert!(Keccak256Transcript::replay(&[], &[]));
+
+        // A tampered message changes every subsequent challenge
+        let tampered: [&[u8]; 3] = [b"first", b"tampered", b""];
+        assert!(!Keccak256Transcript::replay(&tampered, &challenges));
+
+        // A wrong challenge is detected
+        let mut wrong_challenges = challenges.clone();
+        wrong_challenges[2][0] ^= 1;
+        assert!(!Keccak256Transcript::replay(&messages, &wrong_challenges));
+
+        // The number of messages and challenges must agree
+        assert!(!Keccak256Transcript::replay(&messages, &challenges[..2]));
+        assert!(!Keccak256Transcript::replay(&messages[..2], &challenges));
+    }
+
     #[test]
     fn we_can_extend_transcript_with_extend_as_be_from_refs() {
         let mut transcript1: Keccak256Transcript = Transcript::new();
@@ -0,0 +1,173 @@
+use super::Scalar;
+use core::ops::{Add, Mul, Neg, Sub};
+use num_bigint::BigInt;
+
+/// A [`Scalar`] that panics when arithmetic on it leaves a range of signed integers.
+///
+/// Field arithmetic silently wraps around the modulus. This wrapper instead checks every result against a
+/// bit width fixed at construction, interpreting scalars as signed integers as in [`Scalar::MAX_SIGNED`].
+/// A `CheckedScalar` with width `bits` holds exactly the values `v` with `|v| < 2^bits`.
+///
+/// This is intended for catching overflows while developing and testing and is too slow for production code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckedScalar<S: Scalar> {
+    value: S,
+    bits: u32,
+}
+
+impl<S: Scalar> CheckedScalar<S> {
+    /// Wraps `value`, whose absolute value must stay below `2^bits` in all arithmetic.
+    ///
+    /// # Panics
+    /// Panics if `value` itself is out of range.
+    #[must_use]
+    pub fn new(value: S, bits: u32) -> Self {
+        let integer: BigInt = value.into();
+        assert!(
+            integer.bits() <= u64::from(bits),
+            "CheckedScalar overflow: {integer} needs {} bits, but at most {bits} are allowed",
+            integer.bits()
+        );
+        Self { value, bits }
+    }
+
+    /// Returns the wrapped scalar.
+    #[must_use]
+    pub fn value(&self) -> S {
+        self.value
+    }
+
+    /// Returns the bit width that results must fit in.
+    #[must_use]
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Computes `op` on the integer values of `self` and `rhs` and checks the result against the narrower width.
+    ///
+    /// `field_result` is the field result of the same operation, which is correct whenever the check passes.
+    fn checked_binary_op(
+        self,
+        rhs: Self,
+        symbol: &str,
+        op: impl FnOnce(BigInt, BigInt) -> BigInt,
+        field_result: S,
+    ) -> Self {
+        let (lhs_value, rhs_value): (BigInt, BigInt) = (self.value.into(), rhs.value.into());
+        let result = op(lhs_value.clone(), rhs_value.clone());
+        let bits = self.bits.min(rhs.bits);
+        assert!(
+            result.bits() <= u64::from(bits),
+            "CheckedScalar overflow: {lhs_value} {symbol} {rhs_value} = {result} needs {} bits, but at most {bits} are allowed",
+            result.bits()
+        );
+        Self {
+            value: field_result,
+            bits,
+        }
+    }
+}
+
+impl<S: Scalar> Add for CheckedScalar<S> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let mut sum = self.value;
+        sum += rhs.value;
+        self.checked_binary_op(rhs, "+", |a, b| a + b, sum)
+    }
+}
+
+impl<S: Scalar> Sub for CheckedScalar<S> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_binary_op(rhs, "-", |a, b| a - b, self.value - rhs.value)
+    }
+}
+
+impl<S: Scalar> Mul for CheckedScalar<S> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let mut product = self.value;
+        product *= rhs.value;
+        self.checked_binary_op(rhs, "*", |a, b| a * b, product)
+    }
+}
+
+impl<S: Scalar> Neg for CheckedScalar<S> {
+    type Output = Self;
+    /// Negation cannot overflow since the range is symmetric.
+    fn neg(self) -> Self {
+        Self {
+            value: -self.value,
+            bits: self.bits,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CheckedScalar;
+    use crate::base::scalar::{test_scalar::TestScalar, Scalar};
+
+    fn checked(value: i128, bits: u32) -> CheckedScalar<TestScalar> {
+        CheckedScalar::new(TestScalar::from(value), bits)
+    }
+
+    #[test]
+    fn we_can_do_arithmetic_within_the_bit_width() {
+        let a = checked(i128::from(u32::MAX), 64);
+        let b = checked(-3, 64);
+        assert_eq!((a + b).value(), TestScalar::from(i128::from(u32::MAX) - 3));
+        assert_eq!((b - a).value(), TestScalar::from(-3 - i128::from(u32::MAX)));
+        assert_eq!((a * b).value(), TestScalar::from(-3 * i128::from(u32::MAX)));
+        assert_eq!((-b).value(), TestScalar::from(3));
+        assert_eq!(
+            (a * a).value(),
+            TestScalar::from(i128::from(u32::MAX) * i128::from(u32::MAX))
+        );
+        assert_eq!(checked(i128::from(u64::MAX), 64).bits(), 64);
+        assert_eq!(
+            checked(-i128::from(u64::MAX), 64).value(),
+            -TestScalar::from(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn the_narrower_bit_width_is_kept() {
+        assert_eq!((checked(1, 8) + checked(1, 64)).bits(), 8);
+        assert_eq!((checked(1, 64) * checked(1, 8)).bits(), 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "CheckedScalar overflow: 18446744073709551616 needs 65 bits")]
+    fn we_cannot_create_a_checked_scalar_out_of_range() {
+        let _ = checked(1 << 64, 64);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "CheckedScalar overflow: 18446744073709551615 + 1 = 18446744073709551616 needs 65 bits, but at most 64 are allowed"
+    )]
+    fn we_cannot_add_past_the_bit_width() {
+        let _ = checked(i128::from(u64::MAX), 64) + checked(1, 64);
+    }
+
+    #[test]
+    #[should_panic(expected = "CheckedScalar overflow: -18446744073709551615 - 1")]
+    fn we_cannot_subtract_past_the_bit_width() {
+        let _ = checked(-i128::from(u64::MAX), 64) - checked(1, 64);
+    }
+
+    #[test]
+    #[should_panic(expected = "CheckedScalar overflow: 4294967296 * 4294967296")]
+    fn we_cannot_multiply_past_the_bit_width() {
+        let _ = checked(1 << 32, 64) * checked(1 << 32, 64);
+    }
+
+    #[test]
+    #[should_panic(expected = "CheckedScalar overflow")]
+    fn we_detect_overflows_that_wrap_around_the_modulus() {
+        let max = TestScalar::MAX_SIGNED;
+        let _ = CheckedScalar::new(max, 256) * CheckedScalar::new(max, 256);
+    }
+}
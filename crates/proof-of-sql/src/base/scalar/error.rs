@@ -11,3 +11,14 @@ pub enum ScalarConversionError {
         error: String,
     },
 }
+
+#[derive(Snafu, Debug, PartialEq, Eq)]
+/// These errors occur when parsing a scalar from a string fails.
+pub enum ScalarParseError {
+    #[snafu(display("Invalid hex string: expected 64 hex digits"))]
+    /// This error occurs when the string is not exactly 64 hex digits.
+    InvalidHex,
+    #[snafu(display("Value is not below the field modulus"))]
+    /// This error occurs when the parsed value is not a reduced field element.
+    OutOfRange,
+}
@@ -2,7 +2,7 @@
 mod scalar;
 pub use scalar::Scalar;
 mod error;
-pub use error::ScalarConversionError;
+pub use error::{ScalarConversionError, ScalarParseError};
 /// TODO add doc
 mod mont_scalar;
 #[cfg(test)]
@@ -14,6 +14,14 @@ pub mod test_scalar;
 #[cfg(test)]
 mod test_scalar_test;
 
+mod ntt;
+pub use ntt::{find_primitive_root_of_unity, intt, ntt};
+#[cfg(test)]
+mod ntt_test;
+
+mod checked_scalar;
+pub use checked_scalar::CheckedScalar;
+
 mod scalar_ext;
 #[cfg(test)]
 pub(crate) use scalar_ext::test_scalar_constants;
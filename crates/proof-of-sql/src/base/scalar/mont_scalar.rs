@@ -1,4 +1,7 @@
-use crate::base::scalar::{Scalar, ScalarConversionError};
+use crate::base::{
+    math::i256::I256,
+    scalar::{Scalar, ScalarConversionError},
+};
 use alloc::{
     format,
     string::{String, ToString},
@@ -207,6 +210,23 @@ impl<T: MontConfig<4>> MontScalar<T> {
     pub fn to_bytes_le(&self) -> Vec<u8> {
         self.0.into_bigint().to_bytes_le()
     }
+    /// Create a canonical 32-byte little-endian array from a `MontScalar<T>`. The array will be in non-montgomery form.
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 32] {
+        I256::new(self.0.into_bigint().0).to_le_bytes()
+    }
+    /// Create a new `MontScalar<T>` from a canonical 32-byte little-endian array in non-montgomery form.
+    ///
+    /// Returns `None` if the bytes represent an integer that is not less than the field modulus.
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        Fp::from_bigint(ark_ff::BigInt(I256::from_le_bytes(bytes).limbs())).map(Self)
+    }
+    /// Converts a `MontScalar<T>` into the canonical, non-montgomery field element representation used as a Poseidon hash input.
+    #[must_use]
+    pub fn to_poseidon_input(&self) -> ark_ff::BigInt<4> {
+        self.0.into_bigint()
+    }
     /// Convenience function for converting a slice of `ark_curve25519::Fr` into a vector of `Curve25519Scalar`. Should not be used outside of tests.
     #[cfg(test)]
     pub fn wrap_slice(slice: &[Fp256<MontBackend<T, 4>>]) -> Vec<Self> {
@@ -390,6 +410,20 @@ where
     const TWO: Self = Self(Fp::new(ark_ff::BigInt([2, 0, 0, 0])));
     const TEN: Self = Self(Fp::new(ark_ff::BigInt([10, 0, 0, 0])));
     const TWO_POW_64: Self = Self(Fp::new(ark_ff::BigInt([0, 1, 0, 0])));
+    const HALF: Self = {
+        // (p + 1) / 2 = (p - 1) / 2 + 1, since p is odd
+        let mut limbs = T::MODULUS.divide_by_2_round_down().0;
+        let mut i = 0;
+        while i < 4 {
+            let (sum, carry) = limbs[i].overflowing_add(1);
+            limbs[i] = sum;
+            if !carry {
+                break;
+            }
+            i += 1;
+        }
+        Self(Fp::new(ark_ff::BigInt(limbs)))
+    };
     const CHALLENGE_MASK: U256 = {
         assert!(
             T::MODULUS.0[3].leading_zeros() < 64,
@@ -410,6 +444,12 @@ where
         );
         255 - T::MODULUS.0[3].leading_zeros() as u8
     };
+    fn modulus() -> I256 {
+        I256::new(T::MODULUS.0)
+    }
+    fn sqrt(&self) -> Option<Self> {
+        self.0.sqrt().map(Self)
+    }
 }
 
 impl<T> TryFrom<MontScalar<T>> for bool
@@ -470,6 +510,32 @@ where
     }
 }
 
+impl<T> TryFrom<MontScalar<T>> for u64
+where
+    T: MontConfig<4>,
+    MontScalar<T>: Scalar,
+{
+    type Error = ScalarConversionError;
+
+    fn try_from(value: MontScalar<T>) -> Result<Self, Self::Error> {
+        if value < MontScalar::<T>::ZERO {
+            return Err(ScalarConversionError::Overflow {
+                error: format!("{value} is negative and cannot fit in a u64"),
+            });
+        }
+
+        let abs: [u64; 4] = value.into();
+
+        if abs[1] != 0 || abs[2] != 0 || abs[3] != 0 {
+            return Err(ScalarConversionError::Overflow {
+                error: format!("{value} is too large to fit in a u64"),
+            });
+        }
+
+        Ok(abs[0])
+    }
+}
+
 impl<T> TryFrom<MontScalar<T>> for i8
 where
     T: MontConfig<4>,
@@ -1,10 +1,16 @@
-use crate::base::scalar::{
-    test_scalar::{TestMontConfig, TestScalar},
-    Scalar, ScalarConversionError,
+use super::scalar::sqrt_by_tonelli_shanks;
+use crate::{
+    base::scalar::{
+        test_scalar::{TestMontConfig, TestScalar},
+        Scalar, ScalarConversionError,
+    },
+    proof_primitive::{dory::DoryScalar, hyperkzg::BNScalar},
 };
 use ark_ff::MontConfig;
+use ark_std::UniformRand;
 use bnum::types::U256;
 use num_bigint::BigInt;
+use num_traits::Inv;
 #[test]
 fn test_bigint_to_scalar_overflow() {
     assert_eq!(
@@ -52,3 +58,116 @@ fn we_can_bound_modulus_using_max_bits() {
     assert!(modulus_of_i_max_bits <= modulus_of_test_scalar);
     assert!(modulus_of_i_max_bits_plus_1 > modulus_of_test_scalar);
 }
+
+#[test]
+fn we_can_convert_scalars_to_poseidon_inputs() {
+    use ark_ff::BigInteger;
+    assert_eq!(
+        TestScalar::from(123).to_poseidon_input(),
+        ark_ff::BigInt([123, 0, 0, 0])
+    );
+    assert_eq!(
+        DoryScalar::from(123).to_poseidon_input(),
+        ark_ff::BigInt([123, 0, 0, 0])
+    );
+    assert_eq!(
+        (-TestScalar::ONE).to_poseidon_input().to_bytes_le(),
+        (-TestScalar::ONE).to_bytes_le()
+    );
+}
+
+#[test]
+fn we_can_evaluate_affine_functions() {
+    let (a, b, x) = (
+        TestScalar::from(3),
+        TestScalar::from(-7),
+        TestScalar::from(5),
+    );
+    assert_eq!(TestScalar::affine_eval(&a, &b, &x), TestScalar::from(8));
+    assert_eq!(
+        TestScalar::affine_eval(&TestScalar::ZERO, &b, &x),
+        TestScalar::from(-7)
+    );
+    assert_eq!(
+        TestScalar::affine_eval(&TestScalar::MAX_SIGNED, &TestScalar::ONE, &TestScalar::TWO),
+        TestScalar::ZERO
+    );
+}
+
+#[test]
+fn we_can_raise_scalars_to_a_power() {
+    let x = TestScalar::from(-3);
+    assert_eq!(TestScalar::ZERO.pow(0), TestScalar::ONE);
+    assert_eq!(TestScalar::ZERO.pow(5), TestScalar::ZERO);
+    assert_eq!(TestScalar::ONE.pow(u64::MAX), TestScalar::ONE);
+    assert_eq!(x.pow(0), TestScalar::ONE);
+    assert_eq!(x.pow(1), x);
+    assert_eq!(x.pow(2), x * x);
+    assert_eq!(x.pow(5), TestScalar::from(-243));
+    assert_eq!(TestScalar::TWO.pow(64), TestScalar::TWO_POW_64);
+    // MAX_SIGNED is -1/2, so its square is 1/4
+    assert_eq!(
+        TestScalar::MAX_SIGNED.pow(2),
+        TestScalar::from(4).inv().unwrap()
+    );
+}
+
+#[test]
+fn we_can_compute_square_roots_of_scalars() {
+    assert_eq!(TestScalar::ZERO.sqrt(), Some(TestScalar::ZERO));
+    let one = TestScalar::ONE.sqrt().unwrap();
+    assert!(one == TestScalar::ONE || one == -TestScalar::ONE);
+    let two = TestScalar::from(4).sqrt().unwrap();
+    assert!(two == TestScalar::TWO || two == -TestScalar::TWO);
+    // 2 is not a quadratic residue modulo the curve25519 group order
+    assert_eq!(TestScalar::TWO.sqrt(), None);
+    assert_eq!(TestScalar::from(8).sqrt(), None);
+
+    let mut rng = ark_std::test_rng();
+    for _ in 0..10 {
+        let square = TestScalar::rand(&mut rng).pow(2);
+        let root = square.sqrt().unwrap();
+        assert_eq!(root * root, square);
+    }
+}
+
+fn assert_tonelli_shanks_agrees_with_sqrt<S: Scalar>() {
+    assert_eq!(sqrt_by_tonelli_shanks(S::ZERO), Some(S::ZERO));
+    let mut rng = ark_std::test_rng();
+    for _ in 0..20 {
+        let value = S::rand(&mut rng);
+        let root = sqrt_by_tonelli_shanks(value);
+        assert_eq!(root.is_some(), value.sqrt().is_some());
+        if let Some(root) = root {
+            assert_eq!(root.pow(2), value);
+        }
+        let square = value.pow(2);
+        assert_eq!(sqrt_by_tonelli_shanks(square).unwrap().pow(2), square);
+    }
+}
+
+#[test]
+fn we_can_compute_square_roots_with_tonelli_shanks() {
+    // The fields have two-adicities 2, 32 and 28
+    assert_tonelli_shanks_agrees_with_sqrt::<TestScalar>();
+    assert_tonelli_shanks_agrees_with_sqrt::<DoryScalar>();
+    assert_tonelli_shanks_agrees_with_sqrt::<BNScalar>();
+    assert_eq!(sqrt_by_tonelli_shanks(TestScalar::TWO), None);
+}
+
+#[test]
+fn we_can_evaluate_affine_functions_in_batch() {
+    let a = [1, 2, -3].map(TestScalar::from);
+    let b = [0, 10, 4].map(TestScalar::from);
+    assert_eq!(
+        TestScalar::batch_affine_eval(&a, &b, &TestScalar::from(2)),
+        [2, 14, -2].map(TestScalar::from)
+    );
+    assert!(TestScalar::batch_affine_eval(&[], &[], &TestScalar::ONE).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "the slopes and intercepts must have the same length")]
+fn we_cannot_evaluate_affine_functions_in_batch_with_mismatched_lengths() {
+    let _ = TestScalar::batch_affine_eval(&[TestScalar::ONE], &[], &TestScalar::ONE);
+}
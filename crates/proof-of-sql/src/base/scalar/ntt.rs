@@ -0,0 +1,113 @@
+//! The number theoretic transform, i.e. the discrete Fourier transform over a prime field.
+use super::{
+    scalar::{multiplicative_group_order, pow_limbs},
+    Scalar,
+};
+use crate::base::math::i256::I256;
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+use num_traits::{Inv, Zero};
+
+/// Returns the distinct prime factors of `n`.
+fn prime_factors(mut n: usize) -> Vec<usize> {
+    let mut factors = Vec::new();
+    let mut candidate = 2;
+    while candidate * candidate <= n {
+        if n % candidate == 0 {
+            factors.push(candidate);
+            while n % candidate == 0 {
+                n /= candidate;
+            }
+        }
+        candidate += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+/// Returns a primitive `n`-th root of unity, i.e. an element of multiplicative order exactly `n`.
+///
+/// Returns `None` if there is no such element, i.e. if `n` is zero or does not divide `p - 1`.
+#[must_use]
+pub fn find_primitive_root_of_unity<S: Scalar>(n: usize) -> Option<S> {
+    let group_order =
+        BigUint::from_bytes_le(&I256::new(multiplicative_group_order::<S>()).to_le_bytes());
+    if n == 0 || !(&group_order % n).is_zero() {
+        return None;
+    }
+    let cofactor = (group_order / n).to_u64_digits();
+    let maximal_proper_divisors: Vec<Vec<u64>> = prime_factors(n)
+        .into_iter()
+        .map(|factor| BigUint::from(n / factor).to_u64_digits())
+        .collect();
+    // `g^cofactor` has order dividing `n` for every `g`, and exactly `n` for a `phi(n) / n` fraction of all `g`.
+    (2u64..)
+        .map(|g| pow_limbs(S::from(g), &cofactor))
+        .find(|root| {
+            maximal_proper_divisors
+                .iter()
+                .all(|divisor| pow_limbs(*root, divisor) != S::ONE)
+        })
+}
+
+/// Computes the number theoretic transform of `values` in place.
+///
+/// That is, `values[i]` is replaced by `sum_j values[j] * omega^(i * j)`, which is the evaluation at `omega^i`
+/// of the polynomial with coefficients `values`. `omega` must be a primitive `n`-th root of unity, where `n` is the
+/// length of `values`, e.g. the result of [`find_primitive_root_of_unity`].
+///
+/// # Panics
+/// Panics if the length of `values` is not a power of two.
+pub fn ntt<S: Scalar>(values: &mut [S], omega: &S) {
+    let n = values.len();
+    assert!(
+        n.is_power_of_two(),
+        "the length of the values must be a power of two"
+    );
+    if n == 1 {
+        return;
+    }
+    let log_n = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - log_n);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut half_len = 1;
+    while half_len < n {
+        // omega^(n / (2 * half_len)) is a primitive (2 * half_len)-th root of unity
+        let step = pow(*omega, &BigUint::from(n / (2 * half_len)));
+        for chunk in values.chunks_exact_mut(2 * half_len) {
+            let (low, high) = chunk.split_at_mut(half_len);
+            let mut twiddle = S::ONE;
+            for (a, b) in low.iter_mut().zip(high) {
+                let mut t = *b;
+                t *= twiddle;
+                *b = *a - t;
+                *a += t;
+                twiddle *= step;
+            }
+        }
+        half_len *= 2;
+    }
+}
+
+/// Computes the inverse of [`ntt`] in place, i.e. recovers the coefficients of a polynomial from its evaluations
+/// at the powers of `omega`.
+///
+/// # Panics
+/// Panics if the length of `values` is not a power of two.
+pub fn intt<S: Scalar>(values: &mut [S], omega: &S) {
+    let omega_inv = omega.inv().expect("a root of unity is nonzero");
+    ntt(values, &omega_inv);
+    let n_inv = S::from(values.len() as u64)
+        .inv()
+        .expect("the length is a power of two smaller than the characteristic");
+    for value in values.iter_mut() {
+        *value *= n_inv;
+    }
+}
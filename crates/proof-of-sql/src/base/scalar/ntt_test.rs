@@ -0,0 +1,116 @@
+use super::{find_primitive_root_of_unity, intt, ntt, test_scalar::TestScalar, Scalar};
+use crate::proof_primitive::dory::DoryScalar;
+
+fn power<S: Scalar>(base: S, exponent: usize) -> S {
+    itertools::repeat_n(base, exponent).product()
+}
+
+fn naive_polynomial_product<S: Scalar>(a: &[S], b: &[S]) -> Vec<S> {
+    let mut product = vec![S::ZERO; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            let mut term = x;
+            term *= y;
+            product[i + j] += term;
+        }
+    }
+    product
+}
+
+#[test]
+fn we_can_find_primitive_roots_of_unity() {
+    for n in [1, 2, 3, 4, 8, 12, 1 << 10] {
+        let root = find_primitive_root_of_unity::<DoryScalar>(n).unwrap();
+        assert_eq!(power(root, n), DoryScalar::ONE);
+        assert!((1..n).all(|k| power(root, k) != DoryScalar::ONE));
+    }
+    // The scalar field of BLS12-381 has a two-adicity of 32
+    let root = find_primitive_root_of_unity::<DoryScalar>(1 << 32).unwrap();
+    let square = |x: DoryScalar| {
+        let mut y = x;
+        y *= x;
+        y
+    };
+    let half_order_power = (0..31).fold(root, |x, _| square(x));
+    assert_eq!(half_order_power, -DoryScalar::ONE);
+    assert_eq!(square(half_order_power), DoryScalar::ONE);
+}
+
+#[test]
+fn we_cannot_find_roots_of_unity_whose_order_does_not_divide_the_group_order() {
+    assert_eq!(find_primitive_root_of_unity::<DoryScalar>(0), None);
+    assert_eq!(find_primitive_root_of_unity::<DoryScalar>(1 << 33), None);
+    assert_eq!(find_primitive_root_of_unity::<DoryScalar>(5), None);
+    // The scalar field of curve25519 has only a two-adicity of 2
+    assert!(find_primitive_root_of_unity::<TestScalar>(4).is_some());
+    assert_eq!(find_primitive_root_of_unity::<TestScalar>(8), None);
+}
+
+#[test]
+fn we_can_compute_the_ntt_as_evaluations_at_powers_of_the_root() {
+    let coefficients: Vec<DoryScalar> = [3, -1, 4, 1, -5, 9, 2, 6]
+        .into_iter()
+        .map(DoryScalar::from)
+        .collect();
+    let omega = find_primitive_root_of_unity::<DoryScalar>(8).unwrap();
+    let mut values = coefficients.clone();
+    ntt(&mut values, &omega);
+    for (i, value) in values.iter().enumerate() {
+        let point = power(omega, i);
+        let expected: DoryScalar = coefficients
+            .iter()
+            .enumerate()
+            .map(|(j, &c)| {
+                let mut term = c;
+                term *= power(point, j);
+                term
+            })
+            .sum();
+        assert_eq!(*value, expected);
+    }
+    intt(&mut values, &omega);
+    assert_eq!(values, coefficients);
+}
+
+#[test]
+fn we_can_multiply_polynomials_with_the_ntt() {
+    let a: Vec<DoryScalar> = [1, 2, 3, -4].into_iter().map(DoryScalar::from).collect();
+    let b: Vec<DoryScalar> = [7, 0, -1].into_iter().map(DoryScalar::from).collect();
+    let omega = find_primitive_root_of_unity::<DoryScalar>(8).unwrap();
+    let mut a_values = a.clone();
+    a_values.resize(8, DoryScalar::ZERO);
+    let mut b_values = b.clone();
+    b_values.resize(8, DoryScalar::ZERO);
+    ntt(&mut a_values, &omega);
+    ntt(&mut b_values, &omega);
+    let mut product: Vec<DoryScalar> = a_values
+        .into_iter()
+        .zip(b_values)
+        .map(|(mut x, y)| {
+            x *= y;
+            x
+        })
+        .collect();
+    intt(&mut product, &omega);
+    let expected = naive_polynomial_product(&a, &b);
+    assert_eq!(product[..expected.len()], expected[..]);
+    assert!(product[expected.len()..]
+        .iter()
+        .all(|c| *c == DoryScalar::ZERO));
+}
+
+#[test]
+fn we_can_transform_a_single_value() {
+    let mut values = [DoryScalar::from(5)];
+    ntt(&mut values, &DoryScalar::ONE);
+    assert_eq!(values, [DoryScalar::from(5)]);
+    intt(&mut values, &DoryScalar::ONE);
+    assert_eq!(values, [DoryScalar::from(5)]);
+}
+
+#[test]
+#[should_panic(expected = "the length of the values must be a power of two")]
+fn we_cannot_transform_values_whose_length_is_not_a_power_of_two() {
+    let omega = find_primitive_root_of_unity::<DoryScalar>(3).unwrap();
+    ntt(&mut [DoryScalar::ONE; 3], &omega);
+}
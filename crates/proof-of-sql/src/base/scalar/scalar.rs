@@ -1,7 +1,9 @@
 #![allow(clippy::module_inception)]
 
-use crate::base::{encode::VarInt, ref_into::RefInto, scalar::ScalarConversionError};
-use alloc::string::String;
+use crate::base::{
+    encode::VarInt, math::i256::I256, ref_into::RefInto, scalar::ScalarConversionError,
+};
+use alloc::{string::String, vec::Vec};
 use bnum::types::U256;
 use core::ops::Sub;
 use num_bigint::BigInt;
@@ -35,6 +37,7 @@ pub trait Scalar:
     + for<'a> core::convert::From<&'a u64> // Required for `Column` to implement `MultilinearExtension`
     + core::convert::TryInto <bool>
     + core::convert::TryInto<u8>
+    + core::convert::TryInto<u64>
     + core::convert::TryInto <i8>
     + core::convert::TryInto <i16>
     + core::convert::TryInto <i32>
@@ -78,9 +81,147 @@ pub trait Scalar:
     const TEN: Self;
     /// 2^64
     const TWO_POW_64: Self;
+    /// The inverse of 2, which is (p + 1) / 2. Multiplying by this divides by 2 in the field.
+    const HALF: Self;
     /// The value to mask the challenge with to ensure it is in the field.
     /// This one less than the largest power of 2 that is less than the field modulus.
     const CHALLENGE_MASK: U256;
     /// The largest n such that 2^n <=p
     const MAX_BITS: u8;
+    /// The field modulus p, as an [`I256`].
+    ///
+    /// Note: converting this value back into `Self` yields zero.
+    fn modulus() -> I256;
+
+    /// Returns a square root of the element, or `None` if it is not a quadratic residue.
+    ///
+    /// Nonzero residues have two square roots, `r` and `-r`, and either may be returned.
+    /// The default implementation uses the Tonelli-Shanks algorithm. Implementations may override it with a
+    /// faster algorithm for their field.
+    #[must_use]
+    fn sqrt(&self) -> Option<Self> {
+        sqrt_by_tonelli_shanks(*self)
+    }
+
+    /// Evaluates the affine function `a * x + b`.
+    ///
+    /// Implementations may override this with a fused multiply-add.
+    #[must_use]
+    fn affine_eval(a: &Self, b: &Self, x: &Self) -> Self {
+        let mut result = *a;
+        result *= *x;
+        result += *b;
+        result
+    }
+
+    /// Computes `self^exp` by square-and-multiply. By convention `0^0` is `1`.
+    ///
+    /// The running time depends on the bits of `exp`. Implementations may override this with a constant-time
+    /// exponentiation.
+    #[must_use]
+    fn pow(self, exp: u64) -> Self {
+        pow_limbs(self, &[exp])
+    }
+
+    /// Evaluates the affine functions `a[i] * x + b[i]` at the same `x`.
+    ///
+    /// # Panics
+    /// Panics if `a` and `b` have different lengths.
+    #[must_use]
+    fn batch_affine_eval(a: &[Self], b: &[Self], x: &Self) -> Vec<Self> {
+        assert_eq!(
+            a.len(),
+            b.len(),
+            "the slopes and intercepts must have the same length"
+        );
+        a.iter()
+            .zip(b)
+            .map(|(a, b)| Self::affine_eval(a, b, x))
+            .collect()
+    }
+}
+
+fn square<S: Scalar>(value: S) -> S {
+    let mut result = value;
+    result *= value;
+    result
+}
+
+/// Divides a number given as little-endian `u64` limbs by 2, rounding down.
+fn halve_limbs(limbs: [u64; 4]) -> [u64; 4] {
+    [
+        (limbs[0] >> 1) | (limbs[1] << 63),
+        (limbs[1] >> 1) | (limbs[2] << 63),
+        (limbs[2] >> 1) | (limbs[3] << 63),
+        limbs[3] >> 1,
+    ]
+}
+
+/// Computes `base^exp` by square-and-multiply, for an exponent given as little-endian `u64` limbs.
+pub(super) fn pow_limbs<S: Scalar>(base: S, exp: &[u64]) -> S {
+    let bits = exp.iter().rposition(|&limb| limb != 0).map_or(0, |i| {
+        i * 64 + (u64::BITS - exp[i].leading_zeros()) as usize
+    });
+    (0..bits).rev().fold(S::ONE, |acc, i| {
+        let mut result = square(acc);
+        if (exp[i / 64] >> (i % 64)) & 1 == 1 {
+            result *= base;
+        }
+        result
+    })
+}
+
+/// Returns `p - 1`, the order of the multiplicative group of the field, as little-endian `u64` limbs.
+pub(super) fn multiplicative_group_order<S: Scalar>() -> [u64; 4] {
+    // The modulus is an odd prime, so subtracting 1 never borrows.
+    let mut limbs = S::modulus().limbs();
+    limbs[0] -= 1;
+    limbs
+}
+
+/// Computes a square root of `value` with the Tonelli-Shanks algorithm, or `None` if it is not a quadratic residue.
+pub(super) fn sqrt_by_tonelli_shanks<S: Scalar>(value: S) -> Option<S> {
+    if value == S::ZERO {
+        return Some(S::ZERO);
+    }
+    // Write p - 1 = odd * 2^two_adicity.
+    let mut odd = multiplicative_group_order::<S>();
+    let half_p_minus_one = halve_limbs(odd);
+    let mut two_adicity = 0;
+    while odd[0] & 1 == 0 {
+        odd = halve_limbs(odd);
+        two_adicity += 1;
+    }
+    // By Euler's criterion, x^((p - 1) / 2) is 1 for nonzero residues and -1 for non-residues.
+    if pow_limbs(value, &half_p_minus_one) != S::ONE {
+        return None;
+    }
+    let non_residue = core::iter::successors(Some(S::TWO), |z| {
+        let mut next = *z;
+        next += S::ONE;
+        Some(next)
+    })
+    .find(|z| pow_limbs(*z, &half_p_minus_one) != S::ONE)?;
+
+    // Invariants: root^2 = value * t, t^(2^(m - 1)) = 1 and c^(2^(m - 1)) = -1.
+    let mut m = two_adicity;
+    let mut c = pow_limbs(non_residue, &odd);
+    let mut t = pow_limbs(value, &odd);
+    let mut root = pow_limbs(value, &halve_limbs(odd));
+    root *= value;
+    while t != S::ONE {
+        // The least i such that t^(2^i) = 1, which is below m.
+        let mut i = 0;
+        let mut t_pow = t;
+        while t_pow != S::ONE {
+            t_pow = square(t_pow);
+            i += 1;
+        }
+        let b = (i + 1..m).fold(c, |b, _| square(b));
+        m = i;
+        c = square(b);
+        t *= c;
+        root *= b;
+    }
+    Some(root)
 }
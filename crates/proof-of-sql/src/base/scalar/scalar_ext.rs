@@ -1,8 +1,19 @@
-use super::Scalar;
+use super::{Scalar, ScalarParseError};
+use crate::base::{
+    hex::{decode_hex, encode_hex},
+    math::i256::I256,
+    slice_ops,
+};
+use alloc::{string::String, vec::Vec};
 use bnum::types::U256;
 use core::cmp::Ordering;
 use tiny_keccak::Hasher;
 
+/// Returns the modulus of the field of `S` as a [`U256`].
+fn modulus_u256<S: Scalar>() -> U256 {
+    U256::from(S::modulus().limbs())
+}
+
 /// Extension trait for blanket implementations for `Scalar` types.
 /// This trait is primarily to avoid cluttering the core `Scalar` implementation with default implementations
 /// and provides helper methods for `Scalar`.
@@ -20,6 +31,122 @@ pub trait ScalarExt: Scalar {
         }
     }
 
+    /// Returns `true` if `lo <= self <= hi`, comparing the `Scalar`s as signed numbers.
+    ///
+    /// Like [`ScalarExt::signed_cmp`], this assumes the differences between the values are at most [`Scalar::MAX_SIGNED`].
+    fn is_in_range(&self, lo: &Self, hi: &Self) -> bool {
+        self.signed_cmp(lo) != Ordering::Less && self.signed_cmp(hi) != Ordering::Greater
+    }
+
+    /// Computes the witnesses `(self - lo, hi - self)` for the range check `lo <= self <= hi`.
+    /// Both witnesses are non-negative when the check holds. Returns `None` if `self` is out of range.
+    fn range_proof_witness(&self, lo: &Self, hi: &Self) -> Option<(Self, Self)> {
+        self.is_in_range(lo, hi).then(|| (*self - *lo, *hi - *self))
+    }
+
+    /// Computes the lookup table `[0, self, 2 * self, ..., n * self]` using `n` additions.
+    fn precompute_multiples(&self, n: usize) -> Vec<Self> {
+        let mut multiples = Vec::with_capacity(n + 1);
+        let mut multiple = Self::ZERO;
+        multiples.push(multiple);
+        for _ in 0..n {
+            multiple += *self;
+            multiples.push(multiple);
+        }
+        multiples
+    }
+
+    #[must_use]
+    /// Computes the inclusive prefix sums of `values`, i.e. `result[i] = values[0] + ... + values[i]`.
+    ///
+    /// With the `rayon` feature, this uses a parallel prefix sum.
+    fn prefix_sum(values: &[Self]) -> Vec<Self> {
+        slice_ops::prefix_sum(values)
+    }
+
+    #[must_use]
+    /// Computes the inclusive suffix sums of `values`, i.e. `result[i] = values[i] + ... + values[n - 1]`.
+    ///
+    /// With the `rayon` feature, this uses a parallel prefix sum.
+    fn suffix_sum(values: &[Self]) -> Vec<Self> {
+        slice_ops::suffix_sum(values)
+    }
+
+    #[must_use]
+    /// Computes the fingerprint `(values[0] - challenge) * (values[1] - challenge) * ...` of a multiset.
+    ///
+    /// This is the core of permutation and lookup arguments: two multisets are equal exactly when their
+    /// fingerprints are equal as polynomials in `challenge`, so by the Schwartz-Zippel lemma, distinct
+    /// multisets of size `n` have the same fingerprint for a random `challenge` with probability at most `n / |F|`.
+    fn multiset_hash(values: &[Self], challenge: &Self) -> Self {
+        values.iter().map(|value| *value - *challenge).product()
+    }
+
+    #[must_use]
+    /// Computes `p - self` as an integer, where `p` is the modulus of the field.
+    ///
+    /// Unlike the field negation, this maps zero to `p` rather than zero, so the result is always in `1..=p`.
+    /// This is used as a non-negative witness in range proofs.
+    fn neg_partial_mod(&self) -> I256 {
+        I256::new((modulus_u256::<Self>() - self.into_u256_wrapping()).into())
+    }
+
+    #[must_use]
+    /// Encodes the 256-bit integer `hi * 2^128 + lo` as a pair of scalars `(high, low)`.
+    ///
+    /// `high` is `hi`, which carries the sign, and `low` is the bits of `lo` read as an unsigned integer in `0..2^128`.
+    /// In particular, `high * 2^128 + low` is the encoded value whenever its absolute value is below [`Scalar::MAX_SIGNED`].
+    /// This is the inverse of [`ScalarExt::unpack_two_i128`].
+    fn pack_two_i128(hi: i128, lo: i128) -> (Self, Self) {
+        let lo_bits = u128::from_ne_bytes(lo.to_ne_bytes());
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "The low and high 64 bits are split off deliberately"
+        )]
+        let low = Self::from([lo_bits as u64, (lo_bits >> 64) as u64, 0, 0]);
+        (Self::from(hi), low)
+    }
+
+    /// Decodes a pair of scalars produced by [`ScalarExt::pack_two_i128`] into `(hi, lo)`.
+    ///
+    /// Returns `None` if `high` is not an `i128` or `low` is not in `0..2^128`.
+    fn unpack_two_i128(high: &Self, low: &Self) -> Option<(i128, i128)> {
+        let hi: i128 = (*high).try_into().ok()?;
+        let [limb0, limb1, 0, 0]: [u64; 4] = (*low).into() else {
+            return None;
+        };
+        let lo_bits = u128::from(limb0) | (u128::from(limb1) << 64);
+        Some((hi, i128::from_ne_bytes(lo_bits.to_ne_bytes())))
+    }
+
+    /// Encodes a nanosecond timestamp, i.e. nanoseconds since the unix epoch, as a Scalar without loss.
+    ///
+    /// Unlike the `i64` encoding used by `TimestampTZ` columns, this covers instants outside of 1677-2262.
+    /// This is the inverse of [`ScalarExt::to_timestamp_nanos`].
+    fn from_timestamp_nanos(ts: i128) -> Self {
+        Self::from(ts)
+    }
+
+    /// Decodes a nanosecond timestamp produced by [`ScalarExt::from_timestamp_nanos`].
+    ///
+    /// Returns `None` if the Scalar is not an `i128`.
+    fn to_timestamp_nanos(&self) -> Option<i128> {
+        (*self).try_into().ok()
+    }
+
+    /// Converts a big-endian bit string, such as the output of a hash function, to a Scalar.
+    ///
+    /// The bits are read as an unsigned integer with the most significant bit first. Leading zeros are allowed,
+    /// so the slice may be longer than 256 bits. Returns `None` if the integer is not below the modulus.
+    fn from_bits_be(bits: &[bool]) -> Option<Self> {
+        let modulus = modulus_u256::<Self>();
+        let value = bits.iter().try_fold(U256::ZERO, |acc, &bit| {
+            let value = acc.checked_mul(U256::from(2u8))? | U256::from(u8::from(bit));
+            (value < modulus).then_some(value)
+        })?;
+        Some(Self::from_wrapping(value))
+    }
+
     #[must_use]
     /// Converts a U256 to Scalar, wrapping as needed
     fn from_wrapping(value: U256) -> Self {
@@ -32,6 +159,42 @@ pub trait ScalarExt: Scalar {
         U256::from(Into::<[u64; 4]>::into(self))
     }
 
+    /// Returns the canonical 32-byte encoding of the Scalar, i.e. its limbs as little-endian bytes, least
+    /// significant limb first.
+    fn to_canonical_bytes(&self) -> [u8; 32] {
+        I256::new((*self).into()).to_le_bytes()
+    }
+
+    /// Decodes the output of [`ScalarExt::to_canonical_bytes`].
+    ///
+    /// Returns `None` if the bytes encode a value that is not below the modulus.
+    fn from_canonical_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let value = U256::from(I256::from_le_bytes(bytes).limbs());
+        (value < modulus_u256::<Self>()).then(|| Self::from_wrapping(value))
+    }
+
+    /// Returns the Scalar as 64 lowercase hex digits, most significant digit first.
+    ///
+    /// These are the bytes of [`ScalarExt::to_canonical_bytes`] in reverse order.
+    fn to_hex_string(&self) -> String {
+        let mut bytes = self.to_canonical_bytes();
+        bytes.reverse();
+        encode_hex(&bytes)
+    }
+
+    /// Parses the output of [`ScalarExt::to_hex_string`]. Uppercase hex digits are also accepted.
+    ///
+    /// # Errors
+    /// Returns [`ScalarParseError::InvalidHex`] if `s` is not exactly 64 hex digits and
+    /// [`ScalarParseError::OutOfRange`] if the value is not below the modulus.
+    fn from_hex_str(s: &str) -> Result<Self, ScalarParseError> {
+        let mut bytes: [u8; 32] = decode_hex(s)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(ScalarParseError::InvalidHex)?;
+        bytes.reverse();
+        Self::from_canonical_bytes(&bytes).ok_or(ScalarParseError::OutOfRange)
+    }
+
     /// Converts a byte slice to a Scalar using a hash function, preventing collisions.
     /// WARNING: Only up to 31 bytes (2^248 bits) are supported by `PoSQL` cryptographic
     /// objects. This function masks off the last byte of the hash to ensure the result
@@ -63,6 +226,9 @@ pub(crate) fn test_scalar_constants<S: Scalar>() {
     // -1/2 == least upper bound
     assert_eq!(-S::TWO.inv().unwrap(), S::MAX_SIGNED);
     assert_eq!(S::from(10), S::TEN);
+    assert_eq!(S::TWO.inv().unwrap(), S::HALF);
+    assert_eq!(S::HALF * S::TWO, S::ONE);
+    assert_eq!(S::MAX_SIGNED + S::ONE, S::HALF);
 
     // Check the challenge mask
     assert_eq!(
@@ -71,6 +237,10 @@ pub(crate) fn test_scalar_constants<S: Scalar>() {
     );
     assert!(S::MAX_SIGNED.into_u256_wrapping() < S::CHALLENGE_MASK);
     assert!((-S::ONE).into_u256_wrapping() > S::CHALLENGE_MASK);
+
+    // The modulus is congruent to zero in the field
+    assert_eq!(S::modulus().into_scalar::<S>(), S::ZERO);
+    assert_eq!(S::ONE + S::modulus().into_scalar::<S>(), S::ONE);
 }
 
 #[cfg(test)]
@@ -139,4 +309,341 @@ mod tests {
         assert_eq!((two * max).signed_cmp(&zero), Ordering::Less);
         assert_eq!(two * max + one, zero);
     }
+
+    #[test]
+    fn we_can_check_if_a_scalar_is_in_range() {
+        let lo = -TestScalar::TWO;
+        let hi = TestScalar::TEN;
+        for x in [-2, 0, 5, 10] {
+            assert!(TestScalar::from(x).is_in_range(&lo, &hi));
+        }
+        for x in [-3, 11] {
+            assert!(!TestScalar::from(x).is_in_range(&lo, &hi));
+        }
+        assert!(!TestScalar::MAX_SIGNED.is_in_range(&lo, &hi));
+        assert!(TestScalar::ONE.is_in_range(&TestScalar::ONE, &TestScalar::ONE));
+    }
+
+    #[test]
+    fn we_can_compute_range_proof_witnesses() {
+        let lo = -TestScalar::TWO;
+        let hi = TestScalar::TEN;
+        assert_eq!(
+            TestScalar::from(3).range_proof_witness(&lo, &hi),
+            Some((TestScalar::from(5), TestScalar::from(7)))
+        );
+        assert_eq!(
+            lo.range_proof_witness(&lo, &hi),
+            Some((TestScalar::ZERO, TestScalar::from(12)))
+        );
+        assert_eq!(TestScalar::from(11).range_proof_witness(&lo, &hi), None);
+        assert_eq!(TestScalar::from(-3).range_proof_witness(&lo, &hi), None);
+    }
+
+    #[test]
+    fn we_can_precompute_multiples_of_a_scalar() {
+        let s = TestScalar::from(7);
+        assert_eq!(s.precompute_multiples(0), vec![TestScalar::ZERO]);
+        let multiples = s.precompute_multiples(100);
+        assert_eq!(multiples.len(), 101);
+        for (i, multiple) in multiples.into_iter().enumerate() {
+            assert_eq!(multiple, s * TestScalar::from(i as u64));
+        }
+        assert_eq!(
+            (-TestScalar::ONE).precompute_multiples(2),
+            vec![TestScalar::ZERO, -TestScalar::ONE, -TestScalar::TWO]
+        );
+    }
+
+    #[test]
+    fn we_can_compute_prefix_and_suffix_sums_of_scalars() {
+        assert!(TestScalar::prefix_sum(&[]).is_empty());
+        assert!(TestScalar::suffix_sum(&[]).is_empty());
+        let values: Vec<TestScalar> = [3, -1, 4].into_iter().map(TestScalar::from).collect();
+        assert_eq!(
+            TestScalar::prefix_sum(&values),
+            vec![
+                TestScalar::from(3),
+                TestScalar::from(2),
+                TestScalar::from(6)
+            ]
+        );
+        assert_eq!(
+            TestScalar::suffix_sum(&values),
+            vec![
+                TestScalar::from(6),
+                TestScalar::from(3),
+                TestScalar::from(4)
+            ]
+        );
+    }
+
+    #[test]
+    fn we_can_compute_the_multiset_hash_of_scalars() {
+        let challenge = TestScalar::from(10);
+        assert_eq!(TestScalar::multiset_hash(&[], &challenge), TestScalar::ONE);
+        assert_eq!(
+            TestScalar::multiset_hash(&[TestScalar::from(3), TestScalar::from(12)], &challenge),
+            TestScalar::from(-14)
+        );
+        let values: Vec<TestScalar> = [5, -2, 7, 5, 0].into_iter().map(TestScalar::from).collect();
+        let permuted: Vec<TestScalar> =
+            [0, 5, 5, -2, 7].into_iter().map(TestScalar::from).collect();
+        let different: Vec<TestScalar> =
+            [0, 5, 7, -2, 7].into_iter().map(TestScalar::from).collect();
+        assert_eq!(
+            TestScalar::multiset_hash(&values, &challenge),
+            TestScalar::multiset_hash(&permuted, &challenge)
+        );
+        assert_ne!(
+            TestScalar::multiset_hash(&values, &challenge),
+            TestScalar::multiset_hash(&different, &challenge)
+        );
+        assert_eq!(
+            TestScalar::multiset_hash(&values, &TestScalar::from(7)),
+            TestScalar::ZERO
+        );
+    }
+
+    #[test]
+    fn we_can_compute_the_modulus_minus_a_scalar() {
+        assert_eq!(TestScalar::ZERO.neg_partial_mod(), TestScalar::modulus());
+        assert_eq!(
+            TestScalar::ONE.neg_partial_mod(),
+            I256::new([
+                0x5812_631a_5cf5_d3ec,
+                0x14de_f9de_a2f7_9cd6,
+                0,
+                0x1000_0000_0000_0000
+            ])
+        );
+        assert_eq!(
+            TestScalar::ONE
+                .neg_partial_mod()
+                .into_scalar::<TestScalar>(),
+            -TestScalar::ONE
+        );
+        assert_eq!((-TestScalar::ONE).neg_partial_mod(), I256::from(1));
+        assert_eq!(
+            TestScalar::MAX_SIGNED
+                .neg_partial_mod()
+                .into_scalar::<TestScalar>(),
+            -TestScalar::MAX_SIGNED
+        );
+    }
+
+    #[test]
+    fn we_can_pack_and_unpack_two_i128s() {
+        for (hi, lo) in [
+            (0, 0),
+            (1, -1),
+            (-1, 1),
+            (i128::MAX, i128::MIN),
+            (i128::MIN, i128::MAX),
+            (-123_456_789, 987_654_321),
+        ] {
+            let (high, low) = TestScalar::pack_two_i128(hi, lo);
+            assert_eq!(high, TestScalar::from(hi));
+            assert_eq!(TestScalar::unpack_two_i128(&high, &low), Some((hi, lo)));
+        }
+        // The low scalar is the unsigned reading of the low bits
+        assert_eq!(
+            TestScalar::pack_two_i128(0, -1).1,
+            TestScalar::from(u128::MAX)
+        );
+    }
+
+    #[test]
+    fn packed_i128s_combine_into_their_256_bit_value() {
+        let two_pow_128 = TestScalar::from([0, 0, 1, 0]);
+        for (hi, lo, value) in [
+            (1, 5, TestScalar::from([5, 0, 1, 0])),
+            (-1, -1, -TestScalar::ONE),
+            (-2, 0, -(two_pow_128 + two_pow_128)),
+        ] {
+            let (mut high, low) = TestScalar::pack_two_i128(hi, lo);
+            high *= two_pow_128;
+            high += low;
+            assert_eq!(high, value);
+        }
+    }
+
+    #[test]
+    fn we_cannot_unpack_scalars_out_of_range() {
+        let too_large = TestScalar::from([0, 0, 1, 0]);
+        assert_eq!(
+            TestScalar::unpack_two_i128(&TestScalar::ONE, &too_large),
+            None
+        );
+        assert_eq!(
+            TestScalar::unpack_two_i128(&TestScalar::ONE, &-TestScalar::ONE),
+            None
+        );
+        assert_eq!(
+            TestScalar::unpack_two_i128(&too_large, &TestScalar::ONE),
+            None
+        );
+    }
+
+    #[test]
+    fn we_can_round_trip_nanosecond_timestamps_beyond_the_i64_range() {
+        let nanos_per_year = 365 * 24 * 60 * 60 * 1_000_000_000_i128;
+        for ts in [
+            0,
+            -1,
+            1_700_000_000_000_000_000,
+            i128::from(i64::MAX) + 1,
+            i128::from(i64::MIN) - 1,
+            10_000 * nanos_per_year,
+            -10_000 * nanos_per_year,
+            i128::MAX,
+            i128::MIN,
+        ] {
+            let scalar = TestScalar::from_timestamp_nanos(ts);
+            assert_eq!(scalar, TestScalar::from(ts));
+            assert_eq!(scalar.to_timestamp_nanos(), Some(ts));
+        }
+    }
+
+    #[test]
+    fn we_cannot_decode_nanosecond_timestamps_outside_the_i128_range() {
+        let too_large = TestScalar::from([0, 0, 1, 0]);
+        assert_eq!(too_large.to_timestamp_nanos(), None);
+        assert_eq!((-too_large).to_timestamp_nanos(), None);
+    }
+
+    fn bits_be(value: u64) -> Vec<bool> {
+        (0..64).rev().map(|i| (value >> i) & 1 == 1).collect()
+    }
+
+    #[test]
+    fn we_can_convert_big_endian_bits_to_scalars() {
+        assert_eq!(TestScalar::from_bits_be(&[]), Some(TestScalar::ZERO));
+        assert_eq!(
+            TestScalar::from_bits_be(&[true, false, true]),
+            Some(TestScalar::from(5))
+        );
+        assert_eq!(
+            TestScalar::from_bits_be(&bits_be(0x0123_4567_89ab_cdef)),
+            Some(TestScalar::from(0x0123_4567_89ab_cdef_u64))
+        );
+        // Leading zeros beyond 256 bits do not matter
+        let mut bits = vec![false; 300];
+        bits.extend(bits_be(u64::MAX));
+        assert_eq!(
+            TestScalar::from_bits_be(&bits),
+            Some(TestScalar::from(u64::MAX))
+        );
+        // 2^200 + 1
+        let mut bits = vec![true];
+        bits.extend(vec![false; 199]);
+        bits.push(true);
+        assert_eq!(
+            TestScalar::from_bits_be(&bits),
+            Some(TestScalar::from([1, 0, 0, 1 << 8]))
+        );
+    }
+
+    #[test]
+    fn we_cannot_convert_big_endian_bits_that_are_not_below_the_modulus() {
+        let modulus_limbs = cast::<[u8; 32], [u64; 4]>(TestScalar::modulus().to_le_bytes());
+        let modulus_bits: Vec<bool> = modulus_limbs
+            .iter()
+            .rev()
+            .flat_map(|&limb| bits_be(limb))
+            .collect();
+        assert_eq!(TestScalar::from_bits_be(&modulus_bits), None);
+        assert_eq!(TestScalar::from_bits_be(&[true; 256]), None);
+        assert_eq!(TestScalar::from_bits_be(&[true; 300]), None);
+        // p - 1 is the largest value that can be converted
+        let mut max_bits = modulus_bits;
+        *max_bits.last_mut().unwrap() = false;
+        assert_eq!(TestScalar::from_bits_be(&max_bits), Some(-TestScalar::ONE));
+    }
+
+    #[test]
+    fn we_can_round_trip_scalars_through_canonical_bytes() {
+        use ark_std::UniformRand;
+        let mut rng = ark_std::test_rng();
+        let mut one = [0u8; 32];
+        one[0] = 1;
+        assert_eq!(TestScalar::ZERO.to_canonical_bytes(), [0; 32]);
+        assert_eq!(TestScalar::ONE.to_canonical_bytes(), one);
+        assert_eq!(
+            TestScalar::from_canonical_bytes(&one),
+            Some(TestScalar::ONE)
+        );
+        for value in [
+            TestScalar::ZERO,
+            TestScalar::ONE,
+            TestScalar::MAX_SIGNED,
+            -TestScalar::ONE,
+            TestScalar::rand(&mut rng),
+        ] {
+            assert_eq!(
+                TestScalar::from_canonical_bytes(&value.to_canonical_bytes()),
+                Some(value)
+            );
+        }
+    }
+
+    #[test]
+    fn we_cannot_decode_canonical_bytes_that_are_not_reduced() {
+        let modulus = TestScalar::modulus().to_le_bytes();
+        assert_eq!(TestScalar::from_canonical_bytes(&modulus), None);
+        assert_eq!(TestScalar::from_canonical_bytes(&[0xFF; 32]), None);
+    }
+
+    #[test]
+    fn we_can_round_trip_scalars_through_hex_strings() {
+        use ark_std::UniformRand;
+        let mut rng = ark_std::test_rng();
+        assert_eq!(TestScalar::ZERO.to_hex_string(), "0".repeat(64));
+        assert_eq!(
+            TestScalar::from(0xAB_u8).to_hex_string(),
+            format!("{}ab", "0".repeat(62))
+        );
+        assert_eq!(
+            TestScalar::MAX_SIGNED.to_hex_string(),
+            "080000000000000000000000000000000a6f7cef517bce6b2c09318d2e7ae9f6"
+        );
+        for value in [
+            TestScalar::ZERO,
+            TestScalar::MAX_SIGNED,
+            -TestScalar::ONE,
+            TestScalar::rand(&mut rng),
+        ] {
+            assert_eq!(TestScalar::from_hex_str(&value.to_hex_string()), Ok(value));
+        }
+        assert_eq!(
+            TestScalar::from_hex_str(&format!("{}AB", "0".repeat(62))),
+            Ok(TestScalar::from(0xAB_u8))
+        );
+    }
+
+    #[test]
+    fn we_cannot_parse_invalid_or_out_of_range_hex_strings() {
+        assert_eq!(
+            TestScalar::from_hex_str(&"0".repeat(63)),
+            Err(ScalarParseError::InvalidHex)
+        );
+        assert_eq!(
+            TestScalar::from_hex_str(&"0".repeat(65)),
+            Err(ScalarParseError::InvalidHex)
+        );
+        assert_eq!(
+            TestScalar::from_hex_str(&format!("{}g", "0".repeat(63))),
+            Err(ScalarParseError::InvalidHex)
+        );
+        assert_eq!(
+            TestScalar::from_hex_str(&"f".repeat(64)),
+            Err(ScalarParseError::OutOfRange)
+        );
+        assert_eq!(
+            TestScalar::from_hex_str(
+                "1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed"
+            ),
+            Err(ScalarParseError::OutOfRange)
+        );
+    }
 }
@@ -86,3 +86,11 @@ fn we_can_convert_u256_to_test_scalar_with_wrapping_of_random_u256() {
     // ASSERT
     assert_eq!(test_scalar, expected_scalar);
 }
+
+static TEST_SCALAR_HALF: TestScalar = TestScalar::HALF;
+
+#[test]
+fn we_can_use_half_in_static_initialization() {
+    assert_eq!(TEST_SCALAR_HALF + TEST_SCALAR_HALF, TestScalar::ONE);
+    assert_eq!(TEST_SCALAR_HALF * TestScalar::from(10), TestScalar::from(5));
+}
@@ -1,5 +1,8 @@
-use crate::base::{scalar::test_scalar::TestScalar, slice_ops};
-use num_traits::{Inv, Zero};
+use crate::base::{
+    scalar::{test_scalar::TestScalar, Scalar},
+    slice_ops,
+};
+use num_traits::Inv;
 
 #[test]
 fn we_can_pseudo_invert_empty_arrays() {
@@ -47,8 +50,8 @@ fn we_can_pseudo_invert_arrays_of_length_bigger_than_1_with_zeros_and_non_zeros(
     slice_ops::batch_inversion(&mut res[..]);
 
     for (input_val, res_val) in input.iter().zip(res) {
-        if *input_val == TestScalar::zero() {
-            assert!(TestScalar::zero() == res_val);
+        if *input_val == TestScalar::ZERO {
+            assert!(TestScalar::ZERO == res_val);
         } else {
             assert!(input_val.inv().unwrap() == res_val);
         }
@@ -78,8 +81,8 @@ fn we_can_pseudo_invert_arrays_with_nonzero_count_bigger_than_min_chunking_size_
     slice_ops::batch_inversion(&mut res[..]);
 
     for (input_val, res_val) in input.iter().zip(res) {
-        if *input_val == TestScalar::zero() {
-            assert!(TestScalar::zero() == res_val);
+        if *input_val == TestScalar::ZERO {
+            assert!(TestScalar::ZERO == res_val);
         } else {
             assert!(input_val.inv().unwrap() == res_val);
         }
@@ -109,10 +112,28 @@ fn we_can_pseudo_invert_arrays_with_nonzero_count_smaller_than_min_chunking_size
     slice_ops::batch_inversion(&mut res[..]);
 
     for (input_val, res_val) in input.iter().zip(res) {
-        if *input_val == TestScalar::zero() {
-            assert!(TestScalar::zero() == res_val);
+        if *input_val == TestScalar::ZERO {
+            assert!(TestScalar::ZERO == res_val);
         } else {
             assert!(input_val.inv().unwrap() == res_val);
         }
     }
 }
+
+#[test]
+fn we_can_batch_invert_arrays_of_ones_and_multiply_back_to_one() {
+    let mut ones = vec![TestScalar::ONE; 5];
+    slice_ops::batch_inversion(&mut ones[..]);
+    assert_eq!(ones, vec![TestScalar::ONE; 5]);
+
+    let input: Vec<TestScalar> = (-20..20).map(TestScalar::from).collect();
+    let mut res = input.clone();
+    slice_ops::batch_inversion(&mut res[..]);
+    for (input_val, res_val) in input.iter().zip(res) {
+        if *input_val == TestScalar::ZERO {
+            assert_eq!(res_val, TestScalar::ZERO);
+        } else {
+            assert_eq!(*input_val * res_val, TestScalar::ONE);
+        }
+    }
+}
@@ -29,5 +29,10 @@ pub use sub::*;
 mod batch_inverse;
 pub use batch_inverse::*;
 
+mod prefix_sum;
+pub use prefix_sum::*;
+#[cfg(test)]
+mod prefix_sum_test;
+
 #[cfg(test)]
 mod batch_inverse_test;
@@ -0,0 +1,86 @@
+use crate::base::if_rayon;
+use alloc::vec::Vec;
+use core::ops::AddAssign;
+#[cfg(feature = "rayon")]
+use rayon::{
+    iter::{IndexedParallelIterator, ParallelIterator},
+    slice::ParallelSliceMut,
+};
+
+/// Replaces every element of `values` with the sum of it and all elements before it.
+fn prefix_sum_in_place_sequential<T>(values: &mut [T])
+where
+    T: AddAssign + Copy,
+{
+    let mut sum: Option<T> = None;
+    for value in values {
+        if let Some(sum) = sum {
+            *value += sum;
+        }
+        sum = Some(*value);
+    }
+}
+
+/// Replaces every element of `values` with the sum of it and all elements before it.
+///
+/// The slice is split into one chunk per thread. The prefix sums of the chunks are computed in parallel,
+/// then the totals of the preceding chunks are added to each chunk in parallel.
+#[cfg(feature = "rayon")]
+fn prefix_sum_in_place_parallel<T>(values: &mut [T])
+where
+    T: AddAssign + Copy + Send + Sync,
+{
+    let chunk_len = values
+        .len()
+        .div_ceil(rayon::current_num_threads())
+        .max(super::MIN_RAYON_LEN);
+    values
+        .par_chunks_mut(chunk_len)
+        .for_each(prefix_sum_in_place_sequential);
+    let mut chunk_offsets = Vec::with_capacity(values.len().div_ceil(chunk_len));
+    let mut offset: Option<T> = None;
+    for chunk in values.chunks(chunk_len) {
+        chunk_offsets.push(offset);
+        if let Some(&chunk_total) = chunk.last() {
+            offset = Some(offset.map_or(chunk_total, |mut offset| {
+                offset += chunk_total;
+                offset
+            }));
+        }
+    }
+    values
+        .par_chunks_mut(chunk_len)
+        .zip(chunk_offsets)
+        .for_each(|(chunk, chunk_offset)| {
+            if let Some(chunk_offset) = chunk_offset {
+                chunk.iter_mut().for_each(|value| *value += chunk_offset);
+            }
+        });
+}
+
+/// Computes the inclusive prefix sums of `values`, i.e. `result[i] = values[0] + ... + values[i]`.
+pub fn prefix_sum<T>(values: &[T]) -> Vec<T>
+where
+    T: AddAssign + Copy + Send + Sync,
+{
+    let mut result = values.to_vec();
+    if_rayon!(
+        prefix_sum_in_place_parallel(&mut result),
+        prefix_sum_in_place_sequential(&mut result)
+    );
+    result
+}
+
+/// Computes the inclusive suffix sums of `values`, i.e. `result[i] = values[i] + ... + values[n - 1]`.
+pub fn suffix_sum<T>(values: &[T]) -> Vec<T>
+where
+    T: AddAssign + Copy + Send + Sync,
+{
+    let mut result: Vec<T> = values.iter().rev().copied().collect();
+    if_rayon!(
+        prefix_sum_in_place_parallel(&mut result),
+        prefix_sum_in_place_sequential(&mut result)
+    );
+    result.reverse();
+    result
+}
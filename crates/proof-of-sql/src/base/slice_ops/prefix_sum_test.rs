@@ -0,0 +1,37 @@
+use super::*;
+use crate::base::scalar::test_scalar::TestScalar;
+
+#[test]
+fn we_can_compute_prefix_sums_of_empty_slices() {
+    assert!(prefix_sum::<i64>(&[]).is_empty());
+    assert!(suffix_sum::<i64>(&[]).is_empty());
+}
+
+#[test]
+fn we_can_compute_prefix_sums() {
+    assert_eq!(prefix_sum(&[5]), vec![5]);
+    assert_eq!(prefix_sum(&[1, 2, 3, -4]), vec![1, 3, 6, 2]);
+    assert_eq!(suffix_sum(&[5]), vec![5]);
+    assert_eq!(suffix_sum(&[1, 2, 3, -4]), vec![2, 1, -1, -4]);
+}
+
+#[test]
+fn we_can_compute_prefix_sums_of_long_slices() {
+    let values: Vec<TestScalar> = (0..10 * MIN_RAYON_LEN as u64 + 7)
+        .map(TestScalar::from)
+        .collect();
+    let mut expected_prefix = Vec::with_capacity(values.len());
+    let mut sum = TestScalar::from(0);
+    for value in &values {
+        sum += *value;
+        expected_prefix.push(sum);
+    }
+    let expected_suffix: Vec<TestScalar> = expected_prefix
+        .iter()
+        .map(|prefix| sum - *prefix)
+        .zip(&values)
+        .map(|(after, value)| after + *value)
+        .collect();
+    assert_eq!(prefix_sum(&values), expected_prefix);
+    assert_eq!(suffix_sum(&values), expected_suffix);
+}
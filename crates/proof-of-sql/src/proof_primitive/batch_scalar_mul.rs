@@ -0,0 +1,102 @@
+use crate::base::scalar::Scalar;
+use alloc::vec::Vec;
+use ark_ec::AdditiveGroup;
+
+/// The number of bits in the canonical representation of a scalar.
+const SCALAR_BITS: usize = 256;
+/// The largest window size used when building the table of multiples.
+const MAX_WINDOW_SIZE: usize = 16;
+
+/// Chooses a window size for multiplying `num_scalars` scalars by the same base.
+///
+/// Larger windows make every multiplication cheaper but the table grows exponentially,
+/// so the table should only be as large as the number of scalars warrants.
+fn window_size(num_scalars: usize) -> usize {
+    (num_scalars.max(1).ilog2() as usize).clamp(2, MAX_WINDOW_SIZE)
+}
+
+/// Returns the `width` bits of `limbs` starting at bit `offset`, where `width <= 64`.
+fn get_bits(limbs: &[u64; 4], offset: usize, width: usize) -> u64 {
+    let (limb, shift) = (offset / 64, offset % 64);
+    if limb >= limbs.len() {
+        return 0;
+    }
+    let mut bits = limbs[limb] >> shift;
+    if shift + width > 64 && limb + 1 < limbs.len() {
+        bits |= limbs[limb + 1] << (64 - shift);
+    }
+    bits & ((1 << width) - 1)
+}
+
+/// Recodes a scalar into signed digits in `[-2^(w-1), 2^(w-1)]`, least significant first.
+///
+/// The scalar equals `sum(digits[i] * 2^(w*i))`. Using signed digits halves the size of the
+/// table of multiples, since a negative digit can be handled by subtracting a table entry.
+#[expect(
+    clippy::cast_possible_wrap,
+    reason = "windows are at most 16 bits wide, so they always fit in an i64"
+)]
+fn signed_window_digits(limbs: &[u64; 4], window_size: usize) -> Vec<i64> {
+    let half_window = 1i64 << (window_size - 1);
+    let mut carry = 0;
+    (0..=SCALAR_BITS.div_ceil(window_size))
+        .map(|i| {
+            let digit = get_bits(limbs, i * window_size, window_size) as i64 + carry;
+            if digit > half_window {
+                carry = 1;
+                digit - (half_window << 1)
+            } else {
+                carry = 0;
+                digit
+            }
+        })
+        .collect()
+}
+
+/// Multiplies the same `base` by each of `scalars`.
+///
+/// A table holding `j * 2^(w*i) * base` for every window `i` and every `1 <= j <= 2^(w-1)` is
+/// built once. Every scalar is then recoded into signed `w`-bit digits, so that each
+/// multiplication costs about `256 / w` group additions and no doublings.
+/// The window size `w` grows with the number of scalars, so that building the table is
+/// amortized over the multiplications.
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "digits are at most 2^15 in absolute value, so they always fit in a usize"
+)]
+pub fn batch_scalar_mul<S: Scalar, G: AdditiveGroup>(scalars: &[S], base: &G) -> Vec<G> {
+    if scalars.is_empty() {
+        return Vec::new();
+    }
+    let window_size = window_size(scalars.len());
+    let num_windows = SCALAR_BITS.div_ceil(window_size) + 1;
+    let table_width = 1 << (window_size - 1);
+
+    let mut table = Vec::with_capacity(num_windows);
+    let mut window_base = *base;
+    for _ in 0..num_windows {
+        table.push(
+            core::iter::successors(Some(window_base), |multiple| Some(*multiple + window_base))
+                .take(table_width)
+                .collect::<Vec<_>>(),
+        );
+        for _ in 0..window_size {
+            window_base.double_in_place();
+        }
+    }
+
+    scalars
+        .iter()
+        .map(|scalar| {
+            let limbs: [u64; 4] = (*scalar).into();
+            signed_window_digits(&limbs, window_size)
+                .into_iter()
+                .zip(&table)
+                .fold(G::ZERO, |acc, (digit, row)| match digit {
+                    0 => acc,
+                    d if d > 0 => acc + row[d.unsigned_abs() as usize - 1],
+                    d => acc - row[d.unsigned_abs() as usize - 1],
+                })
+        })
+        .collect()
+}
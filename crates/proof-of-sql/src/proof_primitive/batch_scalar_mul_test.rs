@@ -0,0 +1,58 @@
+use super::batch_scalar_mul;
+use crate::{
+    base::scalar::Scalar,
+    proof_primitive::dory::{test_rng, DoryScalar},
+};
+use ark_bls12_381::G1Projective;
+use ark_ec::{AdditiveGroup, PrimeGroup};
+use ark_std::UniformRand;
+
+fn naive_scalar_mul(scalars: &[DoryScalar], base: &G1Projective) -> Vec<G1Projective> {
+    scalars
+        .iter()
+        .map(|scalar| base.mul_bigint(<[u64; 4]>::from(scalar)))
+        .collect()
+}
+
+#[test]
+fn we_can_batch_multiply_no_scalars() {
+    let base = G1Projective::generator();
+    assert!(batch_scalar_mul::<DoryScalar, _>(&[], &base).is_empty());
+}
+
+#[test]
+fn we_can_batch_multiply_simple_scalars() {
+    let base = G1Projective::generator();
+    let scalars = [
+        DoryScalar::ZERO,
+        DoryScalar::ONE,
+        DoryScalar::TWO,
+        DoryScalar::TEN,
+        -DoryScalar::ONE,
+        DoryScalar::MAX_SIGNED,
+    ];
+    let expected = vec![
+        G1Projective::ZERO,
+        base,
+        base.double(),
+        base.double().double().double() + base.double(),
+        -base,
+        naive_scalar_mul(&[DoryScalar::MAX_SIGNED], &base)[0],
+    ];
+    assert_eq!(batch_scalar_mul(&scalars, &base), expected);
+}
+
+#[test]
+fn we_can_batch_multiply_random_scalars_with_different_window_sizes() {
+    let mut rng = test_rng();
+    for num_scalars in [1, 3, 17, 300] {
+        let base = G1Projective::rand(&mut rng);
+        let scalars: Vec<DoryScalar> = (0..num_scalars)
+            .map(|_| DoryScalar::rand(&mut rng))
+            .collect();
+        assert_eq!(
+            batch_scalar_mul(&scalars, &base),
+            naive_scalar_mul(&scalars, &base)
+        );
+    }
+}
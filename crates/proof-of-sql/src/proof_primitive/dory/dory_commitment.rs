@@ -61,6 +61,18 @@ impl Default for DoryCommitment {
     }
 }
 
+impl DoryCommitment {
+    /// Returns the commitment to a column of zeros.
+    ///
+    /// Every entry contributes its value times a fixed group element, so a column of zeros commits to the identity,
+    /// whatever its length and offset and whatever the setup. This allows committing to new all-zero columns
+    /// without any group operations.
+    #[must_use]
+    pub fn commitment_to_zeros() -> Self {
+        Self::default()
+    }
+}
+
 // Traits required for `DoryCommitment` to impl `Commitment`.
 impl_serde_for_ark_serde_checked!(DoryCommitment);
 impl Mul<DoryCommitment> for DoryScalar {
@@ -101,7 +113,7 @@ mod tests {
         base::{
             commitment::{Commitment, NumColumnsMismatch, VecCommitmentExt},
             database::{Column, OwnedColumn},
-            scalar::test_scalar_constants,
+            scalar::{test_scalar_constants, Scalar},
         },
         proof_primitive::dory::{rand_util::test_rng, ProverSetup, PublicParameters},
     };
@@ -114,6 +126,24 @@ mod tests {
         test_scalar_constants::<DoryScalar>();
     }
 
+    #[test]
+    fn we_can_convert_dory_scalars_to_and_from_bytes() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..10 {
+            let scalar = DoryScalar::rand(&mut rng);
+            let bytes = scalar.to_bytes();
+            assert_eq!(bytes.to_vec(), scalar.to_bytes_le());
+            assert_eq!(DoryScalar::from_bytes(&bytes), Some(scalar));
+        }
+        assert_eq!(DoryScalar::from(1).to_bytes()[0], 1);
+        assert_eq!(DoryScalar::from_bytes(&[0; 32]), Some(DoryScalar::ZERO));
+        assert_eq!(DoryScalar::from_bytes(&[0xFF; 32]), None);
+        // The modulus itself is not a canonical encoding
+        let mut modulus_bytes = (-DoryScalar::ONE).to_bytes();
+        modulus_bytes[0] += 1;
+        assert_eq!(DoryScalar::from_bytes(&modulus_bytes), None);
+    }
+
     #[test]
     fn we_can_convert_from_columns() {
         let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
@@ -159,6 +189,30 @@ mod tests {
         assert_eq!(commitments, expected_commitments);
     }
 
+    #[test]
+    fn we_can_get_the_commitment_to_zeros_without_computing_it() {
+        let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let setup = DoryProverPublicSetup::new(&prover_setup, 2);
+        for length in [0, 1, 5, 20] {
+            for offset in [0, 3] {
+                let columns = vec![
+                    OwnedColumn::<DoryScalar>::BigInt(vec![0; length]),
+                    OwnedColumn::Boolean(vec![false; length]),
+                ];
+                let commitments =
+                    Vec::<DoryCommitment>::from_columns_with_offset(&columns, offset, &setup);
+                assert_eq!(commitments, vec![DoryCommitment::commitment_to_zeros(); 2]);
+            }
+        }
+
+        let columns = vec![OwnedColumn::<DoryScalar>::BigInt(vec![12, 34, 56])];
+        let commitment = Vec::<DoryCommitment>::from_columns_with_offset(&columns, 0, &setup)[0];
+        let mut sum = commitment;
+        sum += DoryCommitment::commitment_to_zeros();
+        assert_eq!(sum, commitment);
+    }
+
     #[test]
     fn we_can_append_rows() {
         let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
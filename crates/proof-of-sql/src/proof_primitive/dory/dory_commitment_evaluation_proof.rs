@@ -1,6 +1,6 @@
 use super::{
-    build_vmv_prover_state, build_vmv_verifier_state, compute_T_vec_prime, compute_nu,
-    eval_vmv_re_prove, eval_vmv_re_verify, extended_dory_inner_product_prove,
+    build_vmv_prover_state, build_vmv_verifier_state, compute_T_vec_prime, compute_max_table_size,
+    compute_nu, eval_vmv_re_prove, eval_vmv_re_verify, extended_dory_inner_product_prove,
     extended_dory_inner_product_verify,
     extended_dory_reduce_helper::extended_dory_reduce_verify_fold_s_vecs, DeferredGT,
     DoryCommitment, DoryMessages, DoryProverPublicSetup, DoryScalar, DoryVerifierPublicSetup, F,
@@ -35,6 +35,16 @@ impl CommitmentEvaluationProof for DoryEvaluationProof {
     type ProverPublicSetup<'a> = DoryProverPublicSetup<'a>;
     type VerifierPublicSetup<'a> = DoryVerifierPublicSetup<'a>;
 
+    fn max_table_size(setup: &Self::ProverPublicSetup<'_>) -> Option<usize> {
+        // `compute_nu` never goes below `sigma`, and above `2 * sigma` variables it grows as `num_vars - sigma`.
+        let max_nu = setup.prover_setup().max_nu;
+        Some(if setup.sigma() > max_nu {
+            0
+        } else {
+            compute_max_table_size(max_nu + setup.sigma())
+        })
+    }
+
     #[tracing::instrument(name = "DoryEvaluationProof::new", level = "debug", skip_all)]
     fn new(
         transcript: &mut impl Transcript,
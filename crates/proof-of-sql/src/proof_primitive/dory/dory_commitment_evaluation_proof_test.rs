@@ -92,3 +92,22 @@ fn we_can_serialize_and_deserialize_dory_evaluation_proofs() {
     let decoded: DoryEvaluationProof = postcard::from_bytes(&encoded).unwrap();
     assert_eq!(decoded, proof);
 }
+
+#[test]
+fn we_can_get_the_max_table_size_of_a_setup() {
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    for (sigma, expected) in [(0, 16), (1, 32), (3, 128), (4, 256), (5, 0)] {
+        assert_eq!(
+            DoryEvaluationProof::max_table_size(&DoryProverPublicSetup::new(&prover_setup, sigma)),
+            Some(expected)
+        );
+    }
+    test_random_commitment_evaluation_proof::<DoryEvaluationProof>(
+        128,
+        0,
+        &DoryProverPublicSetup::new(&prover_setup, 3),
+        &DoryVerifierPublicSetup::new(&verifier_setup, 3),
+    );
+}
@@ -104,6 +104,14 @@ pub(super) fn compute_nu(num_vars: usize, sigma: usize) -> usize {
     }
 }
 
+/// Compute the number of rows that fit in `num_vars` variables, saturating at `usize::MAX`.
+pub(super) fn compute_max_table_size(num_vars: usize) -> usize {
+    u32::try_from(num_vars)
+        .ok()
+        .and_then(|num_vars| 1usize.checked_shl(num_vars))
+        .unwrap_or(usize::MAX)
+}
+
 /// Compute the vectors L and R that are derived from `b_point`.
 /// L and R are the vectors such that LMR is exactly the evaluation of `a` at the point `b_point`.
 pub(super) fn compute_L_R_vec(b_point: &[F], sigma: usize, nu: usize) -> (Vec<F>, Vec<F>) {
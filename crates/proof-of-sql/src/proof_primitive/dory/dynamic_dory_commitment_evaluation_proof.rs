@@ -1,4 +1,5 @@
 use super::{
+    compute_max_table_size,
     dynamic_build_vmv_state::{build_dynamic_vmv_prover_state, build_dynamic_vmv_verifier_state},
     dynamic_dory_helper::{compute_dynamic_T_vec_prime, compute_dynamic_nu, fold_dynamic_tensors},
     eval_vmv_re_prove, eval_vmv_re_verify, extended_dory_inner_product_prove,
@@ -37,6 +38,14 @@ impl CommitmentEvaluationProof for DynamicDoryEvaluationProof {
     type ProverPublicSetup<'a> = &'a ProverSetup<'a>;
     type VerifierPublicSetup<'a> = &'a VerifierSetup;
 
+    fn max_table_size(setup: &Self::ProverPublicSetup<'_>) -> Option<usize> {
+        // `compute_dynamic_nu(num_vars)` is `num_vars / 2 + 1`, so at most `2 * max_nu - 1` variables fit.
+        Some(match (2 * setup.max_nu).checked_sub(1) {
+            Some(max_num_vars) => compute_max_table_size(max_num_vars),
+            None => 0,
+        })
+    }
+
     #[tracing::instrument(name = "DoryEvaluationProof::new", level = "debug", skip_all)]
     fn new(
         transcript: &mut impl Transcript,
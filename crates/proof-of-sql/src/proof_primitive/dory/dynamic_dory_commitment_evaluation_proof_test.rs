@@ -75,3 +75,24 @@ fn we_can_serialize_and_deserialize_dory_evaluation_proofs() {
     let decoded: DynamicDoryEvaluationProof = postcard::from_bytes(&encoded).unwrap();
     assert_eq!(decoded, proof);
 }
+
+#[test]
+fn we_can_get_the_max_table_size_of_a_setup() {
+    for (max_nu, expected) in [(0, 0), (1, 2), (2, 8), (4, 128)] {
+        let public_parameters = PublicParameters::test_rand(max_nu, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        assert_eq!(
+            DynamicDoryEvaluationProof::max_table_size(&&prover_setup),
+            Some(expected)
+        );
+    }
+    let public_parameters = PublicParameters::test_rand(4, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    test_random_commitment_evaluation_proof::<DynamicDoryEvaluationProof>(
+        128,
+        0,
+        &&prover_setup,
+        &&verifier_setup,
+    );
+}
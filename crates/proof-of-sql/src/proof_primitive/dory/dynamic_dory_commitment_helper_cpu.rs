@@ -78,8 +78,11 @@ fn compute_dory_commitment(
         CommittableColumn::Uint8(column) => compute_dory_commitment_impl(column, offset, setup),
         CommittableColumn::TinyInt(column) => compute_dory_commitment_impl(column, offset, setup),
         CommittableColumn::SmallInt(column) => compute_dory_commitment_impl(column, offset, setup),
-        CommittableColumn::Int(column) => compute_dory_commitment_impl(column, offset, setup),
+        CommittableColumn::Int(column) | CommittableColumn::Date(column) => {
+            compute_dory_commitment_impl(column, offset, setup)
+        }
         CommittableColumn::BigInt(column) => compute_dory_commitment_impl(column, offset, setup),
+        CommittableColumn::UInt64(column) => compute_dory_commitment_impl(column, offset, setup),
         CommittableColumn::Int128(column) => compute_dory_commitment_impl(column, offset, setup),
         CommittableColumn::VarChar(column)
         | CommittableColumn::VarBinary(column)
@@ -86,7 +86,7 @@ pub(crate) use extended_dory_inner_product::{
 mod extended_dory_inner_product_test;
 
 mod public_parameters;
-pub use public_parameters::PublicParameters;
+pub use public_parameters::{KeyDeserializeError, PublicParameters};
 
 mod eval_vmv_re;
 pub(crate) use eval_vmv_re::{eval_vmv_re_prove, eval_vmv_re_verify};
@@ -123,7 +123,8 @@ mod dory_compute_commitments_test;
 
 mod dory_vmv_helper;
 use dory_vmv_helper::{
-    compute_L_R_vec, compute_T_vec_prime, compute_l_r_tensors, compute_nu, compute_v_vec,
+    compute_L_R_vec, compute_T_vec_prime, compute_l_r_tensors, compute_max_table_size, compute_nu,
+    compute_v_vec,
 };
 mod build_vmv_state;
 use build_vmv_state::{build_vmv_prover_state, build_vmv_verifier_state};
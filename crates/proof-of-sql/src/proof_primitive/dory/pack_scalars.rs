@@ -400,7 +400,7 @@ pub fn bit_table_and_scalars_for_packed_msm(
                     num_matrix_commitment_columns,
                 );
             }
-            CommittableColumn::Int(column) => {
+            CommittableColumn::Int(column) | CommittableColumn::Date(column) => {
                 pack_bit(
                     column,
                     &mut packed_scalars,
@@ -422,6 +422,17 @@ pub fn bit_table_and_scalars_for_packed_msm(
                     num_matrix_commitment_columns,
                 );
             }
+            CommittableColumn::UInt64(column) => {
+                pack_bit(
+                    column,
+                    &mut packed_scalars,
+                    cumulative_bit_sum_table[i],
+                    offset,
+                    committable_columns[i].column_type().byte_size(),
+                    bit_table_full_sum_in_bytes,
+                    num_matrix_commitment_columns,
+                );
+            }
             CommittableColumn::Int128(column) => {
                 pack_bit(
                     column,
@@ -1,11 +1,16 @@
 use super::{G1Affine, G2Affine};
-use alloc::vec::Vec;
+use crate::base::hex::{decode_hex, encode_hex};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 use ark_ff::UniformRand;
 use ark_serialize::{
     CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
 };
 use ark_std::rand::{CryptoRng, Rng};
 use core::iter;
+use snafu::Snafu;
 #[cfg(feature = "std")]
 use std::{
     fs::File,
@@ -33,6 +38,29 @@ pub struct PublicParameters {
     pub(super) max_nu: usize,
 }
 
+/// The version prefix of the hex encoding produced by [`PublicParameters::to_hex_string`].
+const HEX_STRING_VERSION_PREFIX: &str = "v1:";
+
+/// Errors that can occur when decoding [`PublicParameters`] from a hex string.
+#[derive(Snafu, Debug, PartialEq, Eq)]
+pub enum KeyDeserializeError {
+    /// The string does not start with a supported version prefix.
+    #[snafu(display("unsupported public parameters version: expected prefix {expected}"))]
+    UnsupportedVersion {
+        /// The prefix that was expected
+        expected: &'static str,
+    },
+    /// The payload is not a valid hex string.
+    #[snafu(display("public parameters are not valid hex"))]
+    InvalidHex,
+    /// The decoded bytes are not valid public parameters.
+    #[snafu(display("failed to deserialize public parameters: {error}"))]
+    Deserialization {
+        /// The underlying error
+        error: String,
+    },
+}
+
 impl PublicParameters {
     /// Generate cryptographically secure random public parameters.
     pub fn rand<R: CryptoRng + Rng + ?Sized>(max_nu: usize, rng: &mut R) -> Self {
@@ -58,6 +86,18 @@ impl PublicParameters {
             max_nu,
         }
     }
+    /// The log of the number of generators in this setup, i.e. the maximum `nu` that it works for.
+    #[must_use]
+    pub fn nu(&self) -> usize {
+        self.max_nu
+    }
+    /// The number of generators in this setup, `2^nu`.
+    ///
+    /// This is not the largest table the setup can prove; see [`CommitmentEvaluationProof::max_table_size`](crate::base::commitment::CommitmentEvaluationProof::max_table_size).
+    #[must_use]
+    pub fn num_generators(&self) -> usize {
+        1 << self.nu()
+    }
     #[cfg(feature = "std")]
     /// Function to save `PublicParameters` to a file in binary form
     pub fn save_to_file(&self, path: &Path) -> std::io::Result<()> {
@@ -94,6 +134,35 @@ impl PublicParameters {
         )
         .map_err(|e| Error::new(ErrorKind::Other, format!("{e}")))
     }
+    /// Encodes the `PublicParameters` as a versioned hex string, e.g. `v1:deadbeef...`.
+    ///
+    /// This is intended for transporting the parameters through configuration files or environment variables.
+    ///
+    /// # Panics
+    /// Will panic if serialization into an in-memory buffer fails, which should never happen.
+    #[must_use]
+    pub fn to_hex_string(&self) -> String {
+        let mut bytes = Vec::with_capacity(self.compressed_size());
+        self.serialize_compressed(&mut bytes)
+            .expect("serializing into a Vec should not fail");
+        HEX_STRING_VERSION_PREFIX.to_string() + &encode_hex(&bytes)
+    }
+    /// Decodes `PublicParameters` from a string produced by [`PublicParameters::to_hex_string`].
+    ///
+    /// Strings with a missing or unknown version prefix are rejected.
+    pub fn from_hex_string(s: &str) -> Result<Self, KeyDeserializeError> {
+        let hex = s.strip_prefix(HEX_STRING_VERSION_PREFIX).ok_or(
+            KeyDeserializeError::UnsupportedVersion {
+                expected: HEX_STRING_VERSION_PREFIX,
+            },
+        )?;
+        let bytes = decode_hex(hex).ok_or(KeyDeserializeError::InvalidHex)?;
+        Self::deserialize_compressed(&mut &bytes[..]).map_err(|e| {
+            KeyDeserializeError::Deserialization {
+                error: e.to_string(),
+            }
+        })
+    }
 }
 
 impl CanonicalSerialize for PublicParameters {
@@ -232,6 +301,18 @@ mod tests {
     use ark_std::rand::thread_rng;
     use std::io::Cursor;
 
+    #[test]
+    fn we_can_get_the_size_of_public_parameters() {
+        let mut rng = thread_rng();
+        for max_nu in 0..4 {
+            let params = PublicParameters::test_rand(max_nu, &mut rng);
+            assert_eq!(params.nu(), max_nu);
+            assert_eq!(params.num_generators(), 1 << max_nu);
+            assert_eq!(params.Gamma_1.len(), params.num_generators());
+            assert_eq!(params.Gamma_2.len(), params.num_generators());
+        }
+    }
+
     #[test]
     fn we_can_serialize_and_deserialize_round_trip() {
         // Create a random PublicParameters instance
@@ -267,6 +348,52 @@ mod tests {
             .expect("Deserialized parameters are not valid");
     }
 
+    #[test]
+    fn we_can_encode_and_decode_hex_strings_round_trip() {
+        let mut rng = thread_rng();
+        let original_params = PublicParameters::rand(2, &mut rng);
+
+        let hex_string = original_params.to_hex_string();
+        assert!(hex_string.starts_with("v1:"));
+
+        let decoded_params = PublicParameters::from_hex_string(&hex_string)
+            .expect("Failed to decode PublicParameters");
+        assert_eq!(original_params.Gamma_1, decoded_params.Gamma_1);
+        assert_eq!(original_params.Gamma_2, decoded_params.Gamma_2);
+        assert_eq!(original_params.H_1, decoded_params.H_1);
+        assert_eq!(original_params.H_2, decoded_params.H_2);
+        assert_eq!(original_params.Gamma_2_fin, decoded_params.Gamma_2_fin);
+        assert_eq!(original_params.max_nu, decoded_params.max_nu);
+    }
+
+    #[test]
+    fn we_cannot_decode_hex_strings_with_wrong_version_or_invalid_hex() {
+        let mut rng = thread_rng();
+        let hex_string = PublicParameters::rand(1, &mut rng).to_hex_string();
+        let payload = hex_string.strip_prefix("v1:").unwrap();
+
+        assert!(matches!(
+            PublicParameters::from_hex_string(&format!("v0:{payload}")),
+            Err(KeyDeserializeError::UnsupportedVersion { .. })
+        ));
+        assert!(matches!(
+            PublicParameters::from_hex_string(payload),
+            Err(KeyDeserializeError::UnsupportedVersion { .. })
+        ));
+        assert!(matches!(
+            PublicParameters::from_hex_string(&format!("v1:{payload}0")),
+            Err(KeyDeserializeError::InvalidHex)
+        ));
+        assert!(matches!(
+            PublicParameters::from_hex_string("v1:zz"),
+            Err(KeyDeserializeError::InvalidHex)
+        ));
+        assert!(matches!(
+            PublicParameters::from_hex_string("v1:00"),
+            Err(KeyDeserializeError::Deserialization { .. })
+        ));
+    }
+
     // 13th Gen Intel® Core™ i9-13900H × 20
     // nu vs proof size & time:
     // nu = 4  |  0.005 MB  | 287.972567ms
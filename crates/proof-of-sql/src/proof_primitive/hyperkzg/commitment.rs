@@ -146,10 +146,13 @@ fn compute_commitments_impl(
             CommittableColumn::SmallInt(vals) => {
                 compute_commitment_generic_impl(setup, offset, vals)
             }
-            CommittableColumn::Int(vals) => compute_commitment_generic_impl(setup, offset, vals),
+            CommittableColumn::Int(vals) | CommittableColumn::Date(vals) => {
+                compute_commitment_generic_impl(setup, offset, vals)
+            }
             CommittableColumn::BigInt(vals) | CommittableColumn::TimestampTZ(_, _, vals) => {
                 compute_commitment_generic_impl(setup, offset, vals)
             }
+            CommittableColumn::UInt64(vals) => compute_commitment_generic_impl(setup, offset, vals),
             CommittableColumn::Int128(vals) => compute_commitment_generic_impl(setup, offset, vals),
             CommittableColumn::Decimal75(_, _, vals)
             | CommittableColumn::Scalar(vals)
@@ -84,12 +84,15 @@ mod tests {
                 CommittableColumn::SmallInt(vals) => {
                     expected.push(compute_commitment_with_hyperkzg_repo(ck, offset, vals));
                 }
-                CommittableColumn::Int(vals) => {
+                CommittableColumn::Int(vals) | CommittableColumn::Date(vals) => {
                     expected.push(compute_commitment_with_hyperkzg_repo(ck, offset, vals));
                 }
                 CommittableColumn::BigInt(vals) | CommittableColumn::TimestampTZ(_, _, vals) => {
                     expected.push(compute_commitment_with_hyperkzg_repo(ck, offset, vals));
                 }
+                CommittableColumn::UInt64(vals) => {
+                    expected.push(compute_commitment_with_hyperkzg_repo(ck, offset, vals));
+                }
                 CommittableColumn::Int128(vals) => {
                     expected.push(compute_commitment_with_hyperkzg_repo(ck, offset, vals));
                 }
@@ -8,7 +8,7 @@ use crate::{
 };
 use byte_slice_cast::AsByteSlice;
 use num_bigint::BigInt;
-use num_traits::{Inv, One, Zero};
+use num_traits::Inv;
 use rand::{
     distributions::{Distribution, Uniform},
     prelude::StdRng,
@@ -118,7 +118,7 @@ fn test_curve25519_scalar_display() {
 fn test_curve25519_scalar_mid() {
     assert_eq!(
         Curve25519Scalar::MAX_SIGNED,
-        -Curve25519Scalar::one() * Curve25519Scalar::from(2).inv().unwrap()
+        -Curve25519Scalar::ONE * Curve25519Scalar::from(2).inv().unwrap()
     );
 }
 
@@ -312,28 +312,28 @@ fn test_curve25519_scalar_from_bigint() {
 
 #[test]
 fn the_zero_integer_maps_to_the_zero_scalar() {
-    assert_eq!(Curve25519Scalar::from(0_u32), Curve25519Scalar::zero());
-    assert_eq!(Curve25519Scalar::from(0_u64), Curve25519Scalar::zero());
-    assert_eq!(Curve25519Scalar::from(0_u128), Curve25519Scalar::zero());
-    assert_eq!(Curve25519Scalar::from(0_i32), Curve25519Scalar::zero());
-    assert_eq!(Curve25519Scalar::from(0_i64), Curve25519Scalar::zero());
-    assert_eq!(Curve25519Scalar::from(0_i128), Curve25519Scalar::zero());
+    assert_eq!(Curve25519Scalar::from(0_u32), Curve25519Scalar::ZERO);
+    assert_eq!(Curve25519Scalar::from(0_u64), Curve25519Scalar::ZERO);
+    assert_eq!(Curve25519Scalar::from(0_u128), Curve25519Scalar::ZERO);
+    assert_eq!(Curve25519Scalar::from(0_i32), Curve25519Scalar::ZERO);
+    assert_eq!(Curve25519Scalar::from(0_i64), Curve25519Scalar::ZERO);
+    assert_eq!(Curve25519Scalar::from(0_i128), Curve25519Scalar::ZERO);
 }
 
 #[test]
 fn bools_map_to_curve25519_scalar_properly() {
-    assert_eq!(TestScalar::from(true), TestScalar::one());
-    assert_eq!(TestScalar::from(false), TestScalar::zero());
+    assert_eq!(TestScalar::from(true), TestScalar::ONE);
+    assert_eq!(TestScalar::from(false), TestScalar::ZERO);
 }
 
 #[test]
 fn the_one_integer_maps_to_the_zero_scalar() {
-    assert_eq!(Curve25519Scalar::from(1_u32), Curve25519Scalar::one());
-    assert_eq!(Curve25519Scalar::from(1_u64), Curve25519Scalar::one());
-    assert_eq!(Curve25519Scalar::from(1_u128), Curve25519Scalar::one());
-    assert_eq!(Curve25519Scalar::from(1_i32), Curve25519Scalar::one());
-    assert_eq!(Curve25519Scalar::from(1_i64), Curve25519Scalar::one());
-    assert_eq!(Curve25519Scalar::from(1_i128), Curve25519Scalar::one());
+    assert_eq!(Curve25519Scalar::from(1_u32), Curve25519Scalar::ONE);
+    assert_eq!(Curve25519Scalar::from(1_u64), Curve25519Scalar::ONE);
+    assert_eq!(Curve25519Scalar::from(1_u128), Curve25519Scalar::ONE);
+    assert_eq!(Curve25519Scalar::from(1_i32), Curve25519Scalar::ONE);
+    assert_eq!(Curve25519Scalar::from(1_i64), Curve25519Scalar::ONE);
+    assert_eq!(Curve25519Scalar::from(1_i128), Curve25519Scalar::ONE);
 }
 
 #[test]
@@ -343,11 +343,11 @@ fn the_zero_scalar_is_the_additive_identity() {
         let a = Curve25519Scalar::from(rng.gen::<i128>());
         let b = Curve25519Scalar::from(rng.gen::<i128>());
         assert_eq!(a + b, b + a);
-        assert_eq!(a + Curve25519Scalar::zero(), a);
-        assert_eq!(b + Curve25519Scalar::zero(), b);
+        assert_eq!(a + Curve25519Scalar::ZERO, a);
+        assert_eq!(b + Curve25519Scalar::ZERO, b);
         assert_eq!(
-            Curve25519Scalar::zero() + Curve25519Scalar::zero(),
-            Curve25519Scalar::zero()
+            Curve25519Scalar::ZERO + Curve25519Scalar::ZERO,
+            Curve25519Scalar::ZERO
         );
     }
 }
@@ -359,35 +359,35 @@ fn the_one_scalar_is_the_multiplicative_identity() {
         let a = Curve25519Scalar::from(rng.gen::<i128>());
         let b = Curve25519Scalar::from(rng.gen::<i128>());
         assert_eq!(a * b, b * a);
-        assert_eq!(a * Curve25519Scalar::one(), a);
-        assert_eq!(b * Curve25519Scalar::one(), b);
+        assert_eq!(a * Curve25519Scalar::ONE, a);
+        assert_eq!(b * Curve25519Scalar::ONE, b);
         assert_eq!(
-            Curve25519Scalar::one() * Curve25519Scalar::one(),
-            Curve25519Scalar::one()
+            Curve25519Scalar::ONE * Curve25519Scalar::ONE,
+            Curve25519Scalar::ONE
         );
     }
 }
 
 #[test]
 fn the_empty_string_will_be_mapped_to_the_zero_scalar() {
-    assert_eq!(Curve25519Scalar::from(""), Curve25519Scalar::zero());
+    assert_eq!(Curve25519Scalar::from(""), Curve25519Scalar::ZERO);
     assert_eq!(
         Curve25519Scalar::from(<&str>::default()),
-        Curve25519Scalar::zero()
+        Curve25519Scalar::ZERO
     );
 }
 
 #[test]
 fn two_different_strings_map_to_different_scalars() {
     let s = "abc12";
-    assert_ne!(Curve25519Scalar::from(s), Curve25519Scalar::zero());
+    assert_ne!(Curve25519Scalar::from(s), Curve25519Scalar::ZERO);
     assert_ne!(Curve25519Scalar::from(s), Curve25519Scalar::from("abc123"));
 }
 
 #[test]
 fn the_empty_buffer_will_be_mapped_to_the_zero_scalar() {
     let buf = Vec::<u8>::default();
-    assert_eq!(Curve25519Scalar::from(&buf[..]), Curve25519Scalar::zero());
+    assert_eq!(Curve25519Scalar::from(&buf[..]), Curve25519Scalar::ZERO);
 }
 
 #[test]
@@ -395,7 +395,7 @@ fn byte_arrays_with_the_same_content_but_different_types_map_to_different_scalar
     let array = [1_u8, 2_u8, 34_u8];
     assert_ne!(
         Curve25519Scalar::from(array.as_byte_slice()),
-        Curve25519Scalar::zero()
+        Curve25519Scalar::ZERO
     );
     assert_ne!(
         Curve25519Scalar::from(array.as_byte_slice()),
@@ -5,6 +5,11 @@ pub(super) mod dynamic_matrix_utils;
 /// TODO: add docs
 pub(crate) mod sumcheck;
 
+mod batch_scalar_mul;
+pub use batch_scalar_mul::batch_scalar_mul;
+#[cfg(test)]
+mod batch_scalar_mul_test;
+
 pub mod hyperkzg;
 
 /// TODO: Add docs
@@ -18,12 +18,11 @@ use crate::{
 use alloc::rc::Rc;
 use ark_std::UniformRand;
 use merlin::Transcript;
-use num_traits::{One, Zero};
 
 #[test]
 fn test_create_verify_proof() {
     let num_vars = 1;
-    let mut evaluation_point: [Curve25519Scalar; 1] = [Curve25519Scalar::zero(); 1];
+    let mut evaluation_point: [Curve25519Scalar; 1] = [Curve25519Scalar::ZERO; 1];
 
     // create a proof
     let mut poly = CompositePolynomial::new(num_vars);
@@ -95,10 +94,10 @@ fn random_product(
     for _ in 0..num_multiplicands {
         multiplicands.push(Vec::with_capacity(1 << nv));
     }
-    let mut sum = Curve25519Scalar::zero();
+    let mut sum = Curve25519Scalar::ZERO;
 
     for _ in 0..(1 << nv) {
-        let mut product = Curve25519Scalar::one();
+        let mut product = Curve25519Scalar::ONE;
         for multiplicand in multiplicands.iter_mut().take(num_multiplicands) {
             let val = Curve25519Scalar::rand(rng);
             multiplicand.push(val);
@@ -117,7 +116,7 @@ fn random_polynomial(
     rng: &mut ark_std::rand::rngs::StdRng,
 ) -> (CompositePolynomial<Curve25519Scalar>, Curve25519Scalar) {
     use ark_std::rand::Rng;
-    let mut sum = Curve25519Scalar::zero();
+    let mut sum = Curve25519Scalar::ZERO;
     let mut poly = CompositePolynomial::new(nv);
     for _ in 0..num_products {
         let num_multiplicands = rng.gen_range(num_multiplicands_range.0..num_multiplicands_range.1);
@@ -137,7 +136,7 @@ fn test_polynomial(nv: usize, num_multiplicands_range: (usize, usize), num_produ
 
     // create a proof
     let mut transcript = Transcript::new(b"sumchecktest");
-    let mut evaluation_point = vec![Curve25519Scalar::zero(); poly.num_variables];
+    let mut evaluation_point = vec![Curve25519Scalar::ZERO; poly.num_variables];
     let proof = SumcheckProof::create(
         &mut transcript,
         &mut evaluation_point,
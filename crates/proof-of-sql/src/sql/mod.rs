@@ -11,4 +11,8 @@ pub mod proof;
 pub mod proof_exprs;
 pub mod proof_gadgets;
 pub mod proof_plans;
+mod query_batch;
+pub use query_batch::{BatchedQueryResult, QueryBatch, QueryHandle};
+#[cfg(all(test, feature = "blitzar"))]
+mod query_batch_test;
 pub(crate) mod util;
@@ -84,6 +84,10 @@ pub enum ConversionError {
         datatype: ColumnType,
     },
 
+    #[snafu(display("Aggregate functions are not allowed in the WHERE clause"))]
+    /// An aggregation is used in the WHERE clause instead of the result expressions
+    AggregateInWhere,
+
     #[snafu(display(
         "Invalid order by: alias '{alias}' does not appear in the result expressions."
     ))]
@@ -205,3 +209,48 @@ impl ConversionError {
 }
 
 pub type ConversionResult<T> = Result<T, ConversionError>;
+
+#[cfg(test)]
+mod tests {
+    use super::ConversionError;
+    use crate::{
+        base::{database::OwnedTableError, proof::ProofError},
+        sql::proof::QueryError,
+    };
+    use alloc::{boxed::Box, string::ToString};
+    use core::error::Error;
+    use proof_of_sql_parser::posql_time::PoSQLTimestampError;
+
+    fn boxed<E: Error + 'static>(error: E) -> Box<dyn Error> {
+        Box::new(error)
+    }
+
+    #[test]
+    fn we_can_box_errors_as_dyn_error() {
+        let errors = [
+            boxed(OwnedTableError::MissingColumn {
+                column_name: "a".to_string(),
+            }),
+            boxed(ProofError::VerificationError { error: "failed" }),
+            boxed(QueryError::Overflow),
+            boxed(ConversionError::Unprovable {
+                error: "unsupported".to_string(),
+            }),
+        ];
+        for error in errors {
+            assert!(error.source().is_none());
+            assert!(!error.to_string().is_empty());
+        }
+    }
+
+    #[test]
+    fn wrapped_errors_are_returned_as_source() {
+        let error: ConversionError = PoSQLTimestampError::InvalidTimezoneOffset.into();
+        let source = error.source().unwrap();
+        assert_eq!(source.to_string(), "invalid timezone offset");
+        assert_eq!(
+            source.downcast_ref::<PoSQLTimestampError>(),
+            Some(&PoSQLTimestampError::InvalidTimezoneOffset)
+        );
+    }
+}
@@ -259,6 +259,11 @@ impl QueryContextBuilder<'_> {
         op: AggregationOperator,
         expr: &Expression,
     ) -> ConversionResult<ColumnType> {
+        // Aggregates can only be referenced from the result expressions,
+        // where they are evaluated during postprocessing.
+        if !self.context.is_in_result_scope() {
+            return Err(ConversionError::AggregateInWhere);
+        }
         self.context.set_in_agg_scope(true)?;
 
         let expr_dtype = self.visit_expr(expr)?;
@@ -1741,6 +1741,42 @@ fn varchar_column_is_not_allowed_within_numeric_aggregations() {
     ));
 }
 
+#[test]
+fn aggregations_can_be_used_in_result_expressions_but_not_in_the_where_clause() {
+    let t = TableRef::new("sxt", "employees");
+    let accessor = schema_accessor_from_table_ref_with_schema(
+        &t,
+        indexmap! {
+            "department".into() => ColumnType::BigInt,
+            "salary".into() => ColumnType::BigInt,
+        },
+    );
+
+    let valid_queries = [
+        "select department, sum(salary) * 2 as adjusted from sxt.employees group by department",
+        "select department, max(salary) - min(salary) as spread from sxt.employees where salary > 0 group by department",
+    ];
+    for query_text in &valid_queries {
+        let intermediate_ast = SelectStatementParser::new().parse(query_text).unwrap();
+        assert!(
+            QueryExpr::try_new(intermediate_ast, t.schema_id().cloned().unwrap(), &accessor)
+                .is_ok()
+        );
+    }
+
+    let invalid_queries = [
+        "select department from sxt.employees where sum(salary) > 100 group by department",
+        "select salary from sxt.employees where count(*) = 1",
+        "select sum(salary) from sxt.employees where (max(salary) = 3) or (salary = 2)",
+    ];
+    for query_text in &invalid_queries {
+        let intermediate_ast = SelectStatementParser::new().parse(query_text).unwrap();
+        let result =
+            QueryExpr::try_new(intermediate_ast, t.schema_id().cloned().unwrap(), &accessor);
+        assert!(matches!(result, Err(ConversionError::AggregateInWhere)));
+    }
+}
+
 #[test]
 fn group_by_with_bigint_column_is_valid() {
     let t = TableRef::new("sxt", "employees");
@@ -48,8 +48,7 @@ impl<S: Scalar> PostprocessingStep<S> for OrderByPostprocessing {
                 (
                     owned_table
                         .column_by_index(*index)
-                        .expect("The index should be valid here")
-                        .clone(),
+                        .expect("The index should be valid here"),
                     *direction,
                 )
             })
@@ -1,5 +1,7 @@
 use super::CompositePolynomialBuilder;
-use crate::proof_primitive::inner_product::curve_25519_scalar::Curve25519Scalar;
+use crate::{
+    base::scalar::Scalar, proof_primitive::inner_product::curve_25519_scalar::Curve25519Scalar,
+};
 use num_traits::One;
 
 #[test]
@@ -9,12 +11,12 @@ fn we_combine_single_degree_fr_multiplicands() {
     let mle2 = [11, 21];
     let mut builder = CompositePolynomialBuilder::new(1, &fr);
     builder.produce_fr_multiplicand(&One::one(), &[Box::new(&mle1)]);
-    builder.produce_fr_multiplicand(&-Curve25519Scalar::one(), &[Box::new(&mle2)]);
+    builder.produce_fr_multiplicand(&-Curve25519Scalar::ONE, &[Box::new(&mle2)]);
     let p = builder.make_composite_polynomial();
     assert_eq!(p.products.len(), 1);
     assert_eq!(p.flattened_ml_extensions.len(), 2);
     let pt = [Curve25519Scalar::from(9_268_764_u64)];
-    let m0 = Curve25519Scalar::one() - pt[0];
+    let m0 = Curve25519Scalar::ONE - pt[0];
     let m1 = pt[0];
     let eval1 = Curve25519Scalar::from(mle1[0]) * m0 + Curve25519Scalar::from(mle1[1]) * m1;
     let eval2 = Curve25519Scalar::from(mle2[0]) * m0 + Curve25519Scalar::from(mle2[1]) * m1;
@@ -35,7 +37,7 @@ fn we_dont_duplicate_repeated_mles() {
     assert_eq!(p.products.len(), 3);
     assert_eq!(p.flattened_ml_extensions.len(), 5);
     let pt = [Curve25519Scalar::from(9_268_764_u64)];
-    let m0 = Curve25519Scalar::one() - pt[0];
+    let m0 = Curve25519Scalar::ONE - pt[0];
     let m1 = pt[0];
     let eval1 = Curve25519Scalar::from(mle1[0]) * m0 + Curve25519Scalar::from(mle1[1]) * m1;
     let eval2 = Curve25519Scalar::from(mle2[0]) * m0 + Curve25519Scalar::from(mle2[1]) * m1;
@@ -53,15 +55,13 @@ fn we_can_combine_identity_with_zero_sum_polynomials() {
     let mle4 = [13, 23];
     let mut builder = CompositePolynomialBuilder::new(1, &fr);
     builder.produce_fr_multiplicand(&One::one(), &[Box::new(&mle1), Box::new(&mle2)]);
-    builder.produce_zerosum_multiplicand(
-        &-Curve25519Scalar::one(),
-        &[Box::new(&mle3), Box::new(&mle4)],
-    );
+    builder
+        .produce_zerosum_multiplicand(&-Curve25519Scalar::ONE, &[Box::new(&mle3), Box::new(&mle4)]);
     let p = builder.make_composite_polynomial();
     assert_eq!(p.products.len(), 3); //1 for the linear term, 1 for the fr multiplicand, 1 for the zerosum multiplicand
     assert_eq!(p.flattened_ml_extensions.len(), 6); //1 for fr, 1 for the linear term, and 4 for mle1-4
     let pt = [Curve25519Scalar::from(9_268_764_u64)];
-    let m0 = Curve25519Scalar::one() - pt[0];
+    let m0 = Curve25519Scalar::ONE - pt[0];
     let m1 = pt[0];
     let eval1 = Curve25519Scalar::from(mle1[0]) * m0 + Curve25519Scalar::from(mle1[1]) * m1;
     let eval2 = Curve25519Scalar::from(mle2[0]) * m0 + Curve25519Scalar::from(mle2[1]) * m1;
@@ -81,7 +81,7 @@ fn we_can_handle_only_an_empty_fr_multiplicand() {
     assert_eq!(p.products.len(), 1); //1 for the fr multiplicand
     assert_eq!(p.flattened_ml_extensions.len(), 2); //1 for fr, 1 for the linear term
     let pt = [Curve25519Scalar::from(9_268_764_u64)];
-    let m0 = Curve25519Scalar::one() - pt[0];
+    let m0 = Curve25519Scalar::ONE - pt[0];
     let m1 = pt[0];
     let eval1 = (m0 + m1) * Curve25519Scalar::from(17);
     let eval_fr = fr[0] * m0 + fr[1] * m1;
@@ -99,15 +99,13 @@ fn we_can_handle_empty_terms_with_other_terms() {
     let mut builder = CompositePolynomialBuilder::new(1, &fr);
     builder.produce_fr_multiplicand(&One::one(), &[Box::new(&mle1), Box::new(&mle2)]);
     builder.produce_fr_multiplicand(&Curve25519Scalar::from(17), &[]);
-    builder.produce_zerosum_multiplicand(
-        &-Curve25519Scalar::one(),
-        &[Box::new(&mle3), Box::new(&mle4)],
-    );
+    builder
+        .produce_zerosum_multiplicand(&-Curve25519Scalar::ONE, &[Box::new(&mle3), Box::new(&mle4)]);
     let p = builder.make_composite_polynomial();
     assert_eq!(p.products.len(), 3); //1 for the linear term, 1 for the fr multiplicand, 1 for the zerosum multiplicand
     assert_eq!(p.flattened_ml_extensions.len(), 6); //1 for fr, 1 for the linear term, and 4 for mle1-4
     let pt = [Curve25519Scalar::from(9_268_764_u64)];
-    let m0 = Curve25519Scalar::one() - pt[0];
+    let m0 = Curve25519Scalar::ONE - pt[0];
     let m1 = pt[0];
     let eval1 = Curve25519Scalar::from(mle1[0]) * m0 + Curve25519Scalar::from(mle1[1]) * m1;
     let eval2 = Curve25519Scalar::from(mle2[0]) * m0 + Curve25519Scalar::from(mle2[1]) * m1;
@@ -0,0 +1,183 @@
+use super::{FinalRoundBuilder, FirstRoundBuilder, ProofPlan};
+use crate::base::{
+    database::{Column, ColumnType, Table, TableOptions, TableRef},
+    map::IndexMap,
+    scalar::Scalar,
+};
+use bumpalo::Bump;
+use core::iter;
+
+/// The size of the constraint system that proving a query plan produces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ConstraintCount {
+    /// The number of multiplications in the sumcheck polynomial.
+    ///
+    /// A term that multiplies a coefficient by `k` MLEs counts as `k` multiplications.
+    pub multiplications: usize,
+    /// The number of terms summed into the sumcheck polynomial.
+    pub additions: usize,
+    /// The number of MLEs whose evaluations the sumcheck polynomial is built from.
+    pub input_scalars: usize,
+}
+
+/// Returns a column of `column_type` without rows.
+fn empty_column<'a, S: Scalar>(column_type: ColumnType) -> Column<'a, S> {
+    match column_type {
+        ColumnType::Boolean => Column::Boolean(&[]),
+        ColumnType::Uint8 => Column::Uint8(&[]),
+        ColumnType::TinyInt => Column::TinyInt(&[]),
+        ColumnType::SmallInt => Column::SmallInt(&[]),
+        ColumnType::Int => Column::Int(&[]),
+        ColumnType::Date => Column::Date(&[]),
+        ColumnType::BigInt => Column::BigInt(&[]),
+        ColumnType::UInt64 => Column::UInt64(&[]),
+        ColumnType::Int128 => Column::Int128(&[]),
+        ColumnType::Decimal75(precision, scale) => Column::Decimal75(precision, scale, &[]),
+        ColumnType::Scalar => Column::Scalar(&[]),
+        ColumnType::VarChar => Column::VarChar((&[], &[])),
+        ColumnType::TimestampTZ(time_unit, time_zone) => {
+            Column::TimestampTZ(time_unit, time_zone, &[])
+        }
+        ColumnType::VarBinary => Column::VarBinary((&[], &[])),
+    }
+}
+
+/// Counts the constraints that proving `plan` produces, without any data or cryptography.
+///
+/// The plan is evaluated by the prover against empty tables, which produces the same constraints
+/// as any other data, except for the bit decompositions of comparisons and casts. Those depend on
+/// which bits of the data vary, and are not counted, so this is a lower bound for the proving cost.
+#[expect(
+    clippy::missing_panics_doc,
+    reason = "Columns without rows always form a valid table"
+)]
+#[must_use]
+pub fn count_constraints<S: Scalar>(plan: &impl ProofPlan) -> ConstraintCount {
+    let alloc = Bump::new();
+    let column_refs = plan.get_column_references();
+    let table_map: IndexMap<TableRef, Table<S>> = plan
+        .get_table_references()
+        .into_iter()
+        .map(|table_ref| {
+            let columns = column_refs
+                .iter()
+                .filter(|column_ref| column_ref.table_ref() == table_ref)
+                .map(|column_ref| {
+                    (
+                        column_ref.column_id(),
+                        empty_column(*column_ref.column_type()),
+                    )
+                });
+            let table = Table::try_from_iter_with_options(columns, TableOptions::new(Some(0)))
+                .expect("columns without rows always form a table");
+            (table_ref, table)
+        })
+        .collect();
+
+    let mut first_round_builder = FirstRoundBuilder::new(1);
+    plan.first_round_evaluate(&mut first_round_builder, &alloc, &table_map);
+    let post_result_challenges = iter::repeat(S::ONE)
+        .take(first_round_builder.num_post_result_challenges())
+        .collect();
+    let mut final_round_builder = FinalRoundBuilder::new(1, post_result_challenges);
+    plan.final_round_evaluate(&mut final_round_builder, &alloc, &table_map);
+
+    let (multiplications, additions) = final_round_builder
+        .sumcheck_subpolynomials()
+        .iter()
+        .flat_map(|subpolynomial| subpolynomial.iter_mul_by(S::ONE))
+        .fold(
+            (0, 0),
+            |(multiplications, additions), (_, _, multiplicands)| {
+                (multiplications + multiplicands.len(), additions + 1)
+            },
+        );
+    ConstraintCount {
+        multiplications,
+        additions,
+        input_scalars: first_round_builder.pcs_proof_mles().len()
+            + final_round_builder.pcs_proof_mles().len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_constraints, ConstraintCount};
+    use crate::{
+        base::{
+            database::{ColumnField, ColumnRef, ColumnType, LiteralValue, TableRef},
+            scalar::test_scalar::TestScalar,
+        },
+        sql::{
+            proof_exprs::{test_utility::*, ColumnExpr, DynProofExpr, LiteralExpr, TableExpr},
+            proof_plans::{test_utility::*, DynProofPlan},
+        },
+    };
+
+    fn bigint_column(table_ref: &TableRef, name: &str) -> DynProofExpr {
+        DynProofExpr::Column(ColumnExpr::new(ColumnRef::new(
+            table_ref.clone(),
+            name.into(),
+            ColumnType::BigInt,
+        )))
+    }
+
+    fn filter_plan(results: &[&str], where_clause: DynProofExpr) -> DynProofPlan {
+        let table_ref = TableRef::new("sxt", "t");
+        filter(
+            results
+                .iter()
+                .map(|name| aliased_plan(bigint_column(&table_ref, name), name))
+                .collect(),
+            TableExpr { table_ref },
+            where_clause,
+        )
+    }
+
+    #[test]
+    fn a_table_scan_has_no_constraints() {
+        let table_ref = TableRef::new("sxt", "t");
+        let plan = table_exec(
+            table_ref,
+            vec![
+                ColumnField::new("a".into(), ColumnType::BigInt),
+                ColumnField::new("b".into(), ColumnType::VarChar),
+            ],
+        );
+        assert_eq!(
+            count_constraints::<TestScalar>(&plan),
+            ConstraintCount::default()
+        );
+    }
+
+    #[test]
+    fn more_filter_results_and_conditions_need_more_constraints() {
+        let table_ref = TableRef::new("sxt", "t");
+        let one_result = count_constraints::<TestScalar>(&filter_plan(&["a"], const_bool(true)));
+        let two_results =
+            count_constraints::<TestScalar>(&filter_plan(&["a", "b"], const_bool(true)));
+        let equality = count_constraints::<TestScalar>(&filter_plan(
+            &["a"],
+            DynProofExpr::try_new_equals(
+                bigint_column(&table_ref, "c"),
+                DynProofExpr::Literal(LiteralExpr::new(LiteralValue::BigInt(5))),
+            )
+            .unwrap(),
+        ));
+        assert!(one_result.additions > 0);
+        assert!(one_result.multiplications >= one_result.additions);
+        assert!(two_results.input_scalars > one_result.input_scalars);
+        assert!(two_results.multiplications > one_result.multiplications);
+        assert!(equality.input_scalars > one_result.input_scalars);
+        assert!(equality.additions > one_result.additions);
+    }
+
+    #[test]
+    fn the_constraint_count_does_not_depend_on_the_scalar_type() {
+        let plan = filter_plan(&["a", "b"], const_bool(true));
+        assert_eq!(
+            count_constraints::<TestScalar>(&plan),
+            count_constraints::<crate::proof_primitive::dory::DoryScalar>(&plan)
+        );
+    }
+}
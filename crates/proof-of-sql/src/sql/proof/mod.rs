@@ -35,6 +35,9 @@ mod proof_plan;
 pub use proof_plan::ProofPlan;
 pub(crate) use proof_plan::{HonestProver, ProverEvaluate, ProverHonestyMarker};
 
+mod constraint_count;
+pub use constraint_count::{count_constraints, ConstraintCount};
+
 mod query_proof;
 pub use query_proof::QueryProof;
 #[cfg(all(test, feature = "blitzar"))]
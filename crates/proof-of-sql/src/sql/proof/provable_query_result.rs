@@ -110,8 +110,11 @@ impl ProvableQueryResult {
                     ColumnType::Uint8 => decode_and_convert::<u8, S>(&self.data[offset..]),
                     ColumnType::TinyInt => decode_and_convert::<i8, S>(&self.data[offset..]),
                     ColumnType::SmallInt => decode_and_convert::<i16, S>(&self.data[offset..]),
-                    ColumnType::Int => decode_and_convert::<i32, S>(&self.data[offset..]),
+                    ColumnType::Int | ColumnType::Date => {
+                        decode_and_convert::<i32, S>(&self.data[offset..])
+                    }
                     ColumnType::BigInt => decode_and_convert::<i64, S>(&self.data[offset..]),
+                    ColumnType::UInt64 => decode_and_convert::<u64, S>(&self.data[offset..]),
                     ColumnType::Int128 => decode_and_convert::<i128, S>(&self.data[offset..]),
                     ColumnType::Decimal75(_, _) | ColumnType::Scalar => {
                         decode_and_convert::<S, S>(&self.data[offset..])
@@ -192,6 +195,16 @@ impl ProvableQueryResult {
                         offset += num_read;
                         Ok((field.name(), OwnedColumn::BigInt(col)))
                     }
+                    ColumnType::UInt64 => {
+                        let (col, num_read) = decode_multiple_elements(&self.data[offset..], n)?;
+                        offset += num_read;
+                        Ok((field.name(), OwnedColumn::UInt64(col)))
+                    }
+                    ColumnType::Date => {
+                        let (col, num_read) = decode_multiple_elements(&self.data[offset..], n)?;
+                        offset += num_read;
+                        Ok((field.name(), OwnedColumn::Date(col)))
+                    }
                     ColumnType::Int128 => {
                         let (col, num_read) = decode_multiple_elements(&self.data[offset..], n)?;
                         offset += num_read;
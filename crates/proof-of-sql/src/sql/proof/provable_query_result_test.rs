@@ -15,7 +15,6 @@ use arrow::{
     datatypes::{i256, Field, Schema},
     record_batch::RecordBatch,
 };
-use num_traits::Zero;
 
 #[test]
 fn we_can_convert_an_empty_provable_result_to_a_final_result() {
@@ -63,7 +62,7 @@ fn we_can_evaluate_result_columns_with_no_rows() {
     let evals = res
         .evaluate(&evaluation_point, 0, &column_fields[..])
         .unwrap();
-    let expected_evals = [TestScalar::zero()];
+    let expected_evals = [TestScalar::ZERO];
     assert_eq!(evals, expected_evals);
 }
 
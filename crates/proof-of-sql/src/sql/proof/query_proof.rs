@@ -19,7 +19,7 @@ use crate::{
     proof_primitive::sumcheck::SumcheckProof,
     utils::log,
 };
-use alloc::{boxed::Box, vec, vec::Vec};
+use alloc::{boxed::Box, string::ToString, vec, vec::Vec};
 use bumpalo::Bump;
 use core::cmp;
 use num_traits::Zero;
@@ -92,7 +92,37 @@ pub struct QueryProof<CP: CommitmentEvaluationProof> {
     pub(super) evaluation_proof: CP,
 }
 
+/// Checks that no two result columns of `expr` have the same name.
+fn check_result_column_names(expr: &impl ProofPlan) -> Result<(), ProofError> {
+    let mut result_column_names = IndexSet::default();
+    for field in expr.get_column_result_fields() {
+        if !result_column_names.insert(field.name()) {
+            return Err(ProofError::DuplicateColumnInQuery {
+                column: field.name().to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
 impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
+    /// Create a new `QueryProof`, checking that the plan can be proven with `setup`.
+    ///
+    /// # Errors
+    /// Returns an error if
+    /// - several result columns have the same name, e.g. `SELECT a, a FROM t`, giving [`ProofError::DuplicateColumnInQuery`], or
+    /// - the circuit needs more rows than [`CommitmentEvaluationProof::max_table_size`] allows, giving
+    ///   [`ProofError::CircuitTooLarge`]. This is checked after the first round, since e.g. range checks and
+    ///   unions can make the circuit larger than the referenced tables, but before anything is committed to.
+    pub fn try_new(
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+    ) -> Result<(Self, OwnedTable<CP::Scalar>), ProofError> {
+        check_result_column_names(expr)?;
+        Self::prove(expr, accessor, setup, CP::max_table_size(setup))
+    }
+
     /// Create a new `QueryProof`.
     #[tracing::instrument(name = "QueryProof::new", level = "debug", skip_all)]
     pub fn new(
@@ -100,6 +130,18 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         accessor: &impl DataAccessor<CP::Scalar>,
         setup: &CP::ProverPublicSetup<'_>,
     ) -> (Self, OwnedTable<CP::Scalar>) {
+        Self::prove(expr, accessor, setup, None)
+            .expect("proving without a maximum table size cannot fail")
+    }
+
+    /// Prove `expr`, failing with [`ProofError::CircuitTooLarge`] if the range of the circuit
+    /// exceeds `max_table_size`.
+    fn prove(
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        max_table_size: Option<usize>,
+    ) -> Result<(Self, OwnedTable<CP::Scalar>), ProofError> {
         log::log_memory_usage("Start");
 
         let (min_row_num, max_row_num) = get_index_range(accessor, &expr.get_table_references());
@@ -129,6 +171,14 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         let rho_evaluation_lengths = first_round_builder.rho_evaluation_lengths();
 
         let range_length = first_round_builder.range_length();
+        if let Some(max_table_size) = max_table_size {
+            if range_length > max_table_size {
+                return Err(ProofError::CircuitTooLarge {
+                    table_size: range_length,
+                    max_table_size,
+                });
+            }
+        }
         let num_sumcheck_variables = cmp::max(log2_up(range_length), 1);
         assert!(num_sumcheck_variables > 0);
         let post_result_challenge_count = first_round_builder.num_post_result_challenges();
@@ -271,7 +321,7 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
 
         log::log_memory_usage("End");
 
-        (proof, provable_result)
+        Ok((proof, provable_result))
     }
 
     #[tracing::instrument(name = "QueryProof::verify", level = "debug", skip_all, err)]
@@ -14,7 +14,11 @@ use crate::{
         scalar::Scalar,
     },
     proof_primitive::inner_product::curve_25519_scalar::Curve25519Scalar,
-    sql::proof::{FirstRoundBuilder, QueryData, SumcheckSubpolynomialType},
+    sql::{
+        proof::{FirstRoundBuilder, QueryData, SumcheckSubpolynomialType},
+        proof_exprs::test_utility::{aliased_plan, column},
+        proof_plans::test_utility::{column_field, projection, table_exec},
+    },
 };
 use bumpalo::Bump;
 use serde::Serialize;
@@ -245,6 +249,46 @@ fn verify_fails_if_a_bit_distribution_is_invalid() {
     assert!(proof.verify(&expr, &accessor, result, &()).is_err());
 }
 
+#[test]
+fn prove_rejects_duplicate_select_columns() {
+    let t = TableRef::new("sxt", "t");
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t.clone(),
+        owned_table([bigint("a", [1_i64, 2]), bigint("b", [3_i64, 4])]),
+        0,
+        (),
+    );
+    let table = table_exec(
+        t.clone(),
+        vec![
+            column_field("a", ColumnType::BigInt),
+            column_field("b", ColumnType::BigInt),
+        ],
+    );
+    let expr = projection(
+        vec![
+            aliased_plan(column(&t, "a", &accessor), "a"),
+            aliased_plan(column(&t, "b", &accessor), "b"),
+            aliased_plan(column(&t, "a", &accessor), "a"),
+        ],
+        table.clone(),
+    );
+    assert!(matches!(
+        QueryProof::<InnerProductProof>::try_new(&expr, &accessor, &()),
+        Err(ProofError::DuplicateColumnInQuery { column }) if column == "a"
+    ));
+
+    let expr = projection(
+        vec![
+            aliased_plan(column(&t, "a", &accessor), "a"),
+            aliased_plan(column(&t, "a", &accessor), "c"),
+        ],
+        table,
+    );
+    let (proof, result) = QueryProof::<InnerProductProof>::try_new(&expr, &accessor, &()).unwrap();
+    assert!(proof.verify(&expr, &accessor, result, &()).is_ok());
+}
+
 /// prove and verify an artificial query where
 ///     `res_i = x_i * x_i`
 /// where the commitment for x is known
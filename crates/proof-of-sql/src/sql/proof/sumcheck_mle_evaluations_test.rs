@@ -1,9 +1,8 @@
 use super::SumcheckMleEvaluations;
 use crate::{
-    proof_primitive::inner_product::curve_25519_scalar::Curve25519Scalar,
+    base::scalar::Scalar, proof_primitive::inner_product::curve_25519_scalar::Curve25519Scalar,
     sql::proof::SumcheckRandomScalars,
 };
-use num_traits::One;
 
 #[test]
 fn we_can_track_the_evaluation_of_mles_used_within_sumcheck() {
@@ -25,24 +24,24 @@ fn we_can_track_the_evaluation_of_mles_used_within_sumcheck() {
         &pcs_proof_evaluations,
         &[],
     );
-    let expected_eval = (Curve25519Scalar::one() - evaluation_point[0])
-        * (Curve25519Scalar::one() - evaluation_point[1])
-        * (Curve25519Scalar::one() - random_scalars[0])
-        * (Curve25519Scalar::one() - random_scalars[1])
+    let expected_eval = (Curve25519Scalar::ONE - evaluation_point[0])
+        * (Curve25519Scalar::ONE - evaluation_point[1])
+        * (Curve25519Scalar::ONE - random_scalars[0])
+        * (Curve25519Scalar::ONE - random_scalars[1])
         + (evaluation_point[0])
-            * (Curve25519Scalar::one() - evaluation_point[1])
+            * (Curve25519Scalar::ONE - evaluation_point[1])
             * (random_scalars[0])
-            * (Curve25519Scalar::one() - random_scalars[1])
-        + (Curve25519Scalar::one() - evaluation_point[0])
+            * (Curve25519Scalar::ONE - random_scalars[1])
+        + (Curve25519Scalar::ONE - evaluation_point[0])
             * (evaluation_point[1])
-            * (Curve25519Scalar::one() - random_scalars[0])
+            * (Curve25519Scalar::ONE - random_scalars[0])
             * (random_scalars[1]);
     assert_eq!(evals.random_evaluation, expected_eval);
 
-    let expected_eval = (Curve25519Scalar::one() - evaluation_point[0])
-        * (Curve25519Scalar::one() - evaluation_point[1])
-        + (evaluation_point[0]) * (Curve25519Scalar::one() - evaluation_point[1])
-        + (Curve25519Scalar::one() - evaluation_point[0]) * (evaluation_point[1]);
+    let expected_eval = (Curve25519Scalar::ONE - evaluation_point[0])
+        * (Curve25519Scalar::ONE - evaluation_point[1])
+        + (evaluation_point[0]) * (Curve25519Scalar::ONE - evaluation_point[1])
+        + (Curve25519Scalar::ONE - evaluation_point[0]) * (evaluation_point[1]);
     assert_eq!(
         *evals.chi_evaluations.values().next().unwrap(),
         expected_eval
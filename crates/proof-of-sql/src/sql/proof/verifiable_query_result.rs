@@ -3,6 +3,7 @@ use crate::{
     base::{
         commitment::CommitmentEvaluationProof,
         database::{CommitmentAccessor, DataAccessor, OwnedTable},
+        proof::ProofError,
     },
     utils::log,
 };
@@ -89,6 +90,23 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
         Self { result: res, proof }
     }
 
+    /// Form a `VerifiableQueryResult` from a query expression, first checking that the query can be proven
+    /// with `setup`.
+    ///
+    /// # Errors
+    /// Returns the errors of [`QueryProof::try_new`].
+    #[tracing::instrument(name = "VerifiableQueryResult::try_new", level = "info", skip_all)]
+    pub fn try_new(
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+    ) -> Result<Self, ProofError> {
+        log::log_memory_usage("Start");
+        let (proof, res) = QueryProof::try_new(expr, accessor, setup)?;
+        log::log_memory_usage("End");
+        Ok(Self { result: res, proof })
+    }
+
     /// Verify a `VerifiableQueryResult`. Upon success, this function returns the finalized form of
     /// the query result.
     ///
@@ -9,7 +9,6 @@ use crate::{
 };
 use blitzar::proof::InnerProductProof;
 use curve25519_dalek::{ristretto::RistrettoPoint, traits::Identity};
-use num_traits::One;
 use serde::Serialize;
 
 /// This function takes a valid `verifiable_result`, copies it, tweaks it, and checks that
@@ -40,7 +39,7 @@ pub fn exercise_verification(
     // try changing MLE evaluations
     for i in 0..res.proof.pcs_proof_evaluations.final_round.len() {
         let mut res_p = res.clone();
-        res_p.proof.pcs_proof_evaluations.final_round[i] += Curve25519Scalar::one();
+        res_p.proof.pcs_proof_evaluations.final_round[i] += Curve25519Scalar::ONE;
         assert!(res_p.verify(expr, accessor, &()).is_err());
     }
 
@@ -107,8 +106,9 @@ fn append_single_row_to_column<S: Scalar>(column: &OwnedColumn<S>) -> OwnedColum
         OwnedColumn::Uint8(col) => col.push(0),
         OwnedColumn::TinyInt(col) => col.push(0),
         OwnedColumn::SmallInt(col) => col.push(0),
-        OwnedColumn::Int(col) => col.push(0),
+        OwnedColumn::Int(col) | OwnedColumn::Date(col) => col.push(0),
         OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => col.push(0),
+        OwnedColumn::UInt64(col) => col.push(0),
         OwnedColumn::VarChar(col) => col.push(String::new()),
         OwnedColumn::VarBinary(col) => col.push(vec![0u8]),
         OwnedColumn::Int128(col) => col.push(0),
@@ -142,10 +142,11 @@ pub fn tamper_first_row_of_column<S: Scalar>(column: &OwnedColumn<S>) -> OwnedCo
         OwnedColumn::Uint8(col) => col[0] = col[0].wrapping_add(1),
         OwnedColumn::TinyInt(col) => col[0] = col[0].wrapping_add(1),
         OwnedColumn::SmallInt(col) => col[0] = col[0].wrapping_add(1),
-        OwnedColumn::Int(col) => col[0] = col[0].wrapping_add(1),
+        OwnedColumn::Int(col) | OwnedColumn::Date(col) => col[0] = col[0].wrapping_add(1),
         OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => {
             col[0] = col[0].wrapping_add(1);
         }
+        OwnedColumn::UInt64(col) => col[0] = col[0].wrapping_add(1),
         OwnedColumn::VarChar(col) => col[0].push('1'),
         OwnedColumn::VarBinary(col) => col[0].push(1u8),
         OwnedColumn::Int128(col) => col[0] = col[0].wrapping_add(1),
@@ -1,10 +1,10 @@
 use super::{SumcheckMleEvaluations, VerificationBuilderImpl};
 use crate::{
+    base::scalar::Scalar,
     proof_primitive::inner_product::curve_25519_scalar::Curve25519Scalar,
     sql::proof::{SumcheckSubpolynomialType, VerificationBuilder},
 };
 use alloc::collections::VecDeque;
-use num_traits::Zero;
 
 #[test]
 fn an_empty_sumcheck_polynomial_evaluates_to_zero() {
@@ -20,7 +20,7 @@ fn an_empty_sumcheck_polynomial_evaluates_to_zero() {
         Vec::new(),
         0,
     );
-    assert_eq!(builder.sumcheck_evaluation(), Curve25519Scalar::zero());
+    assert_eq!(builder.sumcheck_evaluation(), Curve25519Scalar::ZERO);
 }
 
 #[test]
@@ -25,6 +25,11 @@ impl AndExpr {
     pub fn new(lhs: Box<DynProofExpr>, rhs: Box<DynProofExpr>) -> Self {
         Self { lhs, rhs }
     }
+
+    /// Returns the operands of the logical AND expression
+    pub(super) fn into_operands(self) -> (Box<DynProofExpr>, Box<DynProofExpr>) {
+        (self.lhs, self.rhs)
+    }
 }
 
 impl ProofExpr for AndExpr {
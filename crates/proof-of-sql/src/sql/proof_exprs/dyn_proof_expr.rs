@@ -15,7 +15,7 @@ use crate::{
         AnalyzeError, AnalyzeResult,
     },
 };
-use alloc::{boxed::Box, string::ToString};
+use alloc::{boxed::Box, string::ToString, vec, vec::Vec};
 use bumpalo::Bump;
 use core::fmt::Debug;
 use serde::{Deserialize, Serialize};
@@ -173,6 +173,84 @@ impl DynProofExpr {
             })
     }
 
+    /// Returns the value of the expression if it is a boolean literal.
+    fn as_bool_literal(&self) -> Option<bool> {
+        match self {
+            Self::Literal(LiteralExpr {
+                value: LiteralValue::Boolean(value),
+            }) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Simplifies logical expressions whose operands are boolean literals.
+    ///
+    /// For example, `a AND true` becomes `a`, `a OR true` becomes `true` and `NOT NOT a` becomes `a`.
+    /// Only `AND`, `OR` and `NOT` are folded, since each of them commits to an intermediate result
+    /// which can be skipped entirely when the result is already known.
+    #[must_use]
+    pub(crate) fn fold_constants(self) -> Self {
+        match self {
+            Self::And(expr) => {
+                let (lhs, rhs) = expr.into_operands();
+                let (lhs, rhs) = (lhs.fold_constants(), rhs.fold_constants());
+                match (lhs.as_bool_literal(), rhs.as_bool_literal()) {
+                    (Some(false), _) | (_, Some(false)) => {
+                        Self::new_literal(LiteralValue::Boolean(false))
+                    }
+                    (Some(true), _) => rhs,
+                    (_, Some(true)) => lhs,
+                    (None, None) => Self::And(AndExpr::new(Box::new(lhs), Box::new(rhs))),
+                }
+            }
+            Self::Or(expr) => {
+                let (lhs, rhs) = expr.into_operands();
+                let (lhs, rhs) = (lhs.fold_constants(), rhs.fold_constants());
+                match (lhs.as_bool_literal(), rhs.as_bool_literal()) {
+                    (Some(true), _) | (_, Some(true)) => {
+                        Self::new_literal(LiteralValue::Boolean(true))
+                    }
+                    (Some(false), _) => rhs,
+                    (_, Some(false)) => lhs,
+                    (None, None) => Self::Or(OrExpr::new(Box::new(lhs), Box::new(rhs))),
+                }
+            }
+            Self::Not(expr) => match expr.into_operand().fold_constants() {
+                Self::Not(expr) => *expr.into_operand(),
+                expr => match expr.as_bool_literal() {
+                    Some(value) => Self::new_literal(LiteralValue::Boolean(!value)),
+                    None => Self::Not(NotExpr::new(Box::new(expr))),
+                },
+            },
+            expr => expr,
+        }
+    }
+
+    /// Splits a conjunction `a AND b AND ...` into its operands, in order.
+    #[must_use]
+    pub(crate) fn into_conjuncts(self) -> Vec<Self> {
+        match self {
+            Self::And(expr) => {
+                let (lhs, rhs) = expr.into_operands();
+                let mut conjuncts = lhs.into_conjuncts();
+                conjuncts.extend(rhs.into_conjuncts());
+                conjuncts
+            }
+            expr => vec![expr],
+        }
+    }
+
+    /// Combines `conjuncts` into the expression `a AND b AND ...`. This is the inverse of [`Self::into_conjuncts`].
+    ///
+    /// An empty list of conjuncts gives the literal `true`.
+    #[must_use]
+    pub(crate) fn from_conjuncts(conjuncts: Vec<Self>) -> Self {
+        conjuncts
+            .into_iter()
+            .reduce(|lhs, rhs| Self::And(AndExpr::new(Box::new(lhs), Box::new(rhs))))
+            .unwrap_or_else(|| Self::new_literal(LiteralValue::Boolean(true)))
+    }
+
     /// Check that the plan has the correct data type
     fn check_data_type(&self, data_type: ColumnType) -> AnalyzeResult<()> {
         if self.data_type() == data_type {
@@ -24,6 +24,11 @@ impl NotExpr {
     pub fn new(expr: Box<DynProofExpr>) -> Self {
         Self { expr }
     }
+
+    /// Returns the operand of the logical NOT expression
+    pub(super) fn into_operand(self) -> Box<DynProofExpr> {
+        self.expr
+    }
 }
 
 impl ProofExpr for NotExpr {
@@ -25,6 +25,11 @@ impl OrExpr {
     pub fn new(lhs: Box<DynProofExpr>, rhs: Box<DynProofExpr>) -> Self {
         Self { lhs, rhs }
     }
+
+    /// Returns the operands of the logical OR expression
+    pub(super) fn into_operands(self) -> (Box<DynProofExpr>, Box<DynProofExpr>) {
+        (self.lhs, self.rhs)
+    }
 }
 
 impl ProofExpr for OrExpr {
@@ -53,5 +53,15 @@ mod sort_merge_join_exec_test;
 mod dyn_proof_plan;
 pub use dyn_proof_plan::DynProofPlan;
 
+mod predicate_count;
+pub use predicate_count::VerifiablePredicateCount;
+#[cfg(all(test, feature = "blitzar"))]
+mod predicate_count_test;
+
+mod optimizer;
+pub use optimizer::OptimizerOptions;
+#[cfg(test)]
+mod optimizer_test;
+
 #[cfg(test)]
 mod demo_mock_plan;
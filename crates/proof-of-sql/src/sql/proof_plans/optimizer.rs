@@ -0,0 +1,109 @@
+use super::{DynProofPlan, FilterExec};
+use crate::sql::proof_exprs::{AliasedDynProofExpr, DynProofExpr};
+use alloc::{boxed::Box, vec::Vec};
+
+/// Options controlling which passes [`DynProofPlan::optimize`] runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OptimizerOptions {
+    /// Split `WHERE` clauses into their conjuncts and evaluate each distinct conjunct only once.
+    pub enable_conjunct_dedup: bool,
+    /// Simplify `AND`, `OR` and `NOT` expressions that have boolean literal operands.
+    pub enable_constant_folding: bool,
+}
+
+impl Default for OptimizerOptions {
+    fn default() -> Self {
+        Self {
+            enable_conjunct_dedup: true,
+            enable_constant_folding: true,
+        }
+    }
+}
+
+impl OptimizerOptions {
+    /// Optimizes an expression that is part of the output of a plan.
+    fn optimize_expr(self, expr: DynProofExpr) -> DynProofExpr {
+        if self.enable_constant_folding {
+            expr.fold_constants()
+        } else {
+            expr
+        }
+    }
+
+    /// Optimizes the aliased output expressions of a plan.
+    fn optimize_results(self, results: Vec<AliasedDynProofExpr>) -> Vec<AliasedDynProofExpr> {
+        results
+            .into_iter()
+            .map(|AliasedDynProofExpr { expr, alias }| AliasedDynProofExpr {
+                expr: self.optimize_expr(expr),
+                alias,
+            })
+            .collect()
+    }
+
+    /// Optimizes a `WHERE` clause.
+    fn optimize_predicate(self, predicate: DynProofExpr) -> DynProofExpr {
+        let predicate = self.optimize_expr(predicate);
+        if self.enable_conjunct_dedup {
+            dedup_conjuncts(predicate)
+        } else {
+            predicate
+        }
+    }
+}
+
+/// Removes repeated conjuncts from `a AND b AND ...`, keeping the first occurrence of each.
+fn dedup_conjuncts(predicate: DynProofExpr) -> DynProofExpr {
+    let mut conjuncts = Vec::new();
+    for conjunct in predicate.into_conjuncts() {
+        if !conjuncts.contains(&conjunct) {
+            conjuncts.push(conjunct);
+        }
+    }
+    DynProofExpr::from_conjuncts(conjuncts)
+}
+
+impl DynProofPlan {
+    /// Rewrites the plan into an equivalent plan that needs fewer intermediate results to prove.
+    ///
+    /// The result of the optimized plan is the same as the result of the original plan.
+    #[must_use]
+    pub fn optimize(self, options: &OptimizerOptions) -> Self {
+        let options = *options;
+        match self {
+            DynProofPlan::Empty(_) | DynProofPlan::Table(_) => self,
+            DynProofPlan::Projection(mut exec) => {
+                exec.aliased_results = options.optimize_results(exec.aliased_results);
+                exec.input = Box::new((*exec.input).optimize(&options));
+                DynProofPlan::Projection(exec)
+            }
+            DynProofPlan::Filter(exec) => DynProofPlan::Filter(FilterExec::new(
+                options.optimize_results(exec.aliased_results),
+                exec.table,
+                options.optimize_predicate(exec.where_clause),
+            )),
+            DynProofPlan::GroupBy(mut exec) => {
+                exec.sum_expr = options.optimize_results(exec.sum_expr);
+                exec.where_clause = options.optimize_predicate(exec.where_clause);
+                DynProofPlan::GroupBy(exec)
+            }
+            DynProofPlan::Slice(mut exec) => {
+                exec.input = Box::new((*exec.input).optimize(&options));
+                DynProofPlan::Slice(exec)
+            }
+            DynProofPlan::Union(mut exec) => {
+                exec.inputs = exec
+                    .inputs
+                    .into_iter()
+                    .map(|input| input.optimize(&options))
+                    .collect();
+                DynProofPlan::Union(exec)
+            }
+            DynProofPlan::SortMergeJoin(mut exec) => {
+                exec.left = Box::new((*exec.left).optimize(&options));
+                exec.right = Box::new((*exec.right).optimize(&options));
+                DynProofPlan::SortMergeJoin(exec)
+            }
+        }
+    }
+}
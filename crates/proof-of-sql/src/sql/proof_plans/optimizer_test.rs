@@ -0,0 +1,123 @@
+use super::{test_utility::*, DynProofPlan, OptimizerOptions};
+use crate::{
+    base::{
+        database::{ColumnType, TableRef, TestSchemaAccessor},
+        map::indexmap,
+    },
+    sql::proof_exprs::{test_utility::*, DynProofExpr},
+};
+
+fn setup() -> (TableRef, TestSchemaAccessor) {
+    let t = TableRef::new("sxt", "t");
+    let accessor = TestSchemaAccessor::new(indexmap! {
+        t.clone() => indexmap! {
+            "a".into() => ColumnType::BigInt,
+            "b".into() => ColumnType::Boolean,
+        },
+    });
+    (t, accessor)
+}
+
+fn is_positive(t: &TableRef, accessor: &TestSchemaAccessor) -> DynProofExpr {
+    gte(column(t, "a", accessor), const_bigint(0))
+}
+
+#[test]
+fn we_can_fold_constants_in_a_filter() {
+    let (t, accessor) = setup();
+    let plan = filter(
+        vec![aliased_plan(
+            or(column(&t, "b", &accessor), const_bool(false)),
+            "b",
+        )],
+        tab(&t),
+        and(
+            not(not(is_positive(&t, &accessor))),
+            and(const_bool(true), column(&t, "b", &accessor)),
+        ),
+    );
+    let expected = filter(
+        vec![aliased_plan(column(&t, "b", &accessor), "b")],
+        tab(&t),
+        and(is_positive(&t, &accessor), column(&t, "b", &accessor)),
+    );
+    let options = OptimizerOptions {
+        enable_conjunct_dedup: false,
+        enable_constant_folding: true,
+    };
+    assert_eq!(plan.optimize(&options), expected);
+}
+
+#[test]
+fn we_can_fold_a_where_clause_into_a_single_literal() {
+    let (t, accessor) = setup();
+    let plan = filter(
+        vec![aliased_plan(column(&t, "a", &accessor), "a")],
+        tab(&t),
+        or(
+            not(const_bool(false)),
+            and(column(&t, "b", &accessor), const_bool(false)),
+        ),
+    );
+    let expected = filter(
+        vec![aliased_plan(column(&t, "a", &accessor), "a")],
+        tab(&t),
+        const_bool(true),
+    );
+    assert_eq!(plan.optimize(&OptimizerOptions::default()), expected);
+}
+
+#[test]
+fn we_can_remove_duplicate_conjuncts_from_nested_plans() {
+    let (t, accessor) = setup();
+    let where_clause = and(
+        and(is_positive(&t, &accessor), column(&t, "b", &accessor)),
+        is_positive(&t, &accessor),
+    );
+    let plan = slice_exec(
+        filter(
+            vec![aliased_plan(column(&t, "a", &accessor), "a")],
+            tab(&t),
+            where_clause.clone(),
+        ),
+        1,
+        None,
+    );
+    let expected = slice_exec(
+        filter(
+            vec![aliased_plan(column(&t, "a", &accessor), "a")],
+            tab(&t),
+            and(is_positive(&t, &accessor), column(&t, "b", &accessor)),
+        ),
+        1,
+        None,
+    );
+    let options = OptimizerOptions {
+        enable_conjunct_dedup: true,
+        enable_constant_folding: false,
+    };
+    assert_eq!(plan.clone().optimize(&options), expected);
+    let disabled = OptimizerOptions {
+        enable_conjunct_dedup: false,
+        enable_constant_folding: false,
+    };
+    assert_eq!(plan.clone().optimize(&disabled), plan);
+}
+
+#[test]
+fn we_can_optimize_plans_without_predicates() {
+    let (t, accessor) = setup();
+    let plan = projection(
+        vec![aliased_plan(column(&t, "a", &accessor), "a")],
+        table_exec(
+            t.clone(),
+            vec![
+                column_field("a", ColumnType::BigInt),
+                column_field("b", ColumnType::Boolean),
+            ],
+        ),
+    );
+    assert_eq!(plan.clone().optimize(&OptimizerOptions::default()), plan);
+    let empty = DynProofPlan::new_empty();
+    assert_eq!(empty.clone().optimize(&OptimizerOptions::default()), empty);
+}
@@ -0,0 +1,91 @@
+use super::DynProofPlan;
+use crate::{
+    base::{
+        commitment::CommitmentEvaluationProof,
+        database::{CommitmentAccessor, DataAccessor, OwnedColumn, OwnedTable, TableRef},
+        scalar::Scalar,
+    },
+    sql::{
+        proof::{QueryError, VerifiableQueryResult},
+        proof_exprs::{DynProofExpr, TableExpr},
+    },
+};
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use sqlparser::ast::Ident;
+
+/// The alias of the count column of a [`VerifiablePredicateCount`].
+const COUNT_ALIAS: &str = "__count__";
+
+/// Builds the plan for `SELECT COUNT(*) FROM <table> WHERE <predicate>`.
+fn predicate_count_plan(table_ref: &TableRef, predicate: &DynProofExpr) -> DynProofPlan {
+    DynProofPlan::new_group_by(
+        Vec::new(),
+        Vec::new(),
+        COUNT_ALIAS.into(),
+        TableExpr {
+            table_ref: table_ref.clone(),
+        },
+        predicate.clone(),
+    )
+}
+
+/// Reads the count from the result of a [`predicate_count_plan`].
+fn count_from_table<S: Scalar>(table: &OwnedTable<S>) -> Result<usize, QueryError> {
+    match (
+        table.num_columns(),
+        table.inner_table().get(&Ident::new(COUNT_ALIAS)),
+    ) {
+        (1, Some(OwnedColumn::BigInt(counts))) => {
+            usize::try_from(counts.iter().sum::<i64>()).map_err(|_| QueryError::Overflow)
+        }
+        _ => Err(QueryError::InvalidColumnCount),
+    }
+}
+
+/// A proof of the number of rows of a table that satisfy a predicate.
+///
+/// Unlike a filter, which reveals the rows that satisfy the predicate, the only result of this proof
+/// is the number of such rows. This is useful for certifying statements such as
+/// "at least 100 rows satisfy the condition" without revealing which rows they are.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct VerifiablePredicateCount<CP: CommitmentEvaluationProof> {
+    /// The count along with the proof that it is valid.
+    pub result: VerifiableQueryResult<CP>,
+}
+
+impl<CP: CommitmentEvaluationProof> VerifiablePredicateCount<CP> {
+    /// Counts the rows of the table `table_ref` that satisfy `predicate` and proves the count.
+    ///
+    /// Returns the count along with the proof.
+    ///
+    /// # Panics
+    /// Panics if the prover produces a result that is not a single count column, which should never happen.
+    pub fn new(
+        table_ref: &TableRef,
+        predicate: &DynProofExpr,
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+    ) -> (usize, Self) {
+        let plan = predicate_count_plan(table_ref, predicate);
+        let result = VerifiableQueryResult::new(&plan, accessor, setup);
+        let count = count_from_table(&result.result)
+            .expect("the prover always produces a single count column");
+        (count, Self { result })
+    }
+
+    /// Verifies the proof and returns the number of rows of `table_ref` that satisfy `predicate`.
+    ///
+    /// # Errors
+    /// Returns an error if the proof does not verify.
+    pub fn verify(
+        self,
+        table_ref: &TableRef,
+        predicate: &DynProofExpr,
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> Result<usize, QueryError> {
+        let plan = predicate_count_plan(table_ref, predicate);
+        count_from_table(&self.result.verify(&plan, accessor, setup)?.table)
+    }
+}
@@ -0,0 +1,52 @@
+use super::VerifiablePredicateCount;
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{owned_table_utility::*, OwnedTableTestAccessor, TableRef, TestAccessor},
+    },
+    sql::proof_exprs::test_utility::*,
+};
+
+fn accessor_with_table(t: &TableRef) -> OwnedTableTestAccessor<'static, InnerProductProof> {
+    let data = owned_table([
+        bigint("a", [1, 50, 42, 43, 100]),
+        varchar("b", ["x", "y", "z", "x", "y"]),
+    ]);
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t.clone(), data, 0);
+    accessor
+}
+
+#[test]
+fn we_can_prove_the_number_of_rows_satisfying_a_predicate() {
+    let t = TableRef::new("sxt", "t");
+    let accessor = accessor_with_table(&t);
+    let predicate = not(lte(column(&t, "a", &accessor), const_bigint(42)));
+    let (count, proof) =
+        VerifiablePredicateCount::<InnerProductProof>::new(&t, &predicate, &accessor, &());
+    assert_eq!(count, 3);
+    assert_eq!(proof.verify(&t, &predicate, &accessor, &()).unwrap(), 3);
+}
+
+#[test]
+fn we_can_prove_that_no_rows_satisfy_a_predicate() {
+    let t = TableRef::new("sxt", "t");
+    let accessor = accessor_with_table(&t);
+    let predicate = equal(column(&t, "b", &accessor), const_varchar("w"));
+    let (count, proof) =
+        VerifiablePredicateCount::<InnerProductProof>::new(&t, &predicate, &accessor, &());
+    assert_eq!(count, 0);
+    assert_eq!(proof.verify(&t, &predicate, &accessor, &()).unwrap(), 0);
+}
+
+#[test]
+fn we_cannot_verify_a_count_for_a_different_predicate() {
+    let t = TableRef::new("sxt", "t");
+    let accessor = accessor_with_table(&t);
+    let predicate = equal(column(&t, "b", &accessor), const_varchar("x"));
+    let (count, proof) =
+        VerifiablePredicateCount::<InnerProductProof>::new(&t, &predicate, &accessor, &());
+    assert_eq!(count, 2);
+    let other_predicate = equal(column(&t, "b", &accessor), const_varchar("y"));
+    assert!(proof.verify(&t, &other_predicate, &accessor, &()).is_err());
+}
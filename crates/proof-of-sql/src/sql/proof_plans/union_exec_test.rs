@@ -6,8 +6,12 @@ use crate::{
             OwnedTableTestAccessor, TableRef, TableTestAccessor, TestAccessor,
         },
         map::indexmap,
+        proof::ProofError,
+    },
+    proof_primitive::{
+        dory::{test_rng, DynamicDoryEvaluationProof, ProverSetup, PublicParameters},
+        inner_product::curve_25519_scalar::Curve25519Scalar,
     },
-    proof_primitive::inner_product::curve_25519_scalar::Curve25519Scalar,
     sql::{
         proof::{
             exercise_verification, FirstRoundBuilder, ProvableQueryResult, ProverEvaluate,
@@ -354,3 +358,47 @@ fn we_can_get_result_from_union_using_first_round_evaluate() {
 
     assert_eq!(res, expected);
 }
+
+#[test]
+fn we_cannot_prove_a_union_larger_than_the_setup_even_if_its_tables_fit() {
+    let public_parameters = PublicParameters::test_rand(2, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let t0 = TableRef::new("sxt", "t0");
+    let t1 = TableRef::new("sxt", "t1");
+    let mut accessor =
+        OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_empty_with_setup(&prover_setup);
+    accessor.add_table(
+        t0.clone(),
+        owned_table([bigint("a0", [1_i64, 2, 3, 4, 5])]),
+        0,
+    );
+    accessor.add_table(
+        t1.clone(),
+        owned_table([bigint("a1", [6_i64, 7, 8, 9, 10])]),
+        0,
+    );
+    let ast = union_exec(
+        vec![
+            projection(
+                cols_expr_plan(&t0, &["a0"], &accessor),
+                table_exec(t0.clone(), vec![column_field("a0", ColumnType::BigInt)]),
+            ),
+            projection(
+                cols_expr_plan(&t1, &["a1"], &accessor),
+                table_exec(t1.clone(), vec![column_field("a1", ColumnType::BigInt)]),
+            ),
+        ],
+        vec![column_field("a", ColumnType::BigInt)],
+    );
+    assert!(matches!(
+        VerifiableQueryResult::<DynamicDoryEvaluationProof>::try_new(
+            &ast,
+            &accessor,
+            &&prover_setup
+        ),
+        Err(ProofError::CircuitTooLarge {
+            table_size: 10,
+            max_table_size: 8
+        })
+    ));
+}
@@ -0,0 +1,99 @@
+use crate::{
+    base::{
+        commitment::CommitmentEvaluationProof,
+        database::{DataAccessor, SchemaAccessor},
+    },
+    sql::{
+        parse::{ConversionError, QueryExpr},
+        proof::VerifiableQueryResult,
+    },
+};
+use alloc::{string::ToString, vec::Vec};
+use proof_of_sql_parser::SelectStatement;
+use sqlparser::ast::Ident;
+
+/// Identifies a query added to a [`QueryBatch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QueryHandle(usize);
+
+/// A proven query of a [`QueryBatch`].
+pub struct BatchedQueryResult<CP: CommitmentEvaluationProof> {
+    /// The handle returned when the query was added.
+    pub handle: QueryHandle,
+    /// The parsed query, which is needed to verify the result.
+    pub query: QueryExpr,
+    /// The result along with the proof that it is valid.
+    pub result: VerifiableQueryResult<CP>,
+}
+
+/// A convenience wrapper for proving several queries against the same tables.
+///
+/// All queries share the accessor and prover setup. Queries are parsed as they are added, so invalid
+/// queries are rejected before any proving work is done, and queries with the same plan are only proven once.
+/// Otherwise each query is proven independently with [`VerifiableQueryResult::new`]; no commitments, transcripts
+/// or evaluations are shared between the proofs.
+pub struct QueryBatch<'a, CP: CommitmentEvaluationProof, A> {
+    accessor: &'a A,
+    setup: CP::ProverPublicSetup<'a>,
+    default_schema: Ident,
+    queries: Vec<QueryExpr>,
+}
+
+impl<'a, CP: CommitmentEvaluationProof, A: DataAccessor<CP::Scalar> + SchemaAccessor>
+    QueryBatch<'a, CP, A>
+{
+    /// Creates an empty batch of queries against the tables of `accessor`.
+    ///
+    /// Tables without a schema in the queries are looked up in `default_schema`.
+    #[must_use]
+    pub fn new(accessor: &'a A, default_schema: Ident, setup: CP::ProverPublicSetup<'a>) -> Self {
+        Self {
+            accessor,
+            setup,
+            default_schema,
+            queries: Vec::new(),
+        }
+    }
+
+    /// Parses `sql` and adds it to the batch.
+    ///
+    /// If a query with the same plan was added before, the handle of that query is returned instead.
+    ///
+    /// # Errors
+    /// Returns a [`ConversionError`] if `sql` cannot be parsed or cannot be planned against the accessor.
+    pub fn add_query(&mut self, sql: &str) -> Result<QueryHandle, ConversionError> {
+        let ast = sql
+            .parse::<SelectStatement>()
+            .map_err(|error| ConversionError::ParseError {
+                error: error.to_string(),
+            })?;
+        let query = QueryExpr::try_new(ast, self.default_schema.clone(), self.accessor)?;
+        let index = self
+            .queries
+            .iter()
+            .position(|existing| *existing == query)
+            .unwrap_or_else(|| {
+                self.queries.push(query);
+                self.queries.len() - 1
+            });
+        Ok(QueryHandle(index))
+    }
+
+    /// Proves every distinct query of the batch, in the order they were first added.
+    #[must_use]
+    pub fn prove_all(self) -> Vec<BatchedQueryResult<CP>> {
+        self.queries
+            .into_iter()
+            .enumerate()
+            .map(|(index, query)| {
+                let result =
+                    VerifiableQueryResult::new(query.proof_expr(), self.accessor, &self.setup);
+                BatchedQueryResult {
+                    handle: QueryHandle(index),
+                    query,
+                    result,
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,64 @@
+use super::QueryBatch;
+use crate::base::{
+    commitment::InnerProductProof,
+    database::{owned_table_utility::*, OwnedTableTestAccessor, TableRef, TestAccessor},
+};
+
+fn accessor_with_table() -> OwnedTableTestAccessor<'static, InnerProductProof> {
+    let data = owned_table([
+        bigint("a", [1, 50, 42, 43, 100]),
+        varchar("b", ["x", "y", "z", "x", "y"]),
+    ]);
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(TableRef::new("sxt", "t"), data, 0);
+    accessor
+}
+
+#[test]
+fn we_can_prove_a_batch_of_queries() {
+    let accessor = accessor_with_table();
+    let mut batch = QueryBatch::<InnerProductProof, _>::new(&accessor, "sxt".into(), ());
+    let filter = batch.add_query("SELECT a FROM t WHERE a > 42").unwrap();
+    let count = batch
+        .add_query("SELECT b, COUNT(*) AS c FROM t GROUP BY b")
+        .unwrap();
+    let duplicate = batch.add_query("SELECT a FROM t WHERE a > 42").unwrap();
+    assert_eq!(filter, duplicate);
+    assert_ne!(filter, count);
+
+    let results = batch.prove_all();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].handle, filter);
+    assert_eq!(results[1].handle, count);
+
+    let filter_result = results[0]
+        .result
+        .clone()
+        .verify(results[0].query.proof_expr(), &accessor, &())
+        .unwrap()
+        .table;
+    assert_eq!(filter_result, owned_table([bigint("a", [50, 43, 100])]));
+    let count_result = results[1]
+        .result
+        .clone()
+        .verify(results[1].query.proof_expr(), &accessor, &())
+        .unwrap()
+        .table;
+    assert_eq!(count_result.num_rows(), 3);
+
+    // A result cannot be verified against another query of the batch
+    assert!(results[0]
+        .result
+        .clone()
+        .verify(results[1].query.proof_expr(), &accessor, &())
+        .is_err());
+}
+
+#[test]
+fn we_cannot_add_invalid_queries_to_a_batch() {
+    let accessor = accessor_with_table();
+    let mut batch = QueryBatch::<InnerProductProof, _>::new(&accessor, "sxt".into(), ());
+    assert!(batch.add_query("SELECT FROM").is_err());
+    assert!(batch.add_query("SELECT c FROM t").is_err());
+    assert!(batch.prove_all().is_empty());
+}
@@ -9,8 +9,11 @@ use proof_of_sql::proof_primitive::hyperkzg::{
     nova_commitment_key_to_hyperkzg_public_setup, HyperKZGCommitmentEvaluationProof,
 };
 use proof_of_sql::{
-    base::database::{
-        owned_table_utility::*, OwnedTable, OwnedTableTestAccessor, TableRef, TestAccessor,
+    base::{
+        database::{
+            owned_table_utility::*, OwnedTable, OwnedTableTestAccessor, TableRef, TestAccessor,
+        },
+        proof::ProofError,
     },
     proof_primitive::{
         dory::{
@@ -118,6 +121,37 @@ fn we_can_prove_a_minimal_filter_query_with_dynamic_dory() {
     assert_eq!(owned_table_result, expected_result);
 }
 
+#[test]
+fn we_cannot_prove_a_query_over_a_table_larger_than_the_dynamic_dory_setup() {
+    let public_parameters = PublicParameters::test_rand(2, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+
+    let mut accessor =
+        OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_empty_with_setup(&prover_setup);
+    accessor.add_table(
+        TableRef::new("sxt", "table"),
+        owned_table([bigint("a", [1, 2, 3, 4, 5, 6, 7, 8, 9])]),
+        0,
+    );
+    let query = QueryExpr::try_new(
+        "SELECT * FROM table WHERE a = 1".parse().unwrap(),
+        "sxt".into(),
+        &accessor,
+    )
+    .unwrap();
+    assert!(matches!(
+        VerifiableQueryResult::<DynamicDoryEvaluationProof>::try_new(
+            query.proof_expr(),
+            &accessor,
+            &&prover_setup,
+        ),
+        Err(ProofError::CircuitTooLarge {
+            table_size: 9,
+            max_table_size: 8
+        })
+    ));
+}
+
 #[test]
 #[cfg(feature = "blitzar")]
 fn we_can_prove_a_basic_equality_query_with_curve25519() {